@@ -0,0 +1,114 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+/// Returns `true` if `value` is valid base64 where `alphabet_end` are the last two characters of the alphabet.
+fn is_base64_with(
+	value: &[u8],
+	alphabet_end: [u8; 2],
+	padding_required: bool,
+) -> bool {
+	let padding = value.iter().rev().take(2).take_while(|&&byte| byte == b'=').count();
+	let data = &value[..value.len() - padding];
+
+	if (padding > 0 || padding_required) && !value.len().is_multiple_of(4) {
+		return false;
+	}
+
+	let decode = |byte: u8| match byte {
+		b'A'..=b'Z' => Some(byte - b'A'),
+		b'a'..=b'z' => Some(byte - b'a' + 26),
+		b'0'..=b'9' => Some(byte - b'0' + 52),
+		_ if byte == alphabet_end[0] => Some(62),
+		_ if byte == alphabet_end[1] => Some(63),
+		_ => None,
+	};
+
+	let Some(last) = data.last().copied().and_then(decode) else {
+		return false;
+	};
+	if !data.iter().all(|&byte| decode(byte).is_some()) {
+		return false;
+	}
+
+	// The bits of the last character that do not belong to a decoded byte must be zero
+	match data.len() % 4 {
+		1 => false,
+		2 => last & 0b1111 == 0,
+		3 => last & 0b11 == 0,
+		_ => true,
+	}
+}
+
+impl Guards {
+	/// Ensures that `value` is base64 encoded.
+	///
+	/// Returns [`Ok`] if the string is non-empty, canonical base64, otherwise returns [`Err`] with the given
+	/// `error_message`. Both the standard alphabet with `+` and `/` and the URL-safe alphabet with `-` and `_` are
+	/// accepted, but not mixed in one string. Standard base64 must be padded with `=` to a multiple of four
+	/// characters, URL-safe base64 may leave out the padding. Padding can only be at the end, and the unused bits
+	/// of the last character must be zero, so every accepted string decodes to exactly one byte string.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_base64("SGVsbG8=", "?!"), Ok("SGVsbG8="));
+	/// assert_eq!(Guards::is_base64("SGVsbA==", "?!"), Ok("SGVsbA=="));
+	/// assert_eq!(Guards::is_base64("SGVs", "?!"), Ok("SGVs"));
+	/// assert_eq!(Guards::is_base64("+/+/", "?!"), Ok("+/+/"));
+	/// assert_eq!(Guards::is_base64("-_-_", "?!"), Ok("-_-_"));
+	/// assert_eq!(Guards::is_base64("SGVsbG8", "?!"), Ok("SGVsbG8"));
+	///
+	/// assert_eq!(Guards::is_base64("", "Not base64!"), Err(String::from("Not base64!")));
+	/// assert!(Guards::is_base64("+/-_", "Not base64!").is_err());
+	/// assert!(Guards::is_base64("SGVsbG8+", "?!").is_ok());
+	/// assert!(Guards::is_base64("SGVsbG+", "Not base64!").is_err());
+	///
+	/// // Padding edge cases
+	/// assert!(Guards::is_base64("SGVsbG8==", "Not base64!").is_err());
+	/// assert!(Guards::is_base64("SGVsbA=", "Not base64!").is_err());
+	/// assert!(Guards::is_base64("SGVsbA===", "Not base64!").is_err());
+	/// assert!(Guards::is_base64("SG=sbA==", "Not base64!").is_err());
+	/// assert!(Guards::is_base64("====", "Not base64!").is_err());
+	/// assert!(Guards::is_base64("SGVsb", "Not base64!").is_err());
+	///
+	/// // Non-canonical trailing bits
+	/// assert!(Guards::is_base64("SGVsbB==", "Not base64!").is_err());
+	/// assert!(Guards::is_base64("SGVsbG9=", "Not base64!").is_err());
+	/// ```
+	pub fn is_base64<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let bytes = value.as_ref().as_bytes();
+		if is_base64_with(bytes, [b'+', b'/'], true) || is_base64_with(bytes, [b'-', b'_'], false) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` is base64 encoded.
+	///
+	/// See [Guards::is_base64] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("eyJhbGciOiJIUzI1NiJ9").is_base64("?!").result();
+	/// assert_eq!(pass, Ok("eyJhbGciOiJIUzI1NiJ9"));
+	///
+	/// let fail = Guard::new("eyJhbGciOiJIUzI1NiJ9!").is_base64("Payload must be base64 encoded").result();
+	/// assert_eq!(fail, Err(String::from("Payload must be base64 encoded")));
+	/// ```
+	pub fn is_base64<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_base64", |value| Guards::is_base64(value, error_message).map(|_| ()))
+	}
+}
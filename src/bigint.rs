@@ -0,0 +1,194 @@
+use num_bigint::{
+	BigInt,
+	BigUint,
+};
+
+use crate::{
+	number::sealed,
+	Divisible,
+	Guard,
+	Guards,
+};
+
+/// Implemented for the arbitrary precision integer types of `num_bigint`, and for references to them.
+///
+/// [`BigInt`] and [`BigUint`] also implement [`Divisible`], so [`Guards::is_even`], [`Guards::is_odd`] and
+/// [`Guards::is_multiple_of`] work with them.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// ## Example
+/// ```
+/// use fluent_guards::Guard;
+/// use num_bigint::BigUint;
+///
+/// let modulus = (BigUint::from(1u8) << 2047usize) + 1u8;
+///
+/// let pass = Guard::new(modulus.clone())
+/// 	.is_odd("Modulus must be odd")
+/// 	.has_bit_length_between(2048, 4096, "Modulus must have 2048 to 4096 bits")
+/// 	.result();
+/// assert_eq!(pass, Ok(modulus));
+/// ```
+pub trait BigInteger: Divisible {
+	/// Returns the number of bits needed to represent the magnitude of the value, see [`BigUint::bits`].
+	fn bits(&self) -> u64;
+}
+
+impl<T: BigInteger> BigInteger for &T {
+	fn bits(&self) -> u64 {
+		T::bits(self)
+	}
+}
+
+impl sealed::Sealed for BigUint {}
+
+/// ## Example
+/// ```
+/// use fluent_guards::Guards;
+/// use num_bigint::BigUint;
+///
+/// let even = BigUint::from(1u8) << 2047usize;
+/// let odd = (BigUint::from(1u8) << 2048usize) - 1u8;
+///
+/// assert_eq!(Guards::is_even(even.clone(), "?!"), Ok(even.clone()));
+/// assert_eq!(Guards::is_odd(odd.clone(), "?!"), Ok(odd.clone()));
+/// assert!(Guards::is_even(odd.clone(), "Value was odd!").is_err());
+///
+/// let factor = (BigUint::from(1u8) << 1024usize) + 1u8;
+/// assert_eq!(Guards::is_multiple_of(&factor * &odd, factor.clone(), "?!"), Ok(&factor * &odd));
+/// assert!(Guards::is_multiple_of(&factor * &odd + 1u8, factor.clone(), "Not a multiple!").is_err());
+/// assert_eq!(Guards::is_multiple_of(odd, BigUint::ZERO, "?!"), Err(String::from("invalid divisor: 0")));
+/// ```
+impl Divisible for BigUint {
+	fn is_even(&self) -> bool {
+		!self.bit(0)
+	}
+
+	fn checked_is_multiple_of(
+		&self,
+		divisor: &Self,
+	) -> Option<bool> {
+		if divisor.bits() == 0 {
+			None
+		} else {
+			Some((self % divisor).bits() == 0)
+		}
+	}
+}
+
+impl BigInteger for BigUint {
+	fn bits(&self) -> u64 {
+		BigUint::bits(self)
+	}
+}
+
+impl sealed::Sealed for BigInt {}
+
+/// Negative numbers follow mathematical parity and signs are ignored for multiples, like for primitive integers.
+///
+/// ## Example
+/// ```
+/// use fluent_guards::Guards;
+/// use num_bigint::BigInt;
+///
+/// let odd = -((BigInt::from(1u8) << 2048usize) - 1u8);
+/// assert_eq!(Guards::is_odd(odd.clone(), "?!"), Ok(odd.clone()));
+/// assert!(Guards::is_even(odd.clone(), "Value was odd!").is_err());
+/// assert_eq!(Guards::is_even(BigInt::from(-4), "?!"), Ok(BigInt::from(-4)));
+///
+/// assert_eq!(Guards::is_multiple_of(&odd * 3u8, BigInt::from(-3), "?!"), Ok(&odd * 3u8));
+/// assert!(Guards::is_multiple_of(odd, BigInt::from(1) << 2048usize, "Not a multiple!").is_err());
+/// ```
+impl Divisible for BigInt {
+	fn is_even(&self) -> bool {
+		// The lowest bit of the two's complement form has the same parity as the magnitude
+		!self.bit(0)
+	}
+
+	fn checked_is_multiple_of(
+		&self,
+		divisor: &Self,
+	) -> Option<bool> {
+		if divisor.bits() == 0 {
+			None
+		} else {
+			Some((self % divisor).bits() == 0)
+		}
+	}
+}
+
+impl BigInteger for BigInt {
+	fn bits(&self) -> u64 {
+		BigInt::bits(self)
+	}
+}
+
+impl Guards {
+	/// Ensures that the bit length of `value` is between `min_bits` and `max_bits` (inclusive).
+	///
+	/// Returns [`Ok`] if the value needs at least `min_bits` and at most `max_bits` bits, otherwise returns
+	/// [`Err`] with the given `error_message`. The sign of a [`BigInt`] is ignored and zero has a bit length of `0`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	/// use num_bigint::{BigInt, BigUint};
+	///
+	/// let smallest = BigUint::from(1u8) << 2047usize;
+	/// let largest = (BigUint::from(1u8) << 2048usize) - 1u8;
+	/// assert_eq!(Guards::has_bit_length_between(smallest.clone(), 2048, 2048, "?!"), Ok(smallest.clone()));
+	/// assert_eq!(Guards::has_bit_length_between(largest.clone(), 2048, 2048, "?!"), Ok(largest.clone()));
+	///
+	/// assert_eq!(
+	/// 	Guards::has_bit_length_between(smallest - 1u8, 2048, 2048, "Not 2048 bits!"),
+	/// 	Err(String::from("Not 2048 bits!"))
+	/// );
+	/// assert!(Guards::has_bit_length_between(largest + 1u8, 2048, 2048, "Not 2048 bits!").is_err());
+	///
+	/// assert_eq!(Guards::has_bit_length_between(BigInt::from(-255), 8, 8, "?!"), Ok(BigInt::from(-255)));
+	/// assert_eq!(Guards::has_bit_length_between(BigUint::ZERO, 0, 0, "?!"), Ok(BigUint::ZERO));
+	/// ```
+	pub fn has_bit_length_between<T: BigInteger, E: Into<String>>(
+		value: T,
+		min_bits: u64,
+		max_bits: u64,
+		error_message: E,
+	) -> Result<T, String> {
+		let bits = value.bits();
+		if bits >= min_bits && bits <= max_bits {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: BigInteger> Guard<T> {
+	/// Ensures that the bit length of `value` is between `min_bits` and `max_bits` (inclusive).
+	///
+	/// See [Guards::has_bit_length_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	/// use num_bigint::BigUint;
+	///
+	/// let pass = Guard::new(BigUint::from(65537u32)).has_bit_length_between(2, 32, "?!").result();
+	/// assert_eq!(pass, Ok(BigUint::from(65537u32)));
+	///
+	/// let fail = Guard::new(BigUint::from(1u8) << 1023usize)
+	/// 	.has_bit_length_between(2048, 4096, "Key is too short")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("Key is too short")));
+	/// ```
+	pub fn has_bit_length_between<E: Into<String>>(
+		self,
+		min_bits: u64,
+		max_bits: u64,
+		error_message: E,
+	) -> Self {
+		self.check("has_bit_length_between", |value| {
+			Guards::has_bit_length_between(value, min_bits, max_bits, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,169 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is `true`.
+	///
+	/// Returns [`Ok`] if `value` is `true`, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// struct SignUp {
+	/// 	accepted_terms: bool,
+	/// }
+	///
+	/// let sign_up = SignUp { accepted_terms: true };
+	/// assert_eq!(Guards::is_true(sign_up.accepted_terms, "?!"), Ok(true));
+	///
+	/// let sign_up = SignUp { accepted_terms: false };
+	/// assert_eq!(Guards::is_true(sign_up.accepted_terms, "Please accept the terms!"), Err(String::from("Please accept the terms!")));
+	/// ```
+	pub fn is_true<E: Into<String>>(
+		value: bool,
+		error_message: E,
+	) -> Result<bool, String> {
+		if value {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is `false`.
+	///
+	/// Returns [`Ok`] if `value` is `false`, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_false(false, "?!"), Ok(false));
+	/// assert_eq!(Guards::is_false(true, "Maintenance mode is on!"), Err(String::from("Maintenance mode is on!")));
+	/// ```
+	pub fn is_false<E: Into<String>>(
+		value: bool,
+		error_message: E,
+	) -> Result<bool, String> {
+		if value {
+			Err(error_message.into())
+		} else {
+			Ok(value)
+		}
+	}
+
+	/// Ensures that `consequent` is `true` whenever `antecedent` is.
+	///
+	/// Returns [`Ok`] if `antecedent` is `false` or `consequent` is `true`, otherwise returns [`Err`] with the
+	/// given `error_message`. Useful for rules like "if auto renew is on, a payment method is required".
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::implies(true, true, "?!"), Ok(()));
+	/// assert_eq!(Guards::implies(false, true, "?!"), Ok(()));
+	/// assert_eq!(Guards::implies(false, false, "?!"), Ok(()));
+	/// assert_eq!(Guards::implies(true, false, "Payment method required!"), Err(String::from("Payment method required!")));
+	/// ```
+	pub fn implies<E: Into<String>>(
+		antecedent: bool,
+		consequent: bool,
+		error_message: E,
+	) -> Result<(), String> {
+		if !antecedent || consequent {
+			Ok(())
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl Guard<bool> {
+	/// Ensures that `value` is `true`.
+	///
+	/// See [Guards::is_true] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{CheckOutcome, Guard};
+	///
+	/// struct Consent {
+	/// 	marketing: bool,
+	/// 	data_processing: bool,
+	/// }
+	///
+	/// let consent = Consent { marketing: false, data_processing: true };
+	///
+	/// let pass = Guard::new(consent.data_processing).is_true("?!").result();
+	/// assert_eq!(pass, Ok(true));
+	///
+	/// let (fail, trace) = Guard::new(consent.marketing).traced().is_true("Marketing consent is missing").result_traced();
+	/// assert_eq!(fail, Err(String::from("Marketing consent is missing")));
+	/// assert_eq!((trace[0].name, trace[0].outcome), ("is_true", CheckOutcome::Failed));
+	/// ```
+	pub fn is_true<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_true", |value| Guards::is_true(*value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is `false`.
+	///
+	/// See [Guards::is_false] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(false).is_false("?!").result();
+	/// assert_eq!(pass, Ok(false));
+	///
+	/// let opted_out = true;
+	/// let fail = Guard::new(opted_out).is_false("The user opted out of tracking").result();
+	/// assert_eq!(fail, Err(String::from("The user opted out of tracking")));
+	/// ```
+	pub fn is_false<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_false", |value| Guards::is_false(*value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `consequent` returns `true` whenever `value` is `true`.
+	///
+	/// `consequent` is only called when `value` is `true`. See [Guards::implies] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// struct Subscription {
+	/// 	auto_renew: bool,
+	/// 	payment_method: Option<&'static str>,
+	/// }
+	///
+	/// fn check(subscription: &Subscription) -> Result<bool, String> {
+	/// 	Guard::new(subscription.auto_renew)
+	/// 		.implies(|_| subscription.payment_method.is_some(), "Auto renew needs a payment method")
+	/// 		.result()
+	/// }
+	///
+	/// assert_eq!(check(&Subscription { auto_renew: true, payment_method: Some("card") }), Ok(true));
+	/// assert_eq!(check(&Subscription { auto_renew: false, payment_method: None }), Ok(false));
+	/// assert_eq!(
+	/// 	check(&Subscription { auto_renew: true, payment_method: None }),
+	/// 	Err(String::from("Auto renew needs a payment method"))
+	/// );
+	/// ```
+	pub fn implies<F: FnOnce(&bool) -> bool, E: Into<String>>(
+		self,
+		consequent: F,
+		error_message: E,
+	) -> Self {
+		self.check("implies", |value| {
+			Guards::implies(*value, *value && consequent(value), error_message)
+		})
+	}
+}
@@ -0,0 +1,138 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` starts with the bytes in `prefix`.
+	///
+	/// Returns [`Ok`] if the byte slice starts with `prefix`, otherwise returns [`Err`] with the given
+	/// `error_message`. Useful to check the magic number of an uploaded file. An empty `prefix` always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// const PNG: &[u8] = b"\x89PNG\r\n\x1a\n";
+	///
+	/// let image = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+	/// assert_eq!(Guards::starts_with_bytes(&image, PNG, "?!"), Ok(&image));
+	/// assert_eq!(Guards::starts_with_bytes(&b"GIF89a"[..], b"", "?!"), Ok(&b"GIF89a"[..]));
+	///
+	/// assert_eq!(Guards::starts_with_bytes(&b"GIF89a"[..], PNG, "Not a PNG!"), Err(String::from("Not a PNG!")));
+	/// assert!(Guards::starts_with_bytes(&b"\x89PNG"[..], PNG, "Not a PNG!").is_err());
+	/// ```
+	pub fn starts_with_bytes<T: AsRef<[u8]>, E: Into<String>>(
+		value: T,
+		prefix: &[u8],
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().starts_with(prefix) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is valid UTF-8.
+	///
+	/// Returns [`Ok`] if the byte slice is valid UTF-8, otherwise returns [`Err`] with the given
+	/// `error_message`. Control characters like NUL are valid UTF-8 and pass, check them separately if they are
+	/// not allowed. An empty slice passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_valid_utf8("日本".as_bytes(), "?!"), Ok("日本".as_bytes()));
+	/// assert_eq!(Guards::is_valid_utf8(b"a\0b".to_vec(), "?!"), Ok(b"a\0b".to_vec()));
+	/// assert_eq!(Guards::is_valid_utf8(Vec::new(), "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::is_valid_utf8(&[0xe6, 0x97][..], "Not UTF-8!"), Err(String::from("Not UTF-8!")));
+	/// assert!(Guards::is_valid_utf8(&b"\x89PNG"[..], "Not UTF-8!").is_err());
+	/// ```
+	pub fn is_valid_utf8<T: AsRef<[u8]>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if std::str::from_utf8(value.as_ref()).is_ok() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: AsRef<[u8]>> Guard<T> {
+	/// Ensures that `value` starts with the bytes in `prefix`.
+	///
+	/// See [Guards::starts_with_bytes] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(b"%PDF-1.7\n".to_vec()).starts_with_bytes(b"%PDF-", "?!").result();
+	/// assert_eq!(pass, Ok(b"%PDF-1.7\n".to_vec()));
+	///
+	/// let fail = Guard::new(b"PK\x03\x04".to_vec()).starts_with_bytes(b"%PDF-", "Only PDF files are allowed").result();
+	/// assert_eq!(fail, Err(String::from("Only PDF files are allowed")));
+	/// ```
+	pub fn starts_with_bytes<E: Into<String>>(
+		self,
+		prefix: &[u8],
+		error_message: E,
+	) -> Self {
+		self.check("starts_with_bytes", |value| {
+			Guards::starts_with_bytes(value, prefix, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is valid UTF-8.
+	///
+	/// See [Guards::is_valid_utf8] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(b"name,age\nFerris,9\n".to_vec()).is_valid_utf8("?!").result();
+	/// assert_eq!(pass, Ok(b"name,age\nFerris,9\n".to_vec()));
+	///
+	/// let fail = Guard::new(b"name,age\n\xffFerris,9\n".to_vec()).is_valid_utf8("CSV files must be UTF-8").result();
+	/// assert_eq!(fail, Err(String::from("CSV files must be UTF-8")));
+	/// ```
+	pub fn is_valid_utf8<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_utf8", |value| {
+			Guards::is_valid_utf8(value, error_message).map(|_| ())
+		})
+	}
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Guard<&'a T> {
+	/// Converts the guarded bytes to a `&str`, failing if they are not valid UTF-8.
+	///
+	/// Returns a `Guard<&str>` borrowing the same bytes, so string checks can follow without copying. If the
+	/// bytes are not valid UTF-8, the guard fails with the given `error_message`.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let upload = b"hello\0world".to_vec();
+	/// let pass = Guard::new(&upload).into_utf8_str("?!").has_len(11, "?!").result();
+	/// assert_eq!(pass, Ok("hello\0world"));
+	///
+	/// let upload = [0x68, 0x69, 0xf0, 0x9f, 0xa6];
+	/// let fail = Guard::new(&upload[..]).into_utf8_str("Text files must be UTF-8").is_not_empty("?!").result();
+	/// assert_eq!(fail, Err(String::from("Text files must be UTF-8")));
+	/// ```
+	pub fn into_utf8_str<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Guard<&'a str> {
+		self.convert("into_utf8_str", |value| {
+			std::str::from_utf8(value.as_ref()).map_err(|_| error_message.into())
+		})
+	}
+}
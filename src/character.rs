@@ -0,0 +1,251 @@
+use crate::{
+	Bound,
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is an ASCII digit.
+	///
+	/// Returns [`Ok`] if `value` is `0` to `9`, otherwise returns [`Err`] with the given `error_message`. Digits
+	/// of other scripts, like the Arabic-Indic `'٣'`, fail, use [`Guards::is_numeric_char`] to accept them.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_ascii_digit('7', "?!"), Ok('7'));
+	///
+	/// assert_eq!(Guards::is_ascii_digit('x', "Not a digit!"), Err(String::from("Not a digit!")));
+	/// assert!(Guards::is_ascii_digit('\u{0663}', "Not a digit!").is_err());
+	/// assert!(Guards::is_ascii_digit('½', "Not a digit!").is_err());
+	/// ```
+	pub fn is_ascii_digit<E: Into<String>>(
+		value: char,
+		error_message: E,
+	) -> Result<char, String> {
+		if value.is_ascii_digit() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is numeric.
+	///
+	/// Returns [`Ok`] if `value` is numeric in any script, as defined by [`char::is_numeric`], otherwise returns
+	/// [`Err`] with the given `error_message`. This includes digits like the Arabic-Indic `'٣'` and numbers like
+	/// `'½'`, which cannot be parsed with [`char::to_digit`], use [`Guards::is_ascii_digit`] before parsing.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_numeric_char('7', "?!"), Ok('7'));
+	/// assert_eq!(Guards::is_numeric_char('\u{0663}', "?!"), Ok('\u{0663}'));
+	/// assert_eq!(Guards::is_numeric_char('½', "?!"), Ok('½'));
+	///
+	/// assert_eq!(Guards::is_numeric_char('x', "Not a number!"), Err(String::from("Not a number!")));
+	/// ```
+	pub fn is_numeric_char<E: Into<String>>(
+		value: char,
+		error_message: E,
+	) -> Result<char, String> {
+		if value.is_numeric() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is alphabetic.
+	///
+	/// Returns [`Ok`] if `value` is a letter in any script, as defined by [`char::is_alphabetic`], otherwise
+	/// returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_alphabetic('a', "?!"), Ok('a'));
+	/// assert_eq!(Guards::is_alphabetic('ß', "?!"), Ok('ß'));
+	///
+	/// assert_eq!(Guards::is_alphabetic('1', "Not a letter!"), Err(String::from("Not a letter!")));
+	/// assert!(Guards::is_alphabetic('-', "Not a letter!").is_err());
+	/// ```
+	pub fn is_alphabetic<E: Into<String>>(
+		value: char,
+		error_message: E,
+	) -> Result<char, String> {
+		if value.is_alphabetic() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is an ASCII character.
+	///
+	/// Returns [`Ok`] if `value` is in the ASCII range, otherwise returns [`Err`] with the given `error_message`.
+	/// See [`Guards::is_ascii`] for strings.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_ascii_char(',', "?!"), Ok(','));
+	/// assert_eq!(Guards::is_ascii_char('\t', "?!"), Ok('\t'));
+	///
+	/// assert_eq!(Guards::is_ascii_char('é', "Not ASCII!"), Err(String::from("Not ASCII!")));
+	/// ```
+	pub fn is_ascii_char<E: Into<String>>(
+		value: char,
+		error_message: E,
+	) -> Result<char, String> {
+		if value.is_ascii() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// Returns [`Ok`] if the character is between, otherwise returns [`Err`] with the given `error_message`.
+	/// Characters are compared by their code point, so `'A'` is before `'a'`. See [`Guards::is_between`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::is_char_between('q', 'a', 'z', Bound::Inclusive, "?!"), Ok('q'));
+	/// assert_eq!(Guards::is_char_between('z', 'a', 'z', Bound::Inclusive, "?!"), Ok('z'));
+	///
+	/// assert_eq!(Guards::is_char_between('Q', 'a', 'z', Bound::Inclusive, "Not a-z!"), Err(String::from("Not a-z!")));
+	/// assert!(Guards::is_char_between('z', 'a', 'z', Bound::Exclusive, "Not a-z!").is_err());
+	/// ```
+	pub fn is_char_between<E: Into<String>>(
+		value: char,
+		lower_bound: char,
+		upper_bound: char,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<char, String> {
+		Guards::is_between(value, lower_bound, upper_bound, bound_mode, error_message)
+	}
+}
+
+impl Guard<char> {
+	/// Ensures that `value` is an ASCII digit.
+	///
+	/// See [Guards::is_ascii_digit] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new('3').is_ascii_digit("?!").result();
+	/// assert_eq!(pass, Ok('3'));
+	///
+	/// let fail = Guard::new('\u{0663}').is_ascii_digit("Verbosity must be 0-9").result();
+	/// assert_eq!(fail, Err(String::from("Verbosity must be 0-9")));
+	/// ```
+	pub fn is_ascii_digit<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_ascii_digit", |value| {
+			Guards::is_ascii_digit(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is numeric.
+	///
+	/// See [Guards::is_numeric_char] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new('\u{0663}').is_numeric_char("?!").result();
+	/// assert_eq!(pass, Ok('\u{0663}'));
+	///
+	/// let fail = Guard::new('k').is_numeric_char("Expected a number").result();
+	/// assert_eq!(fail, Err(String::from("Expected a number")));
+	/// ```
+	pub fn is_numeric_char<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_numeric_char", |value| {
+			Guards::is_numeric_char(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is alphabetic.
+	///
+	/// See [Guards::is_alphabetic] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new('v').is_alphabetic("?!").result();
+	/// assert_eq!(pass, Ok('v'));
+	///
+	/// let fail = Guard::new('-').is_alphabetic("Flags must be letters").result();
+	/// assert_eq!(fail, Err(String::from("Flags must be letters")));
+	/// ```
+	pub fn is_alphabetic<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_alphabetic", |value| {
+			Guards::is_alphabetic(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is an ASCII character.
+	///
+	/// See [Guards::is_ascii_char] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(';').is_ascii_char("?!").result();
+	/// assert_eq!(pass, Ok(';'));
+	///
+	/// let fail = Guard::new('、').is_ascii_char("Separators must be ASCII").result();
+	/// assert_eq!(fail, Err(String::from("Separators must be ASCII")));
+	/// ```
+	pub fn is_ascii_char<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_ascii_char", |value| {
+			Guards::is_ascii_char(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// See [Guards::is_char_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let pass = Guard::new('c').is_char_between('a', 'f', Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok('c'));
+	///
+	/// let fail = Guard::new('g').is_char_between('a', 'f', Bound::Inclusive, "Not a hex letter").result();
+	/// assert_eq!(fail, Err(String::from("Not a hex letter")));
+	/// ```
+	pub fn is_char_between<E: Into<String>>(
+		self,
+		lower_bound: char,
+		upper_bound: char,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("is_char_between", |value| {
+			Guards::is_char_between(*value, lower_bound, upper_bound, bound_mode, error_message).map(|_| ())
+		})
+	}
+}
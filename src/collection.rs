@@ -0,0 +1,3329 @@
+use std::{
+	borrow::{
+		Borrow,
+		Cow,
+	},
+	cmp::Ordering,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+		HashMap,
+		HashSet,
+		VecDeque,
+	},
+	fmt::Display,
+	hash::{
+		BuildHasher,
+		Hash,
+	},
+	ops::Range,
+	rc::Rc,
+	sync::Arc,
+};
+
+use crate::{
+	guards::ensure_ordered,
+	Bound,
+	Guard,
+	Guards,
+	Integer,
+	Number,
+};
+
+/// A value with a length, like a string or a collection.
+///
+/// Implemented for strings, slices, arrays and the standard collections, and for references, [`Box`], [`Rc`],
+/// [`Arc`] and [`Cow`] of those. Strings are measured in bytes, like [`str::len`], use the character guards like
+/// [`Guards::has_min_chars`] to count characters.
+///
+/// The length guards like [`Guards::is_not_empty`] require this trait, so custom string types that only
+/// implement [`AsRef<str>`] must implement it too, usually by returning `self.as_ref().len()`.
+///
+/// ## Example
+/// ```
+/// use fluent_guards::{Guard, HasLength};
+///
+/// struct Playlist {
+/// 	songs: Vec<String>,
+/// }
+///
+/// impl HasLength for Playlist {
+/// 	fn len(&self) -> usize {
+/// 		self.songs.len()
+/// 	}
+/// }
+///
+/// let playlist = Playlist { songs: vec![String::from("Crab Rave")] };
+/// assert!(Guard::new(playlist).is_not_empty("Playlist is empty!").result().is_ok());
+/// ```
+pub trait HasLength {
+	/// Returns the length of the value.
+	fn len(&self) -> usize;
+
+	/// Returns `true` if the length of the value is zero.
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+impl<T: HasLength + ?Sized> HasLength for &T {
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+}
+
+impl<T: HasLength + ?Sized> HasLength for Box<T> {
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+}
+
+impl<T: HasLength + ?Sized> HasLength for Rc<T> {
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+}
+
+impl<T: HasLength + ?Sized> HasLength for Arc<T> {
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+}
+
+impl<T: HasLength + ToOwned + ?Sized> HasLength for Cow<'_, T> {
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+}
+
+impl HasLength for str {
+	fn len(&self) -> usize {
+		str::len(self)
+	}
+}
+
+impl HasLength for String {
+	fn len(&self) -> usize {
+		String::len(self)
+	}
+}
+
+impl<T> HasLength for [T] {
+	fn len(&self) -> usize {
+		<[T]>::len(self)
+	}
+}
+
+impl<T, const N: usize> HasLength for [T; N] {
+	fn len(&self) -> usize {
+		N
+	}
+}
+
+impl<T> HasLength for Vec<T> {
+	fn len(&self) -> usize {
+		Vec::len(self)
+	}
+}
+
+impl<T> HasLength for VecDeque<T> {
+	fn len(&self) -> usize {
+		VecDeque::len(self)
+	}
+}
+
+impl<K, V, S> HasLength for HashMap<K, V, S> {
+	fn len(&self) -> usize {
+		HashMap::len(self)
+	}
+}
+
+impl<T, S> HasLength for HashSet<T, S> {
+	fn len(&self) -> usize {
+		HashSet::len(self)
+	}
+}
+
+impl<K, V> HasLength for BTreeMap<K, V> {
+	fn len(&self) -> usize {
+		BTreeMap::len(self)
+	}
+}
+
+impl<T> HasLength for BTreeSet<T> {
+	fn len(&self) -> usize {
+		BTreeSet::len(self)
+	}
+}
+
+/// A map that can be asked whether it contains a key.
+///
+/// Implemented for [`HashMap`] and [`BTreeMap`]. `Q` is the type of the key to look up, which can be a borrowed
+/// form of the key type, so a `HashMap<String, V>` can be checked for a `&str` key.
+pub trait ContainsKey<Q: ?Sized> {
+	/// Returns `true` if the map contains `key`.
+	fn contains_key(
+		&self,
+		key: &Q,
+	) -> bool;
+}
+
+impl<Q: ?Sized, T: ContainsKey<Q> + ?Sized> ContainsKey<Q> for &T {
+	fn contains_key(
+		&self,
+		key: &Q,
+	) -> bool {
+		(**self).contains_key(key)
+	}
+}
+
+impl<K: Borrow<Q> + Eq + Hash, Q: Eq + Hash + ?Sized, V, S: BuildHasher> ContainsKey<Q> for HashMap<K, V, S> {
+	fn contains_key(
+		&self,
+		key: &Q,
+	) -> bool {
+		HashMap::contains_key(self, key)
+	}
+}
+
+impl<K: Borrow<Q> + Ord, Q: Ord + ?Sized, V> ContainsKey<Q> for BTreeMap<K, V> {
+	fn contains_key(
+		&self,
+		key: &Q,
+	) -> bool {
+		BTreeMap::contains_key(self, key)
+	}
+}
+
+/// A map whose entries can be iterated.
+///
+/// Implemented for [`HashMap`] and [`BTreeMap`].
+pub trait Entries {
+	/// The type of the keys.
+	type Key;
+
+	/// The type of the values.
+	type Value;
+
+	/// Returns an iterator over the entries of the map, in the iteration order of the map.
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
+}
+
+impl<T: Entries + ?Sized> Entries for &T {
+	type Key = T::Key;
+	type Value = T::Value;
+
+	fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+		(**self).entries()
+	}
+}
+
+impl<K, V, S> Entries for HashMap<K, V, S> {
+	type Key = K;
+	type Value = V;
+
+	fn entries(&self) -> impl Iterator<Item = (&K, &V)> {
+		self.iter()
+	}
+}
+
+impl<K, V> Entries for BTreeMap<K, V> {
+	type Key = K;
+	type Value = V;
+
+	fn entries(&self) -> impl Iterator<Item = (&K, &V)> {
+		self.iter()
+	}
+}
+
+/// A range given by its start and end.
+///
+/// Implemented for pairs `(start, end)` and [`Range`].
+pub trait Interval {
+	/// The type of the endpoints.
+	type Endpoint;
+
+	/// Returns the start of the range.
+	fn start(&self) -> &Self::Endpoint;
+
+	/// Returns the end of the range.
+	fn end(&self) -> &Self::Endpoint;
+}
+
+impl<T: Interval + ?Sized> Interval for &T {
+	type Endpoint = T::Endpoint;
+
+	fn start(&self) -> &Self::Endpoint {
+		(**self).start()
+	}
+
+	fn end(&self) -> &Self::Endpoint {
+		(**self).end()
+	}
+}
+
+impl<U> Interval for (U, U) {
+	type Endpoint = U;
+
+	fn start(&self) -> &U {
+		&self.0
+	}
+
+	fn end(&self) -> &U {
+		&self.1
+	}
+}
+
+impl<U> Interval for Range<U> {
+	type Endpoint = U;
+
+	fn start(&self) -> &U {
+		&self.start
+	}
+
+	fn end(&self) -> &U {
+		&self.end
+	}
+}
+
+impl Guards {
+	/// Ensures that `value` is not empty.
+	///
+	/// Returns [`Ok`] if the string or collection has at least one element, otherwise returns [`Err`] with the
+	/// given `error_message`. Whitespace counts as a character, so `" "` is not empty. Works on any [`HasLength`]
+	/// value, custom [`AsRef<str>`] types need to implement it as well.
+	///
+	/// ## Example
+	/// ```
+	/// use std::rc::Rc;
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_not_empty("Ferris", "?!"), Ok("Ferris"));
+	/// assert_eq!(Guards::is_not_empty(String::from("Ferris"), "?!"), Ok(String::from("Ferris")));
+	/// assert_eq!(Guards::is_not_empty(" ", "?!"), Ok(" "));
+	/// assert_eq!(Guards::is_not_empty(vec![1, 2, 3], "?!"), Ok(vec![1, 2, 3]));
+	/// assert_eq!(Guards::is_not_empty(&[0][..], "?!"), Ok(&[0][..]));
+	/// assert!(Guards::is_not_empty(Rc::<str>::from("Ferris"), "?!").is_ok());
+	///
+	/// assert_eq!(Guards::is_not_empty("", "Name was empty!"), Err(String::from("Name was empty!")));
+	/// assert!(Guards::is_not_empty(String::new(), "Name was empty!").is_err());
+	/// assert!(Guards::is_not_empty(Vec::<u32>::new(), "No recipients!").is_err());
+	/// ```
+	pub fn is_not_empty<T: HasLength, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.is_empty() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is empty.
+	///
+	/// Returns [`Ok`] if the string or collection has no elements, otherwise returns [`Err`] with the given
+	/// `error_message`. Whitespace counts as a character, so `" "` is not empty. Works on any [`HasLength`] value,
+	/// custom [`AsRef<str>`] types need to implement it as well.
+	///
+	/// ## Example
+	/// ```
+	/// use std::{collections::HashMap, sync::Arc};
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_empty("", "?!"), Ok(""));
+	/// assert_eq!(Guards::is_empty(String::new(), "?!"), Ok(String::new()));
+	/// assert!(Guards::is_empty(HashMap::<String, u32>::new(), "?!").is_ok());
+	/// assert!(Guards::is_empty(Arc::<[u8]>::from([]), "?!").is_ok());
+	///
+	/// assert_eq!(Guards::is_empty("Ferris", "Value was not empty!"), Err(String::from("Value was not empty!")));
+	/// assert!(Guards::is_empty(" ", "Value was not empty!").is_err());
+	/// assert!(Guards::is_empty(vec![()], "Value was not empty!").is_err());
+	/// ```
+	pub fn is_empty<T: HasLength, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.is_empty() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has exactly `len` elements.
+	///
+	/// Returns [`Ok`] if the length of the string or collection is `len`, otherwise returns [`Err`] with the
+	/// given `error_message`. Strings are measured in bytes, see [`HasLength`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_len(vec![0, 0, 0], 3, "?!"), Ok(vec![0, 0, 0]));
+	/// assert_eq!(Guards::has_len([1, 2], 2, "?!"), Ok([1, 2]));
+	/// assert_eq!(Guards::has_len("abc", 3, "?!"), Ok("abc"));
+	/// assert_eq!(Guards::has_len("", 0, "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::has_len(vec![0, 0], 3, "Expected 3 values!"), Err(String::from("Expected 3 values!")));
+	/// assert!(Guards::has_len(vec![0, 0, 0, 0], 3, "Expected 3 values!").is_err());
+	/// assert!(Guards::has_len("日本", 2, "Expected 2 bytes!").is_err());
+	/// ```
+	pub fn has_len<T: HasLength, E: Into<String>>(
+		value: T,
+		len: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.len() == len {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the length of `value` is between `min` and `max`.
+	///
+	/// Returns [`Ok`] if the length of the string or collection is between, otherwise returns [`Err`] with the
+	/// given `error_message`. Strings are measured in bytes, see [`HasLength`], and `bound_mode` works like in
+	/// [`Guards::is_between`].
+	///
+	/// ## Example
+	/// ```
+	/// use std::collections::HashMap;
+	///
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::len_between(vec![1, 2, 3], 1, 5, Bound::Inclusive, "?!"), Ok(vec![1, 2, 3]));
+	/// assert_eq!(Guards::len_between("abc", 1, 5, Bound::Exclusive, "?!"), Ok("abc"));
+	/// assert!(Guards::len_between(HashMap::from([(1, 'a'), (2, 'b')]), 1, 5, Bound::Inclusive, "?!").is_ok());
+	///
+	/// // Exactly `min` and exactly `max`
+	/// assert_eq!(Guards::len_between(vec![1], 1, 3, Bound::Inclusive, "?!"), Ok(vec![1]));
+	/// assert_eq!(Guards::len_between(vec![1, 2, 3], 1, 3, Bound::Inclusive, "?!"), Ok(vec![1, 2, 3]));
+	/// assert_eq!(Guards::len_between(vec![1], 1, 3, Bound::Exclusive, "Invalid batch size!"), Err(String::from("Invalid batch size!")));
+	/// assert!(Guards::len_between(vec![1, 2, 3], 1, 3, Bound::Exclusive, "Invalid batch size!").is_err());
+	///
+	/// assert!(Guards::len_between(Vec::<u8>::new(), 1, 3, Bound::Inclusive, "Invalid batch size!").is_err());
+	/// assert!(Guards::len_between(vec![0; 4], 1, 3, Bound::Inclusive, "Invalid batch size!").is_err());
+	/// ```
+	pub fn len_between<T: HasLength, E: Into<String>>(
+		value: T,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		Guards::is_between(value.len(), min, max, bound_mode, error_message)?;
+		Ok(value)
+	}
+
+	/// Ensures that `value` contains `needle`.
+	///
+	/// Returns [`Ok`] if at least one element of the slice, vector or array equals `needle`, otherwise returns
+	/// [`Err`] with the given `error_message`. An empty collection never contains the needle.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::contains_element(vec!["dev", "prod"], &"prod", "?!"), Ok(vec!["dev", "prod"]));
+	/// assert_eq!(Guards::contains_element([1, 2, 3], &3, "?!"), Ok([1, 2, 3]));
+	///
+	/// assert_eq!(Guards::contains_element(vec!["dev"], &"prod", "Missing prod tag!"), Err(String::from("Missing prod tag!")));
+	/// assert!(Guards::contains_element(Vec::<&str>::new(), &"prod", "Missing prod tag!").is_err());
+	/// ```
+	pub fn contains_element<T: AsRef<[U]>, U: PartialEq, E: Into<String>>(
+		value: T,
+		needle: &U,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().contains(needle) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` does not contain `needle`.
+	///
+	/// Returns [`Ok`] if no element of the slice, vector or array equals `needle`, otherwise returns [`Err`] with
+	/// the given `error_message`. An empty collection always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::lacks_element(vec![4, 8, 15], &0, "?!"), Ok(vec![4, 8, 15]));
+	/// assert_eq!(Guards::lacks_element(Vec::<u32>::new(), &0, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::lacks_element(vec![4, 0, 15], &0, "Ids must not be 0!"), Err(String::from("Ids must not be 0!")));
+	/// assert!(Guards::lacks_element(&[0][..], &0, "Ids must not be 0!").is_err());
+	/// ```
+	pub fn lacks_element<T: AsRef<[U]>, U: PartialEq, E: Into<String>>(
+		value: T,
+		needle: &U,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().contains(needle) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every element of `value` satisfies `predicate`.
+	///
+	/// Returns [`Ok`] if `predicate` returns `true` for all elements of the slice, vector or array, otherwise
+	/// returns [`Err`] with the given `error_message`. The elements are checked in order and checking stops at
+	/// the first failing element. An empty collection always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_port = |port: &u32| (1..=65535).contains(port);
+	///
+	/// assert_eq!(Guards::all_satisfy(vec![80, 443], is_port, "?!"), Ok(vec![80, 443]));
+	/// assert_eq!(Guards::all_satisfy(Vec::new(), is_port, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::all_satisfy(vec![80, 0], is_port, "Invalid port!"), Err(String::from("Invalid port!")));
+	/// assert!(Guards::all_satisfy([70000], is_port, "Invalid port!").is_err());
+	/// ```
+	pub fn all_satisfy<T: AsRef<[U]>, U, F: FnMut(&U) -> bool, E: Into<String>>(
+		value: T,
+		predicate: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().iter().all(predicate) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that at least one element of `value` satisfies `predicate`.
+	///
+	/// Returns [`Ok`] if `predicate` returns `true` for any element of the slice, vector or array, otherwise
+	/// returns [`Err`] with the given `error_message`. The elements are checked in order and checking stops at
+	/// the first passing element. An empty collection always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_admin = |role: &&str| *role == "admin";
+	///
+	/// assert_eq!(Guards::any_satisfies(vec!["user", "admin"], is_admin, "?!"), Ok(vec!["user", "admin"]));
+	///
+	/// assert_eq!(Guards::any_satisfies(vec!["user"], is_admin, "Admin role required!"), Err(String::from("Admin role required!")));
+	/// assert!(Guards::any_satisfies(Vec::new(), is_admin, "Admin role required!").is_err());
+	/// ```
+	pub fn any_satisfies<T: AsRef<[U]>, U, F: FnMut(&U) -> bool, E: Into<String>>(
+		value: T,
+		predicate: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().iter().any(predicate) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that no two elements of `value` are equal.
+	///
+	/// Returns [`Ok`] if every element of the slice, vector or array is unique, otherwise returns [`Err`] with the
+	/// given `error_message`. The elements are tracked in a [`HashSet`], use [`Guards::has_unique_elements_by`]
+	/// for elements that are not [`Hash`]. Empty and single element collections always pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_unique_elements(vec![3, 1, 2], "?!"), Ok(vec![3, 1, 2]));
+	/// assert_eq!(Guards::has_unique_elements(Vec::<u32>::new(), "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::has_unique_elements(["solo"], "?!"), Ok(["solo"]));
+	///
+	/// assert_eq!(Guards::has_unique_elements(vec![3, 1, 3], "Duplicate ids!"), Err(String::from("Duplicate ids!")));
+	/// ```
+	pub fn has_unique_elements<T: AsRef<[U]>, U: Eq + Hash, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let mut seen = HashSet::new();
+
+		if value.as_ref().iter().all(|element| seen.insert(element)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that no two elements of `value` are equal according to `is_equal`.
+	///
+	/// Returns [`Ok`] if `is_equal` returns `false` for every pair of elements of the slice, vector or array,
+	/// otherwise returns [`Err`] with the given `error_message`. Every pair is compared, which takes quadratic
+	/// time, so prefer [`Guards::has_unique_elements`] or [`Guards::has_unique_elements_by_key`] for hashable
+	/// values. Empty and single element collections always pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_close = |a: &f64, b: &f64| (a - b).abs() < 0.01;
+	///
+	/// assert_eq!(Guards::has_unique_elements_by(vec![0.1, 0.2], is_close, "?!"), Ok(vec![0.1, 0.2]));
+	/// assert_eq!(Guards::has_unique_elements_by(Vec::new(), is_close, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::has_unique_elements_by(vec![0.1, 0.2, 0.1001], is_close, "Duplicate values!"), Err(String::from("Duplicate values!")));
+	/// ```
+	pub fn has_unique_elements_by<T: AsRef<[U]>, U, F: FnMut(&U, &U) -> bool, E: Into<String>>(
+		value: T,
+		mut is_equal: F,
+		error_message: E,
+	) -> Result<T, String> {
+		let elements = value.as_ref();
+		let has_duplicate =
+			elements.iter().enumerate().any(|(index, a)| elements[index + 1..].iter().any(|b| is_equal(a, b)));
+
+		if !has_duplicate {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that no two elements of `value` have the same key.
+	///
+	/// Returns [`Ok`] if `key` returns a different value for every element of the slice, vector or array,
+	/// otherwise returns [`Err`] with the given `error_message`. The keys are tracked in a [`HashSet`]. Empty and
+	/// single element collections always pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// struct User {
+	/// 	id: u32,
+	/// 	name: &'static str,
+	/// }
+	///
+	/// let users = vec![User { id: 1, name: "Ferris" }, User { id: 2, name: "Ferris" }];
+	///
+	/// assert!(Guards::has_unique_elements_by_key(&users, |user| user.id, "?!").is_ok());
+	/// assert_eq!(Guards::has_unique_elements_by_key(&users, |user| user.name, "Duplicate names!").err(), Some(String::from("Duplicate names!")));
+	/// ```
+	pub fn has_unique_elements_by_key<T: AsRef<[U]>, U, K: Eq + Hash, F: FnMut(&U) -> K, E: Into<String>>(
+		value: T,
+		mut key: F,
+		error_message: E,
+	) -> Result<T, String> {
+		let mut seen = HashSet::new();
+
+		if value.as_ref().iter().all(|element| seen.insert(key(element))) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the elements of `value` are in non-decreasing order.
+	///
+	/// Returns [`Ok`] if every element of the slice, vector or array is less than or equal to the next one,
+	/// otherwise returns [`Err`] with the given `error_message`. Equal neighbours are allowed, use
+	/// [`Guards::is_sorted_strict`] to reject them. Elements that cannot be compared, like [`f64::NAN`], always
+	/// fail. Empty and single element collections pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_sorted(vec![1, 2, 2, 3], "?!"), Ok(vec![1, 2, 2, 3]));
+	/// assert_eq!(Guards::is_sorted(Vec::<u32>::new(), "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::is_sorted([7], "?!"), Ok([7]));
+	///
+	/// assert_eq!(Guards::is_sorted(vec![1, 3, 2], "Not sorted!"), Err(String::from("Not sorted!")));
+	/// assert!(Guards::is_sorted([1.0, f64::NAN, 2.0], "Not sorted!").is_err());
+	/// assert!(Guards::is_sorted([f64::NAN], "Not sorted!").is_err());
+	/// ```
+	pub fn is_sorted<T: AsRef<[U]>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if is_ordered(value.as_ref(), |ordering| ordering.is_le()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the elements of `value` are in strictly increasing order.
+	///
+	/// Returns [`Ok`] if every element of the slice, vector or array is less than the next one, otherwise returns
+	/// [`Err`] with the given `error_message`. Elements that cannot be compared, like [`f64::NAN`], always fail.
+	/// Empty and single element collections pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_sorted_strict(vec![1, 2, 3], "?!"), Ok(vec![1, 2, 3]));
+	/// assert_eq!(Guards::is_sorted_strict(Vec::<u32>::new(), "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::is_sorted_strict([7], "?!"), Ok([7]));
+	///
+	/// assert_eq!(Guards::is_sorted_strict(vec![1, 2, 2, 3], "Not increasing!"), Err(String::from("Not increasing!")));
+	/// assert!(Guards::is_sorted_strict(vec![3, 2, 1], "Not increasing!").is_err());
+	/// assert!(Guards::is_sorted_strict([1.0, f64::NAN], "Not increasing!").is_err());
+	/// ```
+	pub fn is_sorted_strict<T: AsRef<[U]>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if is_ordered(value.as_ref(), |ordering| ordering.is_lt()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the elements of `value` are in non-increasing order.
+	///
+	/// Returns [`Ok`] if every element of the slice, vector or array is greater than or equal to the next one,
+	/// otherwise returns [`Err`] with the given `error_message`. Equal neighbours are allowed. Elements that
+	/// cannot be compared, like [`f64::NAN`], always fail. Empty and single element collections pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_sorted_desc(vec![3, 2, 2, 1], "?!"), Ok(vec![3, 2, 2, 1]));
+	/// assert_eq!(Guards::is_sorted_desc(Vec::<u32>::new(), "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::is_sorted_desc([7], "?!"), Ok([7]));
+	///
+	/// assert_eq!(Guards::is_sorted_desc(vec![1, 2, 3], "Not descending!"), Err(String::from("Not descending!")));
+	/// assert!(Guards::is_sorted_desc([2.0, f64::NAN, 1.0], "Not descending!").is_err());
+	/// ```
+	pub fn is_sorted_desc<T: AsRef<[U]>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if is_ordered(value.as_ref(), |ordering| ordering.is_ge()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every pair of neighbouring elements of `value` is in order according to `in_order`.
+	///
+	/// Returns [`Ok`] if `in_order(a, b)` returns `true` for every element `a` and the element `b` after it,
+	/// otherwise returns [`Err`] with the given `error_message`. This matches [`slice::is_sorted_by`]. Empty and
+	/// single element collections pass without calling `in_order`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let by_len = |a: &&str, b: &&str| a.len() <= b.len();
+	///
+	/// assert_eq!(Guards::is_sorted_by(vec!["a", "bb", "cc"], by_len, "?!"), Ok(vec!["a", "bb", "cc"]));
+	/// assert_eq!(Guards::is_sorted_by(Vec::new(), by_len, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::is_sorted_by(vec!["bb", "a"], by_len, "Not sorted by length!"), Err(String::from("Not sorted by length!")));
+	/// ```
+	pub fn is_sorted_by<T: AsRef<[U]>, U, F: FnMut(&U, &U) -> bool, E: Into<String>>(
+		value: T,
+		mut in_order: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().windows(2).all(|pair| in_order(&pair[0], &pair[1])) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every element of `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// Returns [`Ok`] if all elements of the slice, vector or array are between, otherwise returns [`Err`] with
+	/// the given `error_message`. `bound_mode` works like in [`Guards::is_between`], so elements that cannot be
+	/// compared, like [`f64::NAN`], fail. An empty collection always passes, unless the bounds are inverted, in
+	/// which case [`Err`] with `"invalid bounds: lower > upper"` is returned.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::all_between(vec![1, 5, 10], 1, 10, Bound::Inclusive, "?!"), Ok(vec![1, 5, 10]));
+	/// assert_eq!(Guards::all_between(Vec::<i32>::new(), 1, 10, Bound::Exclusive, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(
+	/// 	Guards::all_between(vec![1, 5, 10], 1, 10, Bound::Exclusive, "Out of range!"),
+	/// 	Err(String::from("Out of range!"))
+	/// );
+	/// assert!(Guards::all_between(vec![0, 5], 1, 10, Bound::Inclusive, "Out of range!").is_err());
+	/// assert!(Guards::all_between([0.5, f64::NAN], 0.0, 1.0, Bound::Inclusive, "Out of range!").is_err());
+	///
+	/// assert_eq!(
+	/// 	Guards::all_between(Vec::<i32>::new(), 10, 1, Bound::Inclusive, "Out of range!"),
+	/// 	Err(String::from("invalid bounds: lower > upper"))
+	/// );
+	/// ```
+	pub fn all_between<T: AsRef<[U]>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		lower_bound: U,
+		upper_bound: U,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		ensure_ordered(&lower_bound, &upper_bound)?;
+
+		let is_between = |element: &U| match bound_mode {
+			Bound::Exclusive => *element > lower_bound && *element < upper_bound,
+			Bound::Inclusive => *element >= lower_bound && *element <= upper_bound,
+		};
+
+		if value.as_ref().iter().all(is_between) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the sum of the elements of `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// Returns [`Ok`] if the sum is between, otherwise returns [`Err`] with the given `error_message`.
+	/// `bound_mode` works like in [`Guards::is_between`], and the sum of an empty collection is zero. The sum is
+	/// computed in [`Number::Accumulator`], so it only overflows for [`i128`], [`u128`], [`f64`] and decimal
+	/// elements, in which case [`Err`] with `"sum overflows the element type"` is returned instead. Float sums
+	/// are rounded like repeated `+`, so compare them with some tolerance.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::sum_is_between(vec![0.25, 0.25, 0.5], 0.999, 1.001, Bound::Inclusive, "?!"), Ok(vec![0.25, 0.25, 0.5]));
+	/// assert_eq!(Guards::sum_is_between(Vec::<i32>::new(), 0, 10, Bound::Inclusive, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::sum_is_between(vec![4, 6], 0, 10, Bound::Exclusive, "Sum out of range!"), Err(String::from("Sum out of range!")));
+	/// assert!(Guards::sum_is_between([0.5, f64::NAN], 0.0, 1.0, Bound::Inclusive, "Sum out of range!").is_err());
+	///
+	/// assert_eq!(Guards::sum_is_between(vec![200u8, 55], 0, 255, Bound::Inclusive, "?!"), Ok(vec![200u8, 55]));
+	/// assert_eq!(
+	/// 	Guards::sum_is_between(vec![200u8, 100], 0, 255, Bound::Inclusive, "Sum out of range!"),
+	/// 	Err(String::from("Sum out of range!"))
+	/// );
+	/// assert_eq!(
+	/// 	Guards::sum_is_between([i128::MAX, 1], 0, i128::MAX, Bound::Inclusive, "Sum out of range!"),
+	/// 	Err(String::from("sum overflows the element type"))
+	/// );
+	/// assert_eq!(
+	/// 	Guards::sum_is_between([f64::MAX, f64::MAX], 0.0, f64::INFINITY, Bound::Inclusive, "Sum out of range!"),
+	/// 	Err(String::from("sum overflows the element type"))
+	/// );
+	/// ```
+	pub fn sum_is_between<T: AsRef<[U]>, U: Number, E: Into<String>>(
+		value: T,
+		lower_bound: U,
+		upper_bound: U,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		let sum = checked_sum(value.as_ref()).ok_or_else(|| String::from("sum overflows the element type"))?;
+
+		Guards::is_between(
+			sum,
+			lower_bound.to_accumulator(),
+			upper_bound.to_accumulator(),
+			bound_mode,
+			error_message,
+		)?;
+		Ok(value)
+	}
+
+	/// Ensures that the mean of the elements of `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// Returns [`Ok`] if the mean is between, otherwise returns [`Err`] with the given `error_message`.
+	/// `bound_mode` works like in [`Guards::is_between`]. An empty collection has no mean and always fails. The
+	/// mean is never rounded: instead of dividing, the sum is compared with the bounds multiplied by the number of
+	/// elements, so `[1, 2]` with a mean of `1.5` is above an upper bound of `1`.
+	///
+	/// Like in [`Guards::sum_is_between`], the sum is computed in [`Number::Accumulator`], and if it overflows,
+	/// [`Err`] with `"sum overflows the element type"` is returned.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::mean_is_between(vec![10, 20, 30], 0, 20, Bound::Inclusive, "?!"), Ok(vec![10, 20, 30]));
+	/// assert_eq!(Guards::mean_is_between([1, 2], 1, 2, Bound::Exclusive, "?!"), Ok([1, 2]));
+	///
+	/// assert_eq!(Guards::mean_is_between(vec![10, 20, 30], 0, 20, Bound::Exclusive, "Mean out of range!"), Err(String::from("Mean out of range!")));
+	/// assert!(Guards::mean_is_between([1, 2], 0, 1, Bound::Inclusive, "Mean out of range!").is_err());
+	/// assert!(Guards::mean_is_between([1.0, 2.0], 0.0, 1.0, Bound::Inclusive, "Mean out of range!").is_err());
+	/// assert!(Guards::mean_is_between(Vec::<f64>::new(), 0.0, 1.0, Bound::Inclusive, "Mean out of range!").is_err());
+	///
+	/// assert_eq!(Guards::mean_is_between(vec![200u8, 200], 0, 255, Bound::Inclusive, "?!"), Ok(vec![200u8, 200]));
+	/// assert_eq!(Guards::mean_is_between(vec![0i8; 200], 0, 10, Bound::Inclusive, "?!"), Ok(vec![0i8; 200]));
+	/// assert_eq!(Guards::mean_is_between([u128::MAX / 2, 1], 0, u128::MAX, Bound::Inclusive, "?!"), Ok([u128::MAX / 2, 1]));
+	/// assert_eq!(
+	/// 	Guards::mean_is_between([u128::MAX, 1], 0, u128::MAX, Bound::Inclusive, "Mean out of range!"),
+	/// 	Err(String::from("sum overflows the element type"))
+	/// );
+	/// ```
+	pub fn mean_is_between<T: AsRef<[U]>, U: Number, E: Into<String>>(
+		value: T,
+		lower_bound: U,
+		upper_bound: U,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		let elements = value.as_ref();
+		if elements.is_empty() {
+			return Err(error_message.into());
+		}
+
+		let sum = checked_sum(elements).ok_or_else(|| String::from("sum overflows the element type"))?;
+		let count = U::Accumulator::from_count(elements.len())
+			.ok_or_else(|| String::from("count overflows the element type"))?;
+		ensure_ordered(&lower_bound, &upper_bound)?;
+
+		let above_lower = cmp_scaled(sum, lower_bound.to_accumulator(), count);
+		let below_upper = cmp_scaled(sum, upper_bound.to_accumulator(), count);
+		let is_between = match bound_mode {
+			Bound::Exclusive => above_lower == Some(Ordering::Greater) && below_upper == Some(Ordering::Less),
+			Bound::Inclusive => {
+				matches!(above_lower, Some(Ordering::Greater | Ordering::Equal))
+					&& matches!(below_upper, Some(Ordering::Less | Ordering::Equal))
+			},
+		};
+
+		if is_between {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the first element of `value` satisfies `predicate`.
+	///
+	/// Returns [`Ok`] if the slice, vector or array is not empty and `predicate` returns `true` for its first
+	/// element, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_header = |row: &&str| row.starts_with('#');
+	///
+	/// assert_eq!(Guards::first_satisfies(vec!["# id,name", "1,Ferris"], is_header, "?!"), Ok(vec!["# id,name", "1,Ferris"]));
+	///
+	/// assert_eq!(Guards::first_satisfies(vec!["1,Ferris"], is_header, "Missing header!"), Err(String::from("Missing header!")));
+	/// assert!(Guards::first_satisfies(Vec::new(), is_header, "Missing header!").is_err());
+	/// ```
+	pub fn first_satisfies<T: AsRef<[U]>, U, F: FnOnce(&U) -> bool, E: Into<String>>(
+		value: T,
+		predicate: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().first().is_some_and(predicate) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the last element of `value` satisfies `predicate`.
+	///
+	/// Returns [`Ok`] if the slice, vector or array is not empty and `predicate` returns `true` for its last
+	/// element, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::last_satisfies([1, 2, 3], |last| *last > 2, "?!"), Ok([1, 2, 3]));
+	///
+	/// assert_eq!(Guards::last_satisfies([3, 2, 1], |last| *last > 2, "Last value too small!"), Err(String::from("Last value too small!")));
+	/// assert!(Guards::last_satisfies(Vec::<i32>::new(), |_| true, "Last value too small!").is_err());
+	/// ```
+	pub fn last_satisfies<T: AsRef<[U]>, U, F: FnOnce(&U) -> bool, E: Into<String>>(
+		value: T,
+		predicate: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().last().is_some_and(predicate) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the first element of `value` equals `expected`.
+	///
+	/// Returns [`Ok`] if the slice, vector or array is not empty and its first element equals `expected`,
+	/// otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::first_equals(vec!["BEGIN", "data"], &"BEGIN", "?!"), Ok(vec!["BEGIN", "data"]));
+	///
+	/// assert_eq!(Guards::first_equals(vec!["data"], &"BEGIN", "Missing BEGIN!"), Err(String::from("Missing BEGIN!")));
+	/// assert!(Guards::first_equals(Vec::<&str>::new(), &"BEGIN", "Missing BEGIN!").is_err());
+	/// ```
+	pub fn first_equals<T: AsRef<[U]>, U: PartialEq, E: Into<String>>(
+		value: T,
+		expected: &U,
+		error_message: E,
+	) -> Result<T, String> {
+		Guards::first_satisfies(value, |first| first == expected, error_message)
+	}
+
+	/// Ensures that the last element of `value` equals `expected`.
+	///
+	/// Returns [`Ok`] if the slice, vector or array is not empty and its last element equals `expected`,
+	/// otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::last_equals(vec!["data", "END"], &"END", "?!"), Ok(vec!["data", "END"]));
+	///
+	/// assert_eq!(Guards::last_equals(vec!["END", "data"], &"END", "Missing END!"), Err(String::from("Missing END!")));
+	/// assert!(Guards::last_equals(Vec::<&str>::new(), &"END", "Missing END!").is_err());
+	/// ```
+	pub fn last_equals<T: AsRef<[U]>, U: PartialEq, E: Into<String>>(
+		value: T,
+		expected: &U,
+		error_message: E,
+	) -> Result<T, String> {
+		Guards::last_satisfies(value, |last| last == expected, error_message)
+	}
+
+	/// Ensures that every element of `value` is in `allowed`.
+	///
+	/// Returns [`Ok`] if each element of the slice, vector or array is one of `allowed`, otherwise returns [`Err`]
+	/// with the given `error_message`. Duplicates on either side do not matter, and an empty collection is a
+	/// subset of everything.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_subset_of(vec!["sso", "sso"], ["sso", "audit"], "?!"), Ok(vec!["sso", "sso"]));
+	/// assert_eq!(Guards::is_subset_of(Vec::new(), ["sso", "audit"], "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::is_subset_of(Vec::<&str>::new(), [], "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::is_subset_of(vec!["sso", "beta"], ["sso", "audit"], "Flag not allowed!"), Err(String::from("Flag not allowed!")));
+	/// assert!(Guards::is_subset_of(vec!["sso"], [], "Flag not allowed!").is_err());
+	/// ```
+	pub fn is_subset_of<T: AsRef<[U]>, U: Eq + Hash, E: Into<String>>(
+		value: T,
+		allowed: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Result<T, String> {
+		let allowed: HashSet<U> = allowed.into_iter().collect();
+
+		if value.as_ref().iter().all(|element| allowed.contains(element)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every element of `required` is in `value`.
+	///
+	/// Returns [`Ok`] if each of `required` is an element of the slice, vector or array, otherwise returns
+	/// [`Err`] with the given `error_message`. Duplicates on either side do not matter, and every collection is a
+	/// superset of nothing.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_superset_of(vec!["read", "write", "read"], ["read"], "?!"), Ok(vec!["read", "write", "read"]));
+	/// assert_eq!(Guards::is_superset_of(Vec::<&str>::new(), [], "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::is_superset_of(vec!["read"], ["read", "write"], "Missing scope!"), Err(String::from("Missing scope!")));
+	/// assert!(Guards::is_superset_of(Vec::new(), ["read"], "Missing scope!").is_err());
+	/// ```
+	pub fn is_superset_of<T: AsRef<[U]>, U: Eq + Hash, E: Into<String>>(
+		value: T,
+		required: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Result<T, String> {
+		let elements: HashSet<&U> = value.as_ref().iter().collect();
+
+		if required.into_iter().all(|element| elements.contains(&element)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every element of `value` passes `check`.
+	///
+	/// Calls `check` on the elements of the slice, vector or array in order. Returns [`Ok`] if it returns [`Ok`]
+	/// for all of them, otherwise returns [`Err`] with the error of the first failing element, prefixed with
+	/// `name` and the index of the element, like `"items[3]: Quantity must be positive"`. An empty collection
+	/// always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_positive = |quantity: &i32| Guards::is_greater_than(*quantity, 0, "Quantity must be positive").map(|_| ());
+	///
+	/// assert_eq!(Guards::each_element(vec![1, 5], "items", is_positive), Ok(vec![1, 5]));
+	/// assert_eq!(Guards::each_element(Vec::new(), "items", is_positive), Ok(Vec::new()));
+	///
+	/// assert_eq!(
+	/// 	Guards::each_element(vec![1, 5, 0, -2], "items", is_positive),
+	/// 	Err(String::from("items[2]: Quantity must be positive"))
+	/// );
+	/// ```
+	pub fn each_element<T: AsRef<[U]>, U, F: FnMut(&U) -> Result<(), String>>(
+		value: T,
+		name: &str,
+		mut check: F,
+	) -> Result<T, String> {
+		for (index, element) in value.as_ref().iter().enumerate() {
+			check(element).map_err(|error| format!("{name}[{index}]: {error}"))?;
+		}
+
+		Ok(value)
+	}
+
+	/// Ensures that every element of `value` passes `check`, reporting all failing elements.
+	///
+	/// Works like [`Guards::each_element`], but calls `check` on every element instead of stopping at the first
+	/// failing one. The errors of all failing elements are prefixed with `name` and their index, and joined by
+	/// `"; "`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_positive = |quantity: &i32| Guards::is_greater_than(*quantity, 0, "Quantity must be positive").map(|_| ());
+	///
+	/// assert_eq!(Guards::each_element_all(vec![1, 5], "items", is_positive), Ok(vec![1, 5]));
+	///
+	/// assert_eq!(
+	/// 	Guards::each_element_all(vec![1, 5, 0, -2], "items", is_positive),
+	/// 	Err(String::from("items[2]: Quantity must be positive; items[3]: Quantity must be positive"))
+	/// );
+	/// ```
+	pub fn each_element_all<T: AsRef<[U]>, U, F: FnMut(&U) -> Result<(), String>>(
+		value: T,
+		name: &str,
+		mut check: F,
+	) -> Result<T, String> {
+		let errors: Vec<String> = value
+			.as_ref()
+			.iter()
+			.enumerate()
+			.filter_map(|(index, element)| check(element).err().map(|error| format!("{name}[{index}]: {error}")))
+			.collect();
+
+		if errors.is_empty() {
+			Ok(value)
+		} else {
+			Err(errors.join("; "))
+		}
+	}
+
+	/// Ensures that the map `value` contains `key`.
+	///
+	/// Returns [`Ok`] if the key is in the [`HashMap`] or [`BTreeMap`], otherwise returns [`Err`] with the given
+	/// `error_message`. See [`ContainsKey`] for the supported key types.
+	///
+	/// ## Example
+	/// ```
+	/// use std::collections::{BTreeMap, HashMap};
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// let config = HashMap::from([(String::from("host"), "localhost")]);
+	/// assert!(Guards::has_key(&config, "host", "?!").is_ok());
+	/// assert_eq!(Guards::has_key(&config, "port", "Missing port!"), Err(String::from("Missing port!")));
+	///
+	/// let ports = BTreeMap::from([(80, "http"), (443, "https")]);
+	/// assert!(Guards::has_key(&ports, &443, "?!").is_ok());
+	/// assert!(Guards::has_key(BTreeMap::<u16, &str>::new(), &443, "Missing port!").is_err());
+	/// ```
+	pub fn has_key<T: ContainsKey<Q>, Q: ?Sized, E: Into<String>>(
+		value: T,
+		key: &Q,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.contains_key(key) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the map `value` does not contain `key`.
+	///
+	/// Returns [`Ok`] if the key is not in the [`HashMap`] or [`BTreeMap`], otherwise returns [`Err`] with the
+	/// given `error_message`. See [`ContainsKey`] for the supported key types.
+	///
+	/// ## Example
+	/// ```
+	/// use std::collections::HashMap;
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// let config = HashMap::from([("host", "localhost"), ("password", "hunter2")]);
+	/// assert!(Guards::lacks_key(HashMap::<&str, &str>::new(), "password", "?!").is_ok());
+	/// assert!(Guards::lacks_key(&config, "debug", "?!").is_ok());
+	///
+	/// assert_eq!(
+	/// 	Guards::lacks_key(&config, "password", "Passwords do not belong in the config!"),
+	/// 	Err(String::from("Passwords do not belong in the config!"))
+	/// );
+	/// ```
+	pub fn lacks_key<T: ContainsKey<Q>, Q: ?Sized, E: Into<String>>(
+		value: T,
+		key: &Q,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.contains_key(key) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the map `value` contains every key in `keys`.
+	///
+	/// Returns [`Ok`] if all keys are in the [`HashMap`] or [`BTreeMap`], otherwise returns [`Err`] with the given
+	/// `error_message`. An empty list of keys always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use std::collections::HashMap;
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// let config = HashMap::from([("host", "localhost"), ("port", "8080")]);
+	/// assert!(Guards::has_keys(&config, &["host", "port"], "?!").is_ok());
+	/// assert!(Guards::has_keys(&config, &[] as &[&str], "?!").is_ok());
+	///
+	/// assert_eq!(Guards::has_keys(&config, &["host", "user"], "Missing keys!"), Err(String::from("Missing keys!")));
+	/// ```
+	pub fn has_keys<T: ContainsKey<Q>, Q, E: Into<String>>(
+		value: T,
+		keys: &[Q],
+		error_message: E,
+	) -> Result<T, String> {
+		if keys.iter().all(|key| value.contains_key(key)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every key of the map `value` is in `allowed`.
+	///
+	/// Returns [`Ok`] if the [`HashMap`] or [`BTreeMap`] has no other keys, otherwise returns [`Err`] with the
+	/// given `error_message` followed by the first unknown key, for example `"Unknown config key: colour"`. For a
+	/// [`HashMap`] which unknown key comes first is unspecified. Keys are compared with [`Eq`], so `"Port"` and
+	/// `"port"` are different keys, normalize the keys first to compare them without case. An empty map always
+	/// passes.
+	///
+	/// ## Example
+	/// ```
+	/// use std::collections::{BTreeMap, HashMap};
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// let allowed = ["host", "port"];
+	///
+	/// let config = HashMap::from([("host", "localhost"), ("port", "8080")]);
+	/// assert!(Guards::keys_are_subset_of(&config, allowed, "?!").is_ok());
+	/// assert!(Guards::keys_are_subset_of(HashMap::<&str, &str>::new(), allowed, "?!").is_ok());
+	///
+	/// let config = BTreeMap::from([("host", "localhost"), ("colour", "blue")]);
+	/// assert_eq!(Guards::keys_are_subset_of(&config, allowed, "Unknown config key"), Err(String::from("Unknown config key: colour")));
+	///
+	/// let config = BTreeMap::from([("Host", "localhost")]);
+	/// assert!(Guards::keys_are_subset_of(&config, allowed, "Unknown config key").is_err());
+	///
+	/// // Lowercase the keys to accept them in any case
+	/// let config: BTreeMap<String, &str> = config.into_iter().map(|(key, value)| (key.to_lowercase(), value)).collect();
+	/// assert!(Guards::keys_are_subset_of(&config, allowed.map(String::from), "?!").is_ok());
+	/// ```
+	pub fn keys_are_subset_of<T: Entries, E: Into<String>>(
+		value: T,
+		allowed: impl IntoIterator<Item = T::Key>,
+		error_message: E,
+	) -> Result<T, String>
+	where
+		T::Key: Eq + Hash + Display,
+	{
+		match unknown_keys(&value, allowed, false) {
+			None => Ok(value),
+			Some(keys) => Err(format!("{}: {}", error_message.into(), keys)),
+		}
+	}
+
+	/// Ensures that every value of the map `value` satisfies `predicate`.
+	///
+	/// Returns [`Ok`] if `predicate` returns `true` for all values of the [`HashMap`] or [`BTreeMap`], otherwise
+	/// returns [`Err`] with the given `error_message` followed by the key of the first failing value, for example
+	/// `"Invalid header: Cookie"`. For a [`HashMap`] which failing entry comes first is unspecified and may differ
+	/// between runs. An empty map always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use std::collections::{BTreeMap, HashMap};
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// let is_valid = |value: &&str| value.is_ascii() && value.len() < 8 * 1024;
+	///
+	/// let headers = HashMap::from([("Host", "example.com"), ("Accept", "*/*")]);
+	/// assert!(Guards::values_satisfy(&headers, is_valid, "?!").is_ok());
+	/// assert!(Guards::values_satisfy(HashMap::<&str, &str>::new(), is_valid, "?!").is_ok());
+	///
+	/// let headers = BTreeMap::from([("Host", "example.com"), ("X-Name", "Jörg")]);
+	/// assert_eq!(Guards::values_satisfy(&headers, is_valid, "Invalid header"), Err(String::from("Invalid header: X-Name")));
+	/// ```
+	pub fn values_satisfy<T: Entries, F: FnMut(&T::Value) -> bool, E: Into<String>>(
+		value: T,
+		mut predicate: F,
+		error_message: E,
+	) -> Result<T, String>
+	where
+		T::Key: Display,
+	{
+		Guards::entries_satisfy(value, |_, value| predicate(value), error_message)
+	}
+
+	/// Ensures that every entry of the map `value` satisfies `predicate`.
+	///
+	/// Returns [`Ok`] if `predicate` returns `true` for all keys and values of the [`HashMap`] or [`BTreeMap`],
+	/// otherwise returns [`Err`] with the given `error_message` followed by the key of the first failing entry.
+	/// For a [`HashMap`] which failing entry comes first is unspecified and may differ between runs. An empty map
+	/// always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use std::collections::BTreeMap;
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// let limits = BTreeMap::from([("cpu", 4), ("memory", 8192)]);
+	/// let is_valid = |key: &&str, value: &u32| if *key == "cpu" { *value <= 64 } else { *value > 0 };
+	///
+	/// assert!(Guards::entries_satisfy(&limits, is_valid, "?!").is_ok());
+	/// assert!(Guards::entries_satisfy(BTreeMap::new(), is_valid, "?!").is_ok());
+	///
+	/// let limits = BTreeMap::from([("cpu", 128), ("memory", 0)]);
+	/// assert_eq!(Guards::entries_satisfy(&limits, is_valid, "Invalid limit"), Err(String::from("Invalid limit: cpu")));
+	/// ```
+	pub fn entries_satisfy<T: Entries, F: FnMut(&T::Key, &T::Value) -> bool, E: Into<String>>(
+		value: T,
+		predicate: F,
+		error_message: E,
+	) -> Result<T, String>
+	where
+		T::Key: Display,
+	{
+		match failing_keys(&value, predicate, false) {
+			None => Ok(value),
+			Some(keys) => Err(format!("{}: {}", error_message.into(), keys)),
+		}
+	}
+
+	/// Ensures that each element of `value` is exactly one greater than the element before it.
+	///
+	/// Returns [`Ok`] if the integers in the slice, vector or array are consecutive, like `[7, 8, 9]`, otherwise
+	/// returns [`Err`] with the given `error_message`. Overflow does not wrap around, so `[255u8, 0]` is not
+	/// contiguous. Empty and single element collections always pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_contiguous(vec![7, 8, 9], "?!"), Ok(vec![7, 8, 9]));
+	/// assert_eq!(Guards::is_contiguous(vec![-1, 0, 1], "?!"), Ok(vec![-1, 0, 1]));
+	/// assert_eq!(Guards::is_contiguous(Vec::<u32>::new(), "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::is_contiguous([42], "?!"), Ok([42]));
+	///
+	/// assert_eq!(Guards::is_contiguous(vec![7, 9], "Ids must be consecutive!"), Err(String::from("Ids must be consecutive!")));
+	/// assert!(Guards::is_contiguous(vec![7, 7], "Ids must be consecutive!").is_err());
+	/// assert!(Guards::is_contiguous(vec![9, 8], "Ids must be consecutive!").is_err());
+	/// assert!(Guards::is_contiguous([254u8, 255, 0], "Ids must be consecutive!").is_err());
+	/// ```
+	pub fn is_contiguous<T: AsRef<[U]>, U: Integer, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().windows(2).all(|pair| pair[0].checked_add(U::ONE) == Some(pair[1])) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the elements of `value` are monotonically increasing.
+	///
+	/// Returns [`Ok`] if every element of the slice, vector or array is greater than the element before it,
+	/// otherwise returns [`Err`] with the given `error_message`. This is the same as
+	/// [`Guards::is_sorted_strict`], use [`Guards::is_sorted`] to allow equal neighbours. Elements that cannot be
+	/// compared, like [`f64::NAN`], always fail. Empty and single element collections pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_monotonic_increasing(vec![1, 5, 9], "?!"), Ok(vec![1, 5, 9]));
+	/// assert_eq!(Guards::is_monotonic_increasing(Vec::<u32>::new(), "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::is_monotonic_increasing(vec![1, 5, 5], "Not increasing!"), Err(String::from("Not increasing!")));
+	/// assert!(Guards::is_monotonic_increasing(vec![1, 0], "Not increasing!").is_err());
+	/// ```
+	pub fn is_monotonic_increasing<T: AsRef<[U]>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		Guards::is_sorted_strict(value, error_message)
+	}
+
+	/// Ensures that the elements of `value` are monotonically decreasing.
+	///
+	/// Returns [`Ok`] if every element of the slice, vector or array is less than the element before it,
+	/// otherwise returns [`Err`] with the given `error_message`. Use [`Guards::is_sorted_desc`] to allow equal
+	/// neighbours. Elements that cannot be compared, like [`f64::NAN`], always fail. Empty and single element
+	/// collections pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_monotonic_decreasing(vec![9, 5, 1], "?!"), Ok(vec![9, 5, 1]));
+	/// assert_eq!(Guards::is_monotonic_decreasing([3.0], "?!"), Ok([3.0]));
+	///
+	/// assert_eq!(Guards::is_monotonic_decreasing(vec![9, 9, 1], "Not decreasing!"), Err(String::from("Not decreasing!")));
+	/// assert!(Guards::is_monotonic_decreasing([3.0, f64::NAN], "Not decreasing!").is_err());
+	/// ```
+	pub fn is_monotonic_decreasing<T: AsRef<[U]>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if is_ordered(value.as_ref(), |ordering| ordering.is_gt()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that no two neighbouring elements of `value` are equal.
+	///
+	/// Returns [`Ok`] if every element of the slice, vector or array differs from the element right before it,
+	/// otherwise returns [`Err`] with the given `error_message`. Unlike [`Guards::has_unique_elements`], the
+	/// same value may appear again later as long as something else comes in between.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::no_adjacent_duplicates(vec![1, 2, 1], "?!"), Ok(vec![1, 2, 1]));
+	/// assert_eq!(Guards::no_adjacent_duplicates(Vec::<i32>::new(), "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::no_adjacent_duplicates(["on"], "?!"), Ok(["on"]));
+	///
+	/// assert_eq!(Guards::no_adjacent_duplicates(vec![1, 1], "Repeated event!"), Err(String::from("Repeated event!")));
+	/// assert!(Guards::no_adjacent_duplicates(["on", "off", "off", "on"], "Repeated event!").is_err());
+	/// ```
+	pub fn no_adjacent_duplicates<T: AsRef<[U]>, U: PartialEq, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().windows(2).all(|pair| pair[0] != pair[1]) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that no two neighbouring elements of `value` have the same key.
+	///
+	/// Returns [`Ok`] if the key of every element of the slice, vector or array differs from the key of the
+	/// element right before it, otherwise returns [`Err`] with the given `error_message`. `key` is called once
+	/// per element.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let events = vec![("click", 1), ("scroll", 2), ("click", 3)];
+	/// assert_eq!(Guards::no_adjacent_duplicates_by_key(&events, |event| event.0, "?!"), Ok(&events));
+	/// assert_eq!(Guards::no_adjacent_duplicates_by_key(Vec::<(&str, i32)>::new(), |event| event.0, "?!"), Ok(Vec::new()));
+	///
+	/// let events = vec![("click", 1), ("click", 2)];
+	/// assert_eq!(
+	/// 	Guards::no_adjacent_duplicates_by_key(&events, |event| event.0, "Repeated event!"),
+	/// 	Err(String::from("Repeated event!"))
+	/// );
+	/// ```
+	pub fn no_adjacent_duplicates_by_key<T: AsRef<[U]>, U, K: PartialEq, F: FnMut(&U) -> K, E: Into<String>>(
+		value: T,
+		mut key: F,
+		error_message: E,
+	) -> Result<T, String> {
+		let mut previous = None;
+		let valid = value.as_ref().iter().all(|element| {
+			let current = key(element);
+			let differs = previous.as_ref() != Some(&current);
+			previous = Some(current);
+			differs
+		});
+
+		if valid {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the length of `value` is a multiple of `n`.
+	///
+	/// Returns [`Ok`] if the length of the string or collection divides evenly by `n`, otherwise returns [`Err`]
+	/// with the given `error_message`. An empty value passes for every `n` other than zero. Strings are measured
+	/// in bytes, see [`HasLength`]. An `n` of zero is invalid and returns [`Err`] with `"invalid divisor: 0"`
+	/// instead.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::len_is_multiple_of(vec![0u8; 32], 16, "?!"), Ok(vec![0u8; 32]));
+	/// assert_eq!(Guards::len_is_multiple_of(&[1.0, -1.0][..], 2, "?!"), Ok(&[1.0, -1.0][..]));
+	/// assert_eq!(Guards::len_is_multiple_of("abcd", 4, "?!"), Ok("abcd"));
+	/// assert_eq!(Guards::len_is_multiple_of(Vec::<u8>::new(), 16, "?!"), Ok(Vec::new()));
+	/// assert_eq!(Guards::len_is_multiple_of("", 3, "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::len_is_multiple_of(vec![0u8; 20], 16, "Incomplete frame!"), Err(String::from("Incomplete frame!")));
+	/// assert!(Guards::len_is_multiple_of("abc", 2, "Incomplete frame!").is_err());
+	///
+	/// assert_eq!(Guards::len_is_multiple_of(vec![0u8; 16], 0, "?!"), Err(String::from("invalid divisor: 0")));
+	/// assert_eq!(Guards::len_is_multiple_of("", 0, "?!"), Err(String::from("invalid divisor: 0")));
+	/// ```
+	pub fn len_is_multiple_of<T: HasLength, E: Into<String>>(
+		value: T,
+		n: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		match value.len().checked_rem(n) {
+			None => Err(String::from("invalid divisor: 0")),
+			Some(0) => Ok(value),
+			Some(_) => Err(error_message.into()),
+		}
+	}
+
+	/// Checks every element of `values` with `check` and returns `values` if all of them pass.
+	///
+	/// Calls `check` on each element in order, without stopping at failures. Returns [`Ok`] with the untouched
+	/// vector if `check` returns [`Ok`] for every element, otherwise returns [`Err`] with the index and error of
+	/// every failing element. Elements are never cloned. An empty vector always passes. Use
+	/// [`Guards::all_elements_first_error`] to stop at the first failure.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard, Guards};
+	///
+	/// let is_age = |age: &i32| Guard::new(*age).is_between(0, 150, Bound::Inclusive, "Invalid age").result().map(|_| ());
+	///
+	/// assert_eq!(Guards::all_elements(vec![18, 42], is_age), Ok(vec![18, 42]));
+	/// assert_eq!(Guards::all_elements(Vec::new(), is_age), Ok(Vec::new()));
+	///
+	/// assert_eq!(
+	/// 	Guards::all_elements(vec![-1, 42, 200], is_age),
+	/// 	Err(vec![(0, String::from("Invalid age")), (2, String::from("Invalid age"))])
+	/// );
+	/// ```
+	pub fn all_elements<T, F: FnMut(&T) -> Result<(), String>>(
+		values: Vec<T>,
+		mut check: F,
+	) -> Result<Vec<T>, Vec<(usize, String)>> {
+		let errors: Vec<(usize, String)> = values
+			.iter()
+			.enumerate()
+			.filter_map(|(index, element)| check(element).err().map(|error| (index, error)))
+			.collect();
+
+		if errors.is_empty() {
+			Ok(values)
+		} else {
+			Err(errors)
+		}
+	}
+
+	/// Checks the elements of `values` with `check` until one fails and returns `values` if all of them pass.
+	///
+	/// Calls `check` on each element in order. Returns [`Ok`] with the untouched vector if `check` returns
+	/// [`Ok`] for every element, otherwise returns [`Err`] with the index and error of the first failing element.
+	/// The elements after it are not checked. Elements are never cloned. An empty vector always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard, Guards};
+	///
+	/// let is_age = |age: &i32| Guard::new(*age).is_between(0, 150, Bound::Inclusive, "Invalid age").result().map(|_| ());
+	///
+	/// assert_eq!(Guards::all_elements_first_error(vec![18, 42], is_age), Ok(vec![18, 42]));
+	/// assert_eq!(Guards::all_elements_first_error(Vec::new(), is_age), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::all_elements_first_error(vec![42, -1, 200], is_age), Err((1, String::from("Invalid age"))));
+	/// ```
+	pub fn all_elements_first_error<T, F: FnMut(&T) -> Result<(), String>>(
+		values: Vec<T>,
+		mut check: F,
+	) -> Result<Vec<T>, (usize, String)> {
+		for (index, element) in values.iter().enumerate() {
+			check(element).map_err(|error| (index, error))?;
+		}
+
+		Ok(values)
+	}
+
+	/// Ensures that no two ranges in `value` overlap.
+	///
+	/// Returns [`Ok`] if the ranges of the slice, vector or array, given as `(start, end)` pairs or [`Range`]s,
+	/// are disjoint, otherwise returns [`Err`] with the given `error_message`. The ranges may come in any order.
+	/// `bound_mode` says whether a range includes its end: with [`Bound::Inclusive`] a range ending where
+	/// another one starts overlaps it, with [`Bound::Exclusive`] the two only touch and pass. A range whose start
+	/// is after its end, or whose endpoints cannot be compared like [`f64::NAN`], is invalid and returns [`Err`]
+	/// with `"invalid range: start is after end"` instead.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// let meetings = vec![(13, 14), (9, 10), (10, 12)];
+	/// assert_eq!(Guards::ranges_do_not_overlap(&meetings, Bound::Exclusive, "?!"), Ok(&meetings));
+	/// assert_eq!(Guards::ranges_do_not_overlap([0..5, 10..15], Bound::Inclusive, "?!"), Ok([0..5, 10..15]));
+	/// assert_eq!(Guards::ranges_do_not_overlap(vec![(3, 3)], Bound::Inclusive, "?!"), Ok(vec![(3, 3)]));
+	/// assert_eq!(Guards::ranges_do_not_overlap(Vec::<(i32, i32)>::new(), Bound::Inclusive, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(
+	/// 	Guards::ranges_do_not_overlap(&meetings, Bound::Inclusive, "Meetings overlap!"),
+	/// 	Err(String::from("Meetings overlap!"))
+	/// );
+	/// assert!(Guards::ranges_do_not_overlap(vec![(13, 14), (9, 16)], Bound::Exclusive, "Meetings overlap!").is_err());
+	/// assert!(Guards::ranges_do_not_overlap(vec![(9, 17), (12, 13)], Bound::Exclusive, "Meetings overlap!").is_err());
+	/// assert!(Guards::ranges_do_not_overlap([1.0..2.5, 2.0..3.0], Bound::Exclusive, "Meetings overlap!").is_err());
+	///
+	/// assert_eq!(
+	/// 	Guards::ranges_do_not_overlap(vec![(9, 10), (14, 13)], Bound::Exclusive, "?!"),
+	/// 	Err(String::from("invalid range: start is after end"))
+	/// );
+	/// assert!(Guards::ranges_do_not_overlap([(0.0, f64::NAN)], Bound::Exclusive, "?!").is_err());
+	/// ```
+	pub fn ranges_do_not_overlap<T: AsRef<[I]>, I: Interval<Endpoint = U>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		let mut ranges: Vec<&I> = value.as_ref().iter().collect();
+
+		if !ranges.iter().all(|range| range.start() <= range.end()) {
+			return Err(String::from("invalid range: start is after end"));
+		}
+
+		ranges.sort_by(|a, b| a.start().partial_cmp(b.start()).unwrap_or(Ordering::Equal));
+		let disjoint = ranges.windows(2).all(|pair| match bound_mode {
+			Bound::Inclusive => pair[0].end() < pair[1].start(),
+			Bound::Exclusive => pair[0].end() <= pair[1].start(),
+		});
+
+		if disjoint {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that no key appears twice in the key-value pairs of `value`.
+	///
+	/// Returns [`Ok`] if every `(key, value)` pair of the slice, vector or array has a different key, otherwise
+	/// returns [`Err`] with the given `error_message`. Useful before collecting pairs into a [`HashMap`], which
+	/// would silently keep only the last value of a repeated key. Use [`Guards::keys_are_unique_named`] to get
+	/// the repeated key in the error. An empty collection always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let headers = vec![("Host", "example.com"), ("Accept", "*/*")];
+	/// assert_eq!(Guards::keys_are_unique(&headers, "?!"), Ok(&headers));
+	/// assert_eq!(Guards::keys_are_unique([(1, "a"), (2, "a")], "?!"), Ok([(1, "a"), (2, "a")]));
+	/// assert_eq!(Guards::keys_are_unique(Vec::<(u8, u8)>::new(), "?!"), Ok(Vec::new()));
+	///
+	/// let headers = vec![("Host", "example.com"), ("Accept", "*/*"), ("Host", "evil.com")];
+	/// assert_eq!(Guards::keys_are_unique(&headers, "Repeated header!"), Err(String::from("Repeated header!")));
+	/// ```
+	pub fn keys_are_unique<T: AsRef<[(K, V)]>, K: Eq + Hash, V, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let mut seen = HashSet::new();
+
+		if value.as_ref().iter().all(|(key, _)| seen.insert(key)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that no key appears twice in the key-value pairs of `value`, naming the repeated key on failure.
+	///
+	/// Returns [`Ok`] if every `(key, value)` pair of the slice, vector or array has a different key, otherwise
+	/// returns [`Err`] with the given `error_message` followed by the first key that is repeated, for example
+	/// `"Repeated header: Host"`. An empty collection always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let headers = vec![("Host", "example.com"), ("Accept", "*/*")];
+	/// assert_eq!(Guards::keys_are_unique_named(&headers, "?!"), Ok(&headers));
+	/// assert_eq!(Guards::keys_are_unique_named(Vec::<(u8, u8)>::new(), "?!"), Ok(Vec::new()));
+	///
+	/// let headers = vec![("Accept", "*/*"), ("Host", "example.com"), ("Host", "evil.com"), ("Accept", "text/html")];
+	/// assert_eq!(Guards::keys_are_unique_named(&headers, "Repeated header"), Err(String::from("Repeated header: Host")));
+	/// ```
+	pub fn keys_are_unique_named<T: AsRef<[(K, V)]>, K: Eq + Hash + Display, V, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		match duplicate_keys(value.as_ref(), false) {
+			None => Ok(value),
+			Some(keys) => Err(format!("{}: {}", error_message.into(), keys)),
+		}
+	}
+
+	/// Ensures that exactly `n` elements of `value` satisfy `predicate`.
+	///
+	/// Returns [`Ok`] if `predicate` returns `true` for exactly `n` elements of the slice, vector or array,
+	/// otherwise returns [`Err`] with the given `error_message`. Checking stops as soon as more than `n`
+	/// elements pass. An empty collection passes only when `n` is zero.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_selected = |option: &(&str, bool)| option.1;
+	///
+	/// let poll = vec![("red", true), ("green", false), ("blue", true), ("pink", true)];
+	/// assert_eq!(Guards::exactly_n_satisfy(&poll, 3, is_selected, "?!"), Ok(&poll));
+	/// assert_eq!(Guards::exactly_n_satisfy(Vec::new(), 0, is_selected, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::exactly_n_satisfy(&poll, 2, is_selected, "Select exactly 2 options!"), Err(String::from("Select exactly 2 options!")));
+	/// assert!(Guards::exactly_n_satisfy(&poll, 4, is_selected, "Select exactly 4 options!").is_err());
+	/// assert!(Guards::exactly_n_satisfy(Vec::new(), 1, is_selected, "Select exactly 1 option!").is_err());
+	/// ```
+	pub fn exactly_n_satisfy<T: AsRef<[U]>, U, F: FnMut(&U) -> bool, E: Into<String>>(
+		value: T,
+		n: usize,
+		mut predicate: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if count_satisfying(value.as_ref(), |element| predicate(element), n.saturating_add(1)) == n {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that at least `n` elements of `value` satisfy `predicate`.
+	///
+	/// Returns [`Ok`] if `predicate` returns `true` for `n` or more elements of the slice, vector or array,
+	/// otherwise returns [`Err`] with the given `error_message`. Checking stops as soon as `n` elements pass. An
+	/// empty collection passes only when `n` is zero.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_reviewed = |approver: &&str| !approver.is_empty();
+	///
+	/// assert_eq!(Guards::at_least_n_satisfy(["ana", "", "bo"], 2, is_reviewed, "?!"), Ok(["ana", "", "bo"]));
+	/// assert_eq!(Guards::at_least_n_satisfy(["ana", "cy", "bo"], 2, is_reviewed, "?!"), Ok(["ana", "cy", "bo"]));
+	/// assert_eq!(Guards::at_least_n_satisfy(Vec::new(), 0, is_reviewed, "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::at_least_n_satisfy(["ana", ""], 2, is_reviewed, "Two reviews needed!"), Err(String::from("Two reviews needed!")));
+	/// assert!(Guards::at_least_n_satisfy(Vec::new(), 1, is_reviewed, "One review needed!").is_err());
+	/// ```
+	pub fn at_least_n_satisfy<T: AsRef<[U]>, U, F: FnMut(&U) -> bool, E: Into<String>>(
+		value: T,
+		n: usize,
+		mut predicate: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if count_satisfying(value.as_ref(), |element| predicate(element), n) == n {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that at most `n` elements of `value` satisfy `predicate`.
+	///
+	/// Returns [`Ok`] if `predicate` returns `true` for `n` or fewer elements of the slice, vector or array,
+	/// otherwise returns [`Err`] with the given `error_message`. Checking stops as soon as more than `n`
+	/// elements pass. An empty collection always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_primary = |attachment: &(&str, bool)| attachment.1;
+	///
+	/// let attachments = vec![("cv.pdf", true), ("photo.png", false)];
+	/// assert_eq!(Guards::at_most_n_satisfy(&attachments, 1, is_primary, "?!"), Ok(&attachments));
+	/// assert_eq!(Guards::at_most_n_satisfy(&attachments[1..], 1, is_primary, "?!"), Ok(&attachments[1..]));
+	/// assert_eq!(Guards::at_most_n_satisfy(Vec::new(), 0, is_primary, "?!"), Ok(Vec::new()));
+	///
+	/// let attachments = vec![("cv.pdf", true), ("letter.pdf", true)];
+	/// assert_eq!(Guards::at_most_n_satisfy(&attachments, 1, is_primary, "Only one primary!"), Err(String::from("Only one primary!")));
+	/// ```
+	pub fn at_most_n_satisfy<T: AsRef<[U]>, U, F: FnMut(&U) -> bool, E: Into<String>>(
+		value: T,
+		n: usize,
+		mut predicate: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if count_satisfying(value.as_ref(), |element| predicate(element), n.saturating_add(1)) <= n {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has the same elements as `other`, in any order.
+	///
+	/// Returns [`Ok`] if the slice, vector or array contains every element of `other` exactly as many times as
+	/// `other` does, otherwise returns [`Err`] with the given `error_message`. Unlike comparing sets, repeated
+	/// elements must be repeated equally often, so `[1, 1, 2]` is not a permutation of `[1, 2, 2]`. Collections of
+	/// different lengths fail without looking at the elements.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_permutation_of(vec![3, 1, 2], &[1, 2, 3], "?!"), Ok(vec![3, 1, 2]));
+	/// assert_eq!(Guards::is_permutation_of(vec![7, 1, 7], &[7, 7, 1], "?!"), Ok(vec![7, 1, 7]));
+	/// assert_eq!(Guards::is_permutation_of(Vec::<u32>::new(), &[], "?!"), Ok(Vec::new()));
+	///
+	/// assert_eq!(Guards::is_permutation_of(vec![1, 1, 2], &[1, 2, 2], "Tracks changed!"), Err(String::from("Tracks changed!")));
+	/// assert!(Guards::is_permutation_of(vec![1, 2], &[1, 2, 2], "Tracks changed!").is_err());
+	/// assert!(Guards::is_permutation_of(vec![1, 2, 4], &[1, 2, 3], "Tracks changed!").is_err());
+	/// ```
+	pub fn is_permutation_of<T: AsRef<[U]>, U: Eq + Hash, E: Into<String>>(
+		value: T,
+		other: &[U],
+		error_message: E,
+	) -> Result<T, String> {
+		if same_key_counts(value.as_ref(), other, |element| element) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has the same elements as `other`, in any order, comparing them by `key`.
+	///
+	/// Returns [`Ok`] if every key occurs as many times in the slice, vector or array as it does in `other`,
+	/// otherwise returns [`Err`] with the given `error_message`. Collections of different lengths fail without
+	/// calling `key`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let before = [(1, "Intro"), (2, "Verse"), (2, "Verse")];
+	///
+	/// let after = vec![(2, "Verse (remastered)"), (1, "Intro"), (2, "Verse")];
+	/// assert_eq!(Guards::is_permutation_of_by_key(&after, &before, |track| track.0, "?!"), Ok(&after));
+	///
+	/// let after = vec![(1, "Intro"), (1, "Intro"), (2, "Verse")];
+	/// assert_eq!(
+	/// 	Guards::is_permutation_of_by_key(&after, &before, |track| track.0, "Tracks changed!"),
+	/// 	Err(String::from("Tracks changed!"))
+	/// );
+	/// ```
+	pub fn is_permutation_of_by_key<T: AsRef<[U]>, U, K: Eq + Hash, F: FnMut(&U) -> K, E: Into<String>>(
+		value: T,
+		other: &[U],
+		key: F,
+		error_message: E,
+	) -> Result<T, String> {
+		if same_key_counts(value.as_ref(), other, key) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+/// Returns the keys of `map` that are not in `allowed`, separated by `", "`, or only the first when `all` is `false`.
+fn unknown_keys<T: Entries>(
+	map: &T,
+	allowed: impl IntoIterator<Item = T::Key>,
+	all: bool,
+) -> Option<String>
+where
+	T::Key: Eq + Hash + Display,
+{
+	let allowed: HashSet<T::Key> = allowed.into_iter().collect();
+	failing_keys(map, |key, _| allowed.contains(key), all)
+}
+
+/// Returns the keys of the entries of `map` that fail `predicate`, separated by `", "`, or only the first when
+/// `all` is `false`.
+fn failing_keys<T: Entries>(
+	map: &T,
+	mut predicate: impl FnMut(&T::Key, &T::Value) -> bool,
+	all: bool,
+) -> Option<String>
+where
+	T::Key: Display,
+{
+	let failing = map.entries().filter(|(key, value)| !predicate(key, value));
+	let keys: Vec<String> = failing.take(if all { usize::MAX } else { 1 }).map(|(key, _)| key.to_string()).collect();
+
+	if keys.is_empty() {
+		None
+	} else {
+		Some(keys.join(", "))
+	}
+}
+
+/// Returns the keys that appear more than once in `pairs`, each listed once in the order they repeat, separated
+/// by `", "`, or only the first when `all` is `false`.
+fn duplicate_keys<K: Eq + Hash + Display, V>(
+	pairs: &[(K, V)],
+	all: bool,
+) -> Option<String> {
+	let mut seen = HashSet::new();
+	let mut reported = HashSet::new();
+	let repeated = pairs.iter().map(|(key, _)| key).filter(|key| !seen.insert(*key) && reported.insert(*key));
+	let keys: Vec<String> = repeated.take(if all { usize::MAX } else { 1 }).map(ToString::to_string).collect();
+
+	if keys.is_empty() {
+		None
+	} else {
+		Some(keys.join(", "))
+	}
+}
+
+/// Counts the elements that satisfy `predicate`, stopping once `limit` of them are found.
+fn count_satisfying<U>(
+	elements: &[U],
+	predicate: impl FnMut(&&U) -> bool,
+	limit: usize,
+) -> usize {
+	elements.iter().filter(predicate).take(limit).count()
+}
+
+/// Returns `true` if `left` and `right` have the same keys the same number of times, in any order.
+fn same_key_counts<'a, U, K: Eq + Hash>(
+	left: &'a [U],
+	right: &'a [U],
+	mut key: impl FnMut(&'a U) -> K,
+) -> bool {
+	if left.len() != right.len() {
+		return false;
+	}
+
+	let mut counts: HashMap<K, usize> = HashMap::new();
+	for element in left {
+		*counts.entry(key(element)).or_default() += 1;
+	}
+
+	right.iter().all(|element| match counts.get_mut(&key(element)) {
+		Some(count) if *count > 0 => {
+			*count -= 1;
+			true
+		},
+		_ => false,
+	})
+}
+
+fn checked_sum<U: Number>(elements: &[U]) -> Option<U::Accumulator> {
+	elements.iter().try_fold(U::Accumulator::ZERO, |sum, element| {
+		sum.checked_add(element.to_accumulator())
+	})
+}
+
+/// Compares `sum` with `bound * count`, a product that overflows is beyond every sum.
+fn cmp_scaled<A: Number>(
+	sum: A,
+	bound: A,
+	count: A,
+) -> Option<Ordering> {
+	match bound.checked_mul(count) {
+		Some(scaled) => sum.partial_cmp(&scaled),
+		None if bound > A::ZERO => Some(Ordering::Less),
+		None => Some(Ordering::Greater),
+	}
+}
+
+/// Returns `true` if every element can be compared and each neighbouring pair has an accepted ordering.
+fn is_ordered<U: PartialOrd>(
+	elements: &[U],
+	accepts: impl Fn(Ordering) -> bool,
+) -> bool {
+	elements.iter().all(|element| element.partial_cmp(element).is_some())
+		&& elements.windows(2).all(|pair| pair[0].partial_cmp(&pair[1]).is_some_and(&accepts))
+}
+
+impl<T: HasLength> Guard<T> {
+	/// Ensures that `value` is not empty.
+	///
+	/// See [Guards::is_not_empty] for more details.
+	/// ## Example
+	/// ```
+	/// use std::rc::Rc;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("Ferris")).is_not_empty("?!").result();
+	/// assert_eq!(pass, Ok(String::from("Ferris")));
+	///
+	/// let name: Rc<str> = Rc::from("Ferris");
+	/// let pass = Guard::new(Rc::clone(&name)).is_not_empty("?!").result();
+	/// assert_eq!(pass, Ok(name));
+	///
+	/// let fail = Guard::new("").is_not_empty("Name must not be empty").result();
+	/// assert_eq!(fail, Err(String::from("Name must not be empty")));
+	///
+	/// let recipients: Vec<&str> = Vec::new();
+	/// let fail = Guard::new(&recipients).is_not_empty("Must provide at least one recipient").result();
+	/// assert_eq!(fail, Err(String::from("Must provide at least one recipient")));
+	/// ```
+	pub fn is_not_empty<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_not_empty", |value| {
+			Guards::is_not_empty(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is empty.
+	///
+	/// See [Guards::is_empty] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::new()).is_empty("?!").result();
+	/// assert_eq!(pass, Ok(String::new()));
+	///
+	/// let fail = Guard::new("spam").is_empty("Honeypot field must stay empty").result();
+	/// assert_eq!(fail, Err(String::from("Honeypot field must stay empty")));
+	/// ```
+	pub fn is_empty<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_empty", |value| Guards::is_empty(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` has exactly `len` elements.
+	///
+	/// See [Guards::has_len] for more details.
+	/// ## Example
+	/// ```
+	/// use std::collections::HashMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let point = vec![1.0, 2.5, -3.0];
+	/// let pass = Guard::new(point).has_len(3, "?!").result();
+	/// assert_eq!(pass, Ok(vec![1.0, 2.5, -3.0]));
+	///
+	/// let headers = HashMap::from([("Host", "example.com")]);
+	/// let fail = Guard::new(headers).has_len(2, "Expected two headers").result();
+	/// assert_eq!(fail, Err(String::from("Expected two headers")));
+	/// ```
+	pub fn has_len<E: Into<String>>(
+		self,
+		len: usize,
+		error_message: E,
+	) -> Self {
+		self.check("has_len", |value| {
+			Guards::has_len(value, len, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the length of `value` is between `min` and `max`.
+	///
+	/// See [Guards::len_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let ids: Vec<u64> = (1..=500).collect();
+	/// let pass = Guard::new(&ids[..]).len_between(1, 500, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass.map(<[u64]>::len), Ok(500));
+	///
+	/// let fail = Guard::new(Vec::<u64>::new()).len_between(1, 500, Bound::Inclusive, "Send 1 to 500 items").result();
+	/// assert_eq!(fail, Err(String::from("Send 1 to 500 items")));
+	/// ```
+	pub fn len_between<E: Into<String>>(
+		self,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("len_between", |value| {
+			Guards::len_between(value, min, max, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the length of `value` is a multiple of `n`.
+	///
+	/// See [Guards::len_is_multiple_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let samples = vec![0.5f32, -0.5, 0.25, -0.25];
+	/// let pass = Guard::new(samples).len_is_multiple_of(2, "?!").result();
+	/// assert_eq!(pass, Ok(vec![0.5, -0.5, 0.25, -0.25]));
+	///
+	/// let fail = Guard::new(vec![0.5f32, -0.5, 0.25]).len_is_multiple_of(2, "Samples must cover both channels").result();
+	/// assert_eq!(fail, Err(String::from("Samples must cover both channels")));
+	///
+	/// let fail = Guard::new(vec![0u8; 16]).len_is_multiple_of(0, "?!").result();
+	/// assert_eq!(fail, Err(String::from("invalid divisor: 0")));
+	/// ```
+	pub fn len_is_multiple_of<E: Into<String>>(
+		self,
+		n: usize,
+		error_message: E,
+	) -> Self {
+		self.check("len_is_multiple_of", |value| {
+			Guards::len_is_multiple_of(value, n, error_message).map(|_| ())
+		})
+	}
+}
+
+impl<T> Guard<T> {
+	/// Ensures that `value` contains `needle`.
+	///
+	/// See [Guards::contains_element] for more details.
+	/// ## Example
+	/// ```
+	/// use std::rc::Rc;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let tags = vec![String::from("web"), String::from("prod")];
+	/// let pass = Guard::new(&tags).contains_element(&String::from("prod"), "?!").result();
+	/// assert_eq!(pass, Ok(&tags));
+	///
+	/// let fail = Guard::new(vec!["web"]).contains_element(&"prod", "Tags must include 'prod'").result();
+	/// assert_eq!(fail, Err(String::from("Tags must include 'prod'")));
+	///
+	/// // Any `AsRef<[U]>` works, not just the collections with a length
+	/// let ports: Rc<[u16]> = Rc::from([80, 443]);
+	/// let pass = Guard::new(Rc::clone(&ports)).contains_element(&443, "?!").result();
+	/// assert_eq!(pass, Ok(ports));
+	/// ```
+	pub fn contains_element<U: PartialEq, E: Into<String>>(
+		self,
+		needle: &U,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("contains_element", |value| {
+			Guards::contains_element(value, needle, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` does not contain `needle`.
+	///
+	/// See [Guards::lacks_element] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![7, 12]).lacks_element(&0, "?!").result();
+	/// assert_eq!(pass, Ok(vec![7, 12]));
+	///
+	/// let fail = Guard::new([7, 0, 12]).lacks_element(&0, "Ids must not contain 0").result();
+	/// assert_eq!(fail, Err(String::from("Ids must not contain 0")));
+	/// ```
+	pub fn lacks_element<U: PartialEq, E: Into<String>>(
+		self,
+		needle: &U,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("lacks_element", |value| {
+			Guards::lacks_element(value, needle, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every element of `value` satisfies `predicate`.
+	///
+	/// See [Guards::all_satisfy] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![22, 80, 443]).all_satisfy(|port| (1..=65535).contains(port), "?!").result();
+	/// assert_eq!(pass, Ok(vec![22, 80, 443]));
+	///
+	/// let fail = Guard::new(vec![22, 0]).all_satisfy(|port| (1..=65535).contains(port), "Ports must be in 1..=65535").result();
+	/// assert_eq!(fail, Err(String::from("Ports must be in 1..=65535")));
+	/// ```
+	pub fn all_satisfy<U, F: FnMut(&U) -> bool, E: Into<String>>(
+		self,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("all_satisfy", |value| {
+			Guards::all_satisfy(value, predicate, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that at least one element of `value` satisfies `predicate`.
+	///
+	/// See [Guards::any_satisfies] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new([1.5, -0.5]).any_satisfies(|weight: &f64| *weight > 0.0, "?!").result();
+	/// assert_eq!(pass, Ok([1.5, -0.5]));
+	///
+	/// let fail = Guard::new([0.0, -0.5]).any_satisfies(|weight: &f64| *weight > 0.0, "One weight must be positive").result();
+	/// assert_eq!(fail, Err(String::from("One weight must be positive")));
+	/// ```
+	pub fn any_satisfies<U, F: FnMut(&U) -> bool, E: Into<String>>(
+		self,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("any_satisfies", |value| {
+			Guards::any_satisfies(value, predicate, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no two elements of `value` are equal.
+	///
+	/// See [Guards::has_unique_elements] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![101, 102, 103]).has_unique_elements("?!").result();
+	/// assert_eq!(pass, Ok(vec![101, 102, 103]));
+	///
+	/// let fail = Guard::new(vec![101, 102, 101]).has_unique_elements("User ids must be unique").result();
+	/// assert_eq!(fail, Err(String::from("User ids must be unique")));
+	/// ```
+	pub fn has_unique_elements<U: Eq + Hash, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("has_unique_elements", |value| {
+			Guards::has_unique_elements(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no two elements of `value` are equal according to `is_equal`.
+	///
+	/// See [Guards::has_unique_elements_by] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let same_name = |a: &&str, b: &&str| a.eq_ignore_ascii_case(b);
+	///
+	/// let pass = Guard::new(["Alice", "Bob"]).has_unique_elements_by(same_name, "?!").result();
+	/// assert_eq!(pass, Ok(["Alice", "Bob"]));
+	///
+	/// let fail = Guard::new(["Alice", "ALICE"]).has_unique_elements_by(same_name, "Names must be unique").result();
+	/// assert_eq!(fail, Err(String::from("Names must be unique")));
+	/// ```
+	pub fn has_unique_elements_by<U, F: FnMut(&U, &U) -> bool, E: Into<String>>(
+		self,
+		is_equal: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("has_unique_elements_by", |value| {
+			Guards::has_unique_elements_by(value, is_equal, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no two elements of `value` have the same key.
+	///
+	/// See [Guards::has_unique_elements_by_key] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![("a.txt", 10), ("b.txt", 10)]).has_unique_elements_by_key(|(path, _)| *path, "?!").result();
+	/// assert!(pass.is_ok());
+	///
+	/// let fail = Guard::new(vec![("a.txt", 10), ("a.txt", 20)]).has_unique_elements_by_key(|(path, _)| *path, "Duplicate path").result();
+	/// assert_eq!(fail, Err(String::from("Duplicate path")));
+	/// ```
+	pub fn has_unique_elements_by_key<U, K: Eq + Hash, F: FnMut(&U) -> K, E: Into<String>>(
+		self,
+		key: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("has_unique_elements_by_key", |value| {
+			Guards::has_unique_elements_by_key(value, key, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the elements of `value` are in non-decreasing order.
+	///
+	/// See [Guards::is_sorted] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![0.5, 0.5, 1.0]).is_sorted("?!").result();
+	/// assert_eq!(pass, Ok(vec![0.5, 0.5, 1.0]));
+	///
+	/// let fail = Guard::new(vec![0.5, f64::NAN]).is_sorted("Readings must be sorted").result();
+	/// assert_eq!(fail, Err(String::from("Readings must be sorted")));
+	/// ```
+	pub fn is_sorted<U: PartialOrd, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_sorted", |value| Guards::is_sorted(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that the elements of `value` are in strictly increasing order.
+	///
+	/// See [Guards::is_sorted_strict] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let timestamps: Vec<u64> = vec![1_700_000_000, 1_700_000_060, 1_700_000_120];
+	/// let pass = Guard::new(&timestamps).is_sorted_strict("?!").result();
+	/// assert_eq!(pass, Ok(&timestamps));
+	///
+	/// let fail = Guard::new([1_700_000_000u64, 1_700_000_000]).is_sorted_strict("Timestamps must increase").result();
+	/// assert_eq!(fail, Err(String::from("Timestamps must increase")));
+	/// ```
+	pub fn is_sorted_strict<U: PartialOrd, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_sorted_strict", |value| {
+			Guards::is_sorted_strict(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the elements of `value` are in non-increasing order.
+	///
+	/// See [Guards::is_sorted_desc] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![99, 87, 87, 40]).is_sorted_desc("?!").result();
+	/// assert_eq!(pass, Ok(vec![99, 87, 87, 40]));
+	///
+	/// let fail = Guard::new(vec![87, 99]).is_sorted_desc("Scores must be ranked").result();
+	/// assert_eq!(fail, Err(String::from("Scores must be ranked")));
+	/// ```
+	pub fn is_sorted_desc<U: PartialOrd, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_sorted_desc", |value| {
+			Guards::is_sorted_desc(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every pair of neighbouring elements of `value` is in order according to `in_order`.
+	///
+	/// See [Guards::is_sorted_by] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let events = vec![("boot", 1), ("login", 5), ("logout", 9)];
+	/// let pass = Guard::new(&events).is_sorted_by(|a, b| a.1 < b.1, "?!").result();
+	/// assert_eq!(pass, Ok(&events));
+	///
+	/// let fail = Guard::new(vec![("login", 5), ("boot", 1)]).is_sorted_by(|a, b| a.1 < b.1, "Events out of order").result();
+	/// assert_eq!(fail, Err(String::from("Events out of order")));
+	/// ```
+	pub fn is_sorted_by<U, F: FnMut(&U, &U) -> bool, E: Into<String>>(
+		self,
+		in_order: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_sorted_by", |value| {
+			Guards::is_sorted_by(value, in_order, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every element of `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// See [Guards::all_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let readings = vec![21.5, 22.0, 23.4];
+	/// let pass = Guard::new(&readings).all_between(-40.0, 85.0, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(&readings));
+	///
+	/// let fail = Guard::new(vec![21.5, 120.0]).all_between(-40.0, 85.0, Bound::Inclusive, "Reading outside sensor range").result();
+	/// assert_eq!(fail, Err(String::from("Reading outside sensor range")));
+	///
+	/// let fail = Guard::new(readings).all_between(85.0, -40.0, Bound::Inclusive, "?!").result();
+	/// assert_eq!(fail, Err(String::from("invalid bounds: lower > upper")));
+	/// ```
+	pub fn all_between<U: PartialOrd, E: Into<String>>(
+		self,
+		lower_bound: U,
+		upper_bound: U,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("all_between", |value| {
+			Guards::all_between(value, lower_bound, upper_bound, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the sum of the elements of `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// See [Guards::sum_is_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let pass = Guard::new(vec![0.6, 0.3, 0.1]).sum_is_between(0.999, 1.001, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(vec![0.6, 0.3, 0.1]));
+	///
+	/// let fail = Guard::new(vec![0.6, 0.3]).sum_is_between(0.999, 1.001, Bound::Inclusive, "Weights must sum to 1").result();
+	/// assert_eq!(fail, Err(String::from("Weights must sum to 1")));
+	/// ```
+	pub fn sum_is_between<U: Number, E: Into<String>>(
+		self,
+		lower_bound: U,
+		upper_bound: U,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("sum_is_between", |value| {
+			Guards::sum_is_between(value, lower_bound, upper_bound, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the mean of the elements of `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// See [Guards::mean_is_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let batch_sizes: Vec<u64> = vec![120, 80, 100];
+	/// let pass = Guard::new(&batch_sizes).mean_is_between(1, 100, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(&batch_sizes));
+	///
+	/// let fail = Guard::new(Vec::<u64>::new()).mean_is_between(1, 100, Bound::Inclusive, "Average batch size must be 1 to 100").result();
+	/// assert_eq!(fail, Err(String::from("Average batch size must be 1 to 100")));
+	/// ```
+	pub fn mean_is_between<U: Number, E: Into<String>>(
+		self,
+		lower_bound: U,
+		upper_bound: U,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("mean_is_between", |value| {
+			Guards::mean_is_between(value, lower_bound, upper_bound, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the first element of `value` satisfies `predicate`.
+	///
+	/// See [Guards::first_satisfies] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let rows = vec!["id,name", "1,Ferris"];
+	/// let pass = Guard::new(&rows).first_satisfies(|row| row.starts_with("id,"), "?!").result();
+	/// assert_eq!(pass, Ok(&rows));
+	///
+	/// let fail = Guard::new(vec!["1,Ferris"]).first_satisfies(|row| row.starts_with("id,"), "CSV must start with a header").result();
+	/// assert_eq!(fail, Err(String::from("CSV must start with a header")));
+	/// ```
+	pub fn first_satisfies<U, F: FnOnce(&U) -> bool, E: Into<String>>(
+		self,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("first_satisfies", |value| {
+			Guards::first_satisfies(value, predicate, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the last element of `value` satisfies `predicate`.
+	///
+	/// See [Guards::last_satisfies] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec!["1,Ferris", "-- 1 row"]).last_satisfies(|row| row.starts_with("--"), "?!").result();
+	/// assert_eq!(pass, Ok(vec!["1,Ferris", "-- 1 row"]));
+	///
+	/// let fail = Guard::new(Vec::<&str>::new()).last_satisfies(|row| row.starts_with("--"), "CSV must end with a summary").result();
+	/// assert_eq!(fail, Err(String::from("CSV must end with a summary")));
+	/// ```
+	pub fn last_satisfies<U, F: FnOnce(&U) -> bool, E: Into<String>>(
+		self,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("last_satisfies", |value| {
+			Guards::last_satisfies(value, predicate, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the first element of `value` equals `expected`.
+	///
+	/// See [Guards::first_equals] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new([0xCA, 0xFE, 0x00]).first_equals(&0xCA, "?!").result();
+	/// assert_eq!(pass, Ok([0xCA, 0xFE, 0x00]));
+	///
+	/// let fail = Guard::new([0x00, 0xFE]).first_equals(&0xCA, "Bad magic byte").result();
+	/// assert_eq!(fail, Err(String::from("Bad magic byte")));
+	/// ```
+	pub fn first_equals<U: PartialEq, E: Into<String>>(
+		self,
+		expected: &U,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("first_equals", |value| {
+			Guards::first_equals(value, expected, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the last element of `value` equals `expected`.
+	///
+	/// See [Guards::last_equals] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![1, 2, 0]).last_equals(&0, "?!").result();
+	/// assert_eq!(pass, Ok(vec![1, 2, 0]));
+	///
+	/// let fail = Guard::new(vec![1, 2]).last_equals(&0, "Missing terminator").result();
+	/// assert_eq!(fail, Err(String::from("Missing terminator")));
+	/// ```
+	pub fn last_equals<U: PartialEq, E: Into<String>>(
+		self,
+		expected: &U,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("last_equals", |value| {
+			Guards::last_equals(value, expected, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every element of `value` is in `allowed`.
+	///
+	/// See [Guards::is_subset_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let plan = ["sso", "audit-log", "export"];
+	///
+	/// let pass = Guard::new(vec!["export"]).is_subset_of(plan, "?!").result();
+	/// assert_eq!(pass, Ok(vec!["export"]));
+	///
+	/// let fail = Guard::new(vec!["export", "beta"]).is_subset_of(plan, "Flag is not part of your plan").result();
+	/// assert_eq!(fail, Err(String::from("Flag is not part of your plan")));
+	/// ```
+	pub fn is_subset_of<U: Eq + Hash, E: Into<String>>(
+		self,
+		allowed: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_subset_of", |value| {
+			Guards::is_subset_of(value, allowed, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every element of `required` is in `value`.
+	///
+	/// See [Guards::is_superset_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let columns = vec![String::from("id"), String::from("name"), String::from("email")];
+	///
+	/// let pass = Guard::new(&columns).is_superset_of([String::from("id"), String::from("email")], "?!").result();
+	/// assert_eq!(pass, Ok(&columns));
+	///
+	/// let fail = Guard::new(&columns).is_superset_of([String::from("created_at")], "Missing required column").result();
+	/// assert_eq!(fail, Err(String::from("Missing required column")));
+	/// ```
+	pub fn is_superset_of<U: Eq + Hash, E: Into<String>>(
+		self,
+		required: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_superset_of", |value| {
+			Guards::is_superset_of(value, required, error_message).map(|_| ())
+		})
+	}
+
+	/// Runs the guard chain built by `check` on every element of `value`.
+	///
+	/// `check` gets a [`Guard`] on a reference to each element and returns the guard at the end of its chain.
+	/// Errors are prefixed with `name` and the index of the element, like in [Guards::each_element]. Checking
+	/// stops at the first failing element, unless the guard is [eager](Guard::eager), in which case every element
+	/// is checked but only the first error is kept. The element guards are eager too. Use
+	/// [`Guard::each_element_all`] to report every failing element.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// fn check(quantity: Guard<&i32>) -> Guard<&i32> {
+	/// 	quantity.is_not_equal_to(&0, "Quantity must not be zero").is_greater_than(&0, "Quantity must be positive")
+	/// }
+	///
+	/// let quantities = vec![2, 0, -1];
+	///
+	/// let fail = Guard::new(&quantities).each_element("items", check).result();
+	/// assert_eq!(fail, Err(String::from("items[1]: Quantity must not be zero")));
+	///
+	/// let fail = Guard::new(&quantities).eager().each_element("items", check).result();
+	/// assert_eq!(fail, Err(String::from("items[1]: Quantity must not be zero")));
+	///
+	/// let pass = Guard::new(vec![3, 1]).each_element("items", |quantity| quantity.is_less_than(&10, "?!")).result();
+	/// assert_eq!(pass, Ok(vec![3, 1]));
+	/// ```
+	pub fn each_element<U, F: FnMut(Guard<&U>) -> Guard<&U>>(
+		self,
+		name: &str,
+		mut check: F,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		let eager = self.is_eager();
+
+		self.check("each_element", |value| {
+			let mut first_error = None;
+
+			for (index, element) in value.as_ref().iter().enumerate() {
+				let guard = if eager {
+					Guard::new(element).eager()
+				} else {
+					Guard::new(element)
+				};
+
+				if let Err(error) = check(guard).result() {
+					first_error.get_or_insert_with(|| format!("{name}[{index}]: {error}"));
+					if !eager {
+						break;
+					}
+				}
+			}
+
+			first_error.map_or(Ok(()), Err)
+		})
+	}
+
+	/// Runs the guard chain built by `check` on every element of `value`, reporting all failing elements.
+	///
+	/// See [Guards::each_element_all] for more details. The element guards are [eager](Guard::eager) if this
+	/// guard is.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// fn check(quantity: Guard<&i32>) -> Guard<&i32> {
+	/// 	quantity.is_not_equal_to(&0, "Quantity must not be zero").is_greater_than(&0, "Quantity must be positive")
+	/// }
+	///
+	/// let fail = Guard::new(vec![2, 0, -1]).each_element_all("items", check).result();
+	/// assert_eq!(fail, Err(String::from("items[1]: Quantity must not be zero; items[2]: Quantity must be positive")));
+	///
+	/// let pass = Guard::new(vec![3, 1]).each_element_all("items", check).result();
+	/// assert_eq!(pass, Ok(vec![3, 1]));
+	/// ```
+	pub fn each_element_all<U, F: FnMut(Guard<&U>) -> Guard<&U>>(
+		self,
+		name: &str,
+		mut check: F,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		let eager = self.is_eager();
+
+		self.check("each_element_all", |value| {
+			Guards::each_element_all(value, name, |element| {
+				let guard = if eager {
+					Guard::new(element).eager()
+				} else {
+					Guard::new(element)
+				};
+
+				check(guard).result().map(|_| ())
+			})
+			.map(|_| ())
+		})
+	}
+
+	/// Ensures that the map `value` contains `key`.
+	///
+	/// See [Guards::has_key] for more details.
+	/// ## Example
+	/// ```
+	/// use std::collections::HashMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(HashMap::from([("host", "localhost")])).has_key("host", "?!").result();
+	/// assert!(pass.is_ok());
+	///
+	/// let fail = Guard::new(HashMap::from([("host", "localhost")])).has_key("port", "Config needs a port").result();
+	/// assert_eq!(fail, Err(String::from("Config needs a port")));
+	/// ```
+	pub fn has_key<Q: ?Sized, E: Into<String>>(
+		self,
+		key: &Q,
+		error_message: E,
+	) -> Self
+	where
+		T: ContainsKey<Q>,
+	{
+		self.check("has_key", |value| {
+			Guards::has_key(value, key, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the map `value` does not contain `key`.
+	///
+	/// See [Guards::lacks_key] for more details.
+	/// ## Example
+	/// ```
+	/// use std::collections::BTreeMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(BTreeMap::from([(String::from("user"), 1)])).lacks_key("root", "?!").result();
+	/// assert!(pass.is_ok());
+	///
+	/// let fail = Guard::new(BTreeMap::from([(String::from("root"), 0)])).lacks_key("root", "Root account is not allowed").result();
+	/// assert_eq!(fail, Err(String::from("Root account is not allowed")));
+	/// ```
+	pub fn lacks_key<Q: ?Sized, E: Into<String>>(
+		self,
+		key: &Q,
+		error_message: E,
+	) -> Self
+	where
+		T: ContainsKey<Q>,
+	{
+		self.check("lacks_key", |value| {
+			Guards::lacks_key(value, key, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the map `value` contains every key in `keys`.
+	///
+	/// See [Guards::has_keys] for more details.
+	/// ## Example
+	/// ```
+	/// use std::collections::HashMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let headers = HashMap::from([("Host", "example.com"), ("Accept", "*/*")]);
+	///
+	/// let pass = Guard::new(&headers).has_keys(&["Host", "Accept"], "?!").result();
+	/// assert_eq!(pass, Ok(&headers));
+	///
+	/// let fail = Guard::new(&headers).has_keys(&["Host", "Authorization"], "Missing required headers").result();
+	/// assert_eq!(fail, Err(String::from("Missing required headers")));
+	/// ```
+	pub fn has_keys<Q, E: Into<String>>(
+		self,
+		keys: &[Q],
+		error_message: E,
+	) -> Self
+	where
+		T: ContainsKey<Q>,
+	{
+		self.check("has_keys", |value| {
+			Guards::has_keys(value, keys, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every key of the map `value` is in `allowed`.
+	///
+	/// See [Guards::keys_are_subset_of] for more details. When the guard is [eager](Guard::eager), all unknown
+	/// keys are listed, separated by `", "`.
+	/// ## Example
+	/// ```
+	/// use std::collections::BTreeMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let allowed = ["host", "port", "timeout"];
+	///
+	/// let pass = Guard::new(BTreeMap::from([("host", "localhost")])).keys_are_subset_of(allowed, "?!").result();
+	/// assert!(pass.is_ok());
+	///
+	/// let config = BTreeMap::from([("host", "localhost"), ("colour", "blue"), ("retries", "3")]);
+	///
+	/// let fail = Guard::new(&config).keys_are_subset_of(allowed, "Unknown config key").result();
+	/// assert_eq!(fail, Err(String::from("Unknown config key: colour")));
+	///
+	/// let fail = Guard::new(&config).eager().keys_are_subset_of(allowed, "Unknown config keys").result();
+	/// assert_eq!(fail, Err(String::from("Unknown config keys: colour, retries")));
+	/// ```
+	pub fn keys_are_subset_of<E: Into<String>>(
+		self,
+		allowed: impl IntoIterator<Item = T::Key>,
+		error_message: E,
+	) -> Self
+	where
+		T: Entries,
+		T::Key: Eq + Hash + Display,
+	{
+		let all = self.is_eager();
+
+		self.check("keys_are_subset_of", |value| match unknown_keys(value, allowed, all) {
+			None => Ok(()),
+			Some(keys) => Err(format!("{}: {}", error_message.into(), keys)),
+		})
+	}
+
+	/// Ensures that every value of the map `value` satisfies `predicate`.
+	///
+	/// See [Guards::values_satisfy] for more details. When the guard is [eager](Guard::eager), the keys of all
+	/// failing values are listed, separated by `", "`.
+	/// ## Example
+	/// ```
+	/// use std::collections::BTreeMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let headers = BTreeMap::from([("Accept", "*/*"), ("User-Agent", "crab/1.0")]);
+	/// let pass = Guard::new(&headers).values_satisfy(|value| value.is_ascii(), "?!").result();
+	/// assert_eq!(pass, Ok(&headers));
+	///
+	/// let headers = BTreeMap::from([("Accept", "*/*"), ("From", "jörg@example.com"), ("X-Name", "Jörg")]);
+	/// let fail = Guard::new(&headers).eager().values_satisfy(|value| value.is_ascii(), "Headers must be ASCII").result();
+	/// assert_eq!(fail, Err(String::from("Headers must be ASCII: From, X-Name")));
+	/// ```
+	pub fn values_satisfy<F: FnMut(&T::Value) -> bool, E: Into<String>>(
+		self,
+		mut predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: Entries,
+		T::Key: Display,
+	{
+		let all = self.is_eager();
+
+		self.check("values_satisfy", |value| {
+			match failing_keys(value, |_, value| predicate(value), all) {
+				None => Ok(()),
+				Some(keys) => Err(format!("{}: {}", error_message.into(), keys)),
+			}
+		})
+	}
+
+	/// Ensures that every entry of the map `value` satisfies `predicate`.
+	///
+	/// See [Guards::entries_satisfy] for more details. When the guard is [eager](Guard::eager), the keys of all
+	/// failing entries are listed, separated by `", "`.
+	/// ## Example
+	/// ```
+	/// use std::collections::BTreeMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let env = BTreeMap::from([("HOME", "/home/ferris"), ("PATH", "/usr/bin")]);
+	/// let pass = Guard::new(&env).entries_satisfy(|key, value| key.len() + value.len() < 64, "?!").result();
+	/// assert_eq!(pass, Ok(&env));
+	///
+	/// let env = BTreeMap::from([("HOME", ""), ("PATH", "/usr/bin")]);
+	/// let fail = Guard::new(env).entries_satisfy(|_, value| !value.is_empty(), "Empty variable").result();
+	/// assert_eq!(fail, Err(String::from("Empty variable: HOME")));
+	/// ```
+	pub fn entries_satisfy<F: FnMut(&T::Key, &T::Value) -> bool, E: Into<String>>(
+		self,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: Entries,
+		T::Key: Display,
+	{
+		let all = self.is_eager();
+
+		self.check("entries_satisfy", |value| match failing_keys(value, predicate, all) {
+			None => Ok(()),
+			Some(keys) => Err(format!("{}: {}", error_message.into(), keys)),
+		})
+	}
+
+	/// Ensures that each element of `value` is exactly one greater than the element before it.
+	///
+	/// See [Guards::is_contiguous] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![1001u64, 1002, 1003]).is_contiguous("?!").result();
+	/// assert_eq!(pass, Ok(vec![1001, 1002, 1003]));
+	///
+	/// let fail = Guard::new(vec![1001u64, 1003]).is_contiguous("Import ids must be consecutive").result();
+	/// assert_eq!(fail, Err(String::from("Import ids must be consecutive")));
+	/// ```
+	pub fn is_contiguous<U: Integer, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_contiguous", |value| {
+			Guards::is_contiguous(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the elements of `value` are monotonically increasing.
+	///
+	/// See [Guards::is_monotonic_increasing] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![3, 10, 11]).is_monotonic_increasing("?!").result();
+	/// assert_eq!(pass, Ok(vec![3, 10, 11]));
+	///
+	/// let fail = Guard::new(vec![3, 10, 4]).is_monotonic_increasing("Ids must increase").result();
+	/// assert_eq!(fail, Err(String::from("Ids must increase")));
+	/// ```
+	pub fn is_monotonic_increasing<U: PartialOrd, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_monotonic_increasing", |value| {
+			Guards::is_monotonic_increasing(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the elements of `value` are monotonically decreasing.
+	///
+	/// See [Guards::is_monotonic_decreasing] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new([10, 3, -4]).is_monotonic_decreasing("?!").result();
+	/// assert_eq!(pass, Ok([10, 3, -4]));
+	///
+	/// let fail = Guard::new([10, 3, 3]).is_monotonic_decreasing("Countdown must decrease").result();
+	/// assert_eq!(fail, Err(String::from("Countdown must decrease")));
+	/// ```
+	pub fn is_monotonic_decreasing<U: PartialOrd, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_monotonic_decreasing", |value| {
+			Guards::is_monotonic_decreasing(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no two neighbouring elements of `value` are equal.
+	///
+	/// See [Guards::no_adjacent_duplicates] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec!["up", "down", "up"]).no_adjacent_duplicates("?!").result();
+	/// assert_eq!(pass, Ok(vec!["up", "down", "up"]));
+	///
+	/// let fail = Guard::new(vec!["up", "up"]).no_adjacent_duplicates("Events must be debounced").result();
+	/// assert_eq!(fail, Err(String::from("Events must be debounced")));
+	/// ```
+	pub fn no_adjacent_duplicates<U: PartialEq, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("no_adjacent_duplicates", |value| {
+			Guards::no_adjacent_duplicates(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no two neighbouring elements of `value` have the same key.
+	///
+	/// See [Guards::no_adjacent_duplicates_by_key] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new([(1, "press"), (2, "release")]).no_adjacent_duplicates_by_key(|event| event.1, "?!").result();
+	/// assert_eq!(pass, Ok([(1, "press"), (2, "release")]));
+	///
+	/// let fail = Guard::new([(1, "press"), (2, "press")])
+	/// 	.no_adjacent_duplicates_by_key(|event| event.1, "Events must be debounced")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("Events must be debounced")));
+	/// ```
+	pub fn no_adjacent_duplicates_by_key<U, K: PartialEq, F: FnMut(&U) -> K, E: Into<String>>(
+		self,
+		key: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("no_adjacent_duplicates_by_key", |value| {
+			Guards::no_adjacent_duplicates_by_key(value, key, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no two ranges in `value` overlap.
+	///
+	/// See [Guards::ranges_do_not_overlap] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let shifts = vec![(18, 24), (6, 12), (12, 18)];
+	/// let pass = Guard::new(shifts).ranges_do_not_overlap(Bound::Exclusive, "?!").result();
+	/// assert_eq!(pass, Ok(vec![(18, 24), (6, 12), (12, 18)]));
+	///
+	/// let fail = Guard::new(vec![6..14, 12..18]).ranges_do_not_overlap(Bound::Exclusive, "Shifts must not overlap").result();
+	/// assert_eq!(fail, Err(String::from("Shifts must not overlap")));
+	///
+	/// let fail = Guard::new(vec![(14, 6)]).ranges_do_not_overlap(Bound::Exclusive, "?!").result();
+	/// assert_eq!(fail, Err(String::from("invalid range: start is after end")));
+	/// ```
+	pub fn ranges_do_not_overlap<I: Interval<Endpoint = U>, U: PartialOrd, E: Into<String>>(
+		self,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[I]>,
+	{
+		self.check("ranges_do_not_overlap", |value| {
+			Guards::ranges_do_not_overlap(value, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no key appears twice in the key-value pairs of `value`.
+	///
+	/// See [Guards::keys_are_unique] for more details.
+	/// ## Example
+	/// ```
+	/// use std::collections::HashMap;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let pairs = vec![("retries", 3), ("timeout", 30)];
+	/// let pass = Guard::new(pairs).keys_are_unique("?!").result();
+	/// assert_eq!(pass.map(HashMap::from_iter), Ok(HashMap::from([("retries", 3), ("timeout", 30)])));
+	///
+	/// let fail = Guard::new(vec![("retries", 3), ("retries", 5)]).keys_are_unique("Options must not repeat").result();
+	/// assert_eq!(fail, Err(String::from("Options must not repeat")));
+	/// ```
+	pub fn keys_are_unique<K: Eq + Hash, V, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[(K, V)]>,
+	{
+		self.check("keys_are_unique", |value| {
+			Guards::keys_are_unique(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that no key appears twice in the key-value pairs of `value`, naming the repeated key on failure.
+	///
+	/// See [Guards::keys_are_unique_named] for more details. When the guard is [eager](Guard::eager), all
+	/// repeated keys are listed once each, separated by `", "`.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![("retries", 3), ("timeout", 30)]).keys_are_unique_named("?!").result();
+	/// assert_eq!(pass, Ok(vec![("retries", 3), ("timeout", 30)]));
+	///
+	/// let pairs = vec![("retries", 3), ("timeout", 30), ("retries", 5), ("timeout", 10), ("retries", 1)];
+	///
+	/// let fail = Guard::new(&pairs).keys_are_unique_named("Repeated option").result();
+	/// assert_eq!(fail, Err(String::from("Repeated option: retries")));
+	///
+	/// let fail = Guard::new(&pairs).eager().keys_are_unique_named("Repeated options").result();
+	/// assert_eq!(fail, Err(String::from("Repeated options: retries, timeout")));
+	/// ```
+	pub fn keys_are_unique_named<K: Eq + Hash + Display, V, E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[(K, V)]>,
+	{
+		let all = self.is_eager();
+
+		self.check("keys_are_unique_named", |value| {
+			match duplicate_keys(value.as_ref(), all) {
+				None => Ok(()),
+				Some(keys) => Err(format!("{}: {}", error_message.into(), keys)),
+			}
+		})
+	}
+
+	/// Ensures that exactly `n` elements of `value` satisfy `predicate`.
+	///
+	/// See [Guards::exactly_n_satisfy] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let attachments = vec![("cv.pdf", true), ("photo.png", false)];
+	/// let pass = Guard::new(&attachments).exactly_n_satisfy(1, |attachment| attachment.1, "?!").result();
+	/// assert_eq!(pass, Ok(&attachments));
+	///
+	/// let attachments = vec![("cv.pdf", false), ("photo.png", false)];
+	/// let fail = Guard::new(&attachments).exactly_n_satisfy(1, |attachment| attachment.1, "Mark one attachment as primary").result();
+	/// assert_eq!(fail, Err(String::from("Mark one attachment as primary")));
+	/// ```
+	pub fn exactly_n_satisfy<U, F: FnMut(&U) -> bool, E: Into<String>>(
+		self,
+		n: usize,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("exactly_n_satisfy", |value| {
+			Guards::exactly_n_satisfy(value, n, predicate, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that at least `n` elements of `value` satisfy `predicate`.
+	///
+	/// See [Guards::at_least_n_satisfy] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(vec![4, 5, 2]).at_least_n_satisfy(2, |rating: &u8| *rating >= 4, "?!").result();
+	/// assert_eq!(pass, Ok(vec![4, 5, 2]));
+	///
+	/// let fail = Guard::new(vec![4, 1, 2]).at_least_n_satisfy(2, |rating: &u8| *rating >= 4, "Needs two good ratings").result();
+	/// assert_eq!(fail, Err(String::from("Needs two good ratings")));
+	/// ```
+	pub fn at_least_n_satisfy<U, F: FnMut(&U) -> bool, E: Into<String>>(
+		self,
+		n: usize,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("at_least_n_satisfy", |value| {
+			Guards::at_least_n_satisfy(value, n, predicate, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that at most `n` elements of `value` satisfy `predicate`.
+	///
+	/// See [Guards::at_most_n_satisfy] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(["ok", "ok", "retry"]).at_most_n_satisfy(1, |status: &&str| *status == "retry", "?!").result();
+	/// assert_eq!(pass, Ok(["ok", "ok", "retry"]));
+	///
+	/// let fail = Guard::new(["retry", "ok", "retry"])
+	/// 	.at_most_n_satisfy(1, |status: &&str| *status == "retry", "Too many retries")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("Too many retries")));
+	/// ```
+	pub fn at_most_n_satisfy<U, F: FnMut(&U) -> bool, E: Into<String>>(
+		self,
+		n: usize,
+		predicate: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("at_most_n_satisfy", |value| {
+			Guards::at_most_n_satisfy(value, n, predicate, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has the same elements as `other`, in any order.
+	///
+	/// See [Guards::is_permutation_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let playlist = ["t-17", "t-03", "t-17", "t-42"];
+	///
+	/// let pass = Guard::new(vec!["t-42", "t-17", "t-03", "t-17"]).is_permutation_of(&playlist, "?!").result();
+	/// assert_eq!(pass, Ok(vec!["t-42", "t-17", "t-03", "t-17"]));
+	///
+	/// let fail = Guard::new(vec!["t-42", "t-17", "t-03", "t-03"]).is_permutation_of(&playlist, "Reordering must keep all tracks").result();
+	/// assert_eq!(fail, Err(String::from("Reordering must keep all tracks")));
+	/// ```
+	pub fn is_permutation_of<U: Eq + Hash, E: Into<String>>(
+		self,
+		other: &[U],
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_permutation_of", |value| {
+			Guards::is_permutation_of(value, other, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has the same elements as `other`, in any order, comparing them by `key`.
+	///
+	/// See [Guards::is_permutation_of_by_key] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let seats = [("Ana", 1), ("Bo", 2)];
+	///
+	/// let pass = Guard::new([("Bo", 1), ("Ana", 2)]).is_permutation_of_by_key(&seats, |seat| seat.0, "?!").result();
+	/// assert_eq!(pass, Ok([("Bo", 1), ("Ana", 2)]));
+	///
+	/// let fail = Guard::new([("Bo", 1), ("Cy", 2)])
+	/// 	.is_permutation_of_by_key(&seats, |seat| seat.0, "Guests must not change")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("Guests must not change")));
+	/// ```
+	pub fn is_permutation_of_by_key<U, K: Eq + Hash, F: FnMut(&U) -> K, E: Into<String>>(
+		self,
+		other: &[U],
+		key: F,
+		error_message: E,
+	) -> Self
+	where
+		T: AsRef<[U]>,
+	{
+		self.check("is_permutation_of_by_key", |value| {
+			Guards::is_permutation_of_by_key(value, other, key, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,168 @@
+use std::cmp::Ordering;
+
+use rust_decimal::Decimal;
+
+use crate::{
+	number::{
+		sealed,
+		DecimalPlaces,
+	},
+	Guard,
+	Guards,
+	Number,
+	Signed,
+};
+
+impl sealed::Sealed for Decimal {}
+
+/// Decimals work with every guard for [`Number`] and [`Signed`] types.
+///
+/// ## Example
+/// ```
+/// use std::str::FromStr;
+///
+/// use fluent_guards::{Bound, Guard};
+/// use rust_decimal::Decimal;
+///
+/// let discount = Decimal::from_str("12.5").unwrap();
+/// let pass = Guard::new(discount)
+/// 	.is_percentage("Discount must be a percentage")
+/// 	.is_between(Decimal::ZERO, Decimal::from(50), Bound::Exclusive, "Discount is too large")
+/// 	.has_max_decimal_places(1, "Discount is too precise")
+/// 	.result();
+/// assert_eq!(pass, Ok(discount));
+///
+/// let fail = Guard::new(Decimal::from_str("-0.01").unwrap()).is_positive("Amount must be positive").result();
+/// assert_eq!(fail, Err(String::from("Amount must be positive")));
+/// ```
+impl Number for Decimal {
+	const ZERO: Self = Decimal::ZERO;
+	const ONE_HUNDRED: Self = Decimal::ONE_HUNDRED;
+
+	type Accumulator = Decimal;
+
+	fn cmp_difference(
+		self,
+		other: Self,
+		delta: Self,
+	) -> Option<Ordering> {
+		match self.checked_sub(other) {
+			Some(difference) => Some(difference.abs().cmp(&delta)),
+			None => Some(Ordering::Greater),
+		}
+	}
+
+	fn checked_add(
+		self,
+		rhs: Self,
+	) -> Option<Self> {
+		Decimal::checked_add(self, rhs)
+	}
+
+	fn checked_mul(
+		self,
+		rhs: Self,
+	) -> Option<Self> {
+		Decimal::checked_mul(self, rhs)
+	}
+
+	fn from_count(count: usize) -> Option<Self> {
+		Some(Decimal::from(count))
+	}
+
+	fn to_accumulator(self) -> Self::Accumulator {
+		self
+	}
+}
+
+impl Signed for Decimal {}
+
+/// Decimals are exact, so trailing zeros are ignored but nothing else is rounded.
+///
+/// ## Example
+/// ```
+/// use std::str::FromStr;
+///
+/// use fluent_guards::Guards;
+/// use rust_decimal::Decimal;
+///
+/// let price = Decimal::from_str("19.99").unwrap();
+/// assert_eq!(Guards::has_max_decimal_places(price, 2, "?!"), Ok(price));
+///
+/// let price = Decimal::from_str("19.9900").unwrap();
+/// assert_eq!(Guards::has_max_decimal_places(price, 2, "?!"), Ok(price));
+///
+/// let price = Decimal::from_str("19.999").unwrap();
+/// assert!(Guards::has_max_decimal_places(price, 2, "Too many decimals!").is_err());
+/// ```
+impl DecimalPlaces for Decimal {
+	fn has_max_decimal_places(
+		self,
+		decimal_places: u32,
+	) -> bool {
+		self.normalize().scale() <= decimal_places
+	}
+}
+
+impl Guards {
+	/// Ensures that `value` is a valid amount of money in a currency with `currency_minor_units` minor units.
+	///
+	/// Returns [`Ok`] if the value has at most `currency_minor_units` decimal places, otherwise returns [`Err`]
+	/// with the given `error_message`. Trailing zeros are ignored, so `10.50` is a valid amount in a currency
+	/// with one minor unit. Use `2` for currencies like EUR or USD, `0` for JPY and `3` for BHD.
+	///
+	/// ## Example
+	/// ```
+	/// use std::str::FromStr;
+	///
+	/// use fluent_guards::Guards;
+	/// use rust_decimal::Decimal;
+	///
+	/// let eur = Decimal::from_str("10.99").unwrap();
+	/// assert_eq!(Guards::is_valid_money(eur, 2, "?!"), Ok(eur));
+	///
+	/// let bhd = Decimal::from_str("10.995").unwrap();
+	/// assert_eq!(Guards::is_valid_money(bhd, 3, "?!"), Ok(bhd));
+	///
+	/// let jpy = Decimal::from_str("1000.00").unwrap();
+	/// assert_eq!(Guards::is_valid_money(jpy, 0, "?!"), Ok(jpy));
+	///
+	/// let jpy = Decimal::from_str("1000.5").unwrap();
+	/// assert_eq!(Guards::is_valid_money(jpy, 0, "Not a valid JPY amount!"), Err(String::from("Not a valid JPY amount!")));
+	/// ```
+	pub fn is_valid_money<E: Into<String>>(
+		value: Decimal,
+		currency_minor_units: u32,
+		error_message: E,
+	) -> Result<Decimal, String> {
+		Guards::has_max_decimal_places(value, currency_minor_units, error_message)
+	}
+}
+
+impl Guard<Decimal> {
+	/// Ensures that `value` is a valid amount of money in a currency with `currency_minor_units` minor units.
+	///
+	/// See [Guards::is_valid_money] for more details.
+	/// ## Example
+	/// ```
+	/// use std::str::FromStr;
+	///
+	/// use fluent_guards::Guard;
+	/// use rust_decimal::Decimal;
+	///
+	/// let pass = Guard::new(Decimal::from_str("24.95").unwrap()).is_valid_money(2, "?!").is_positive("?!").result();
+	/// assert_eq!(pass, Ok(Decimal::from_str("24.95").unwrap()));
+	///
+	/// let fail = Guard::new(Decimal::from_str("24.955").unwrap()).is_valid_money(2, "Invalid EUR amount").result();
+	/// assert_eq!(fail, Err(String::from("Invalid EUR amount")));
+	/// ```
+	pub fn is_valid_money<E: Into<String>>(
+		self,
+		currency_minor_units: u32,
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_money", |value| {
+			Guards::is_valid_money(*value, currency_minor_units, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,113 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+const MAX_LOCAL_PART_LEN: usize = 64;
+const MAX_DOMAIN_LEN: usize = 255;
+const MAX_LABEL_LEN: usize = 63;
+
+fn is_valid_local_part(local_part: &str) -> bool {
+	const SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+
+	!local_part.is_empty()
+		&& local_part.len() <= MAX_LOCAL_PART_LEN
+		&& local_part
+			.split('.')
+			.all(|atom| !atom.is_empty() && atom.chars().all(|c| c.is_alphanumeric() || SPECIALS.contains(c)))
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+	domain.len() <= MAX_DOMAIN_LEN
+		&& domain.contains('.')
+		&& domain.split('.').all(|label| {
+			!label.is_empty()
+				&& label.chars().count() <= MAX_LABEL_LEN
+				&& !label.starts_with('-')
+				&& !label.ends_with('-')
+				&& label.chars().all(|c| c.is_alphanumeric() || c == '-')
+		})
+}
+
+impl Guards {
+	/// Ensures that `value` looks like an email address.
+	///
+	/// Returns [`Ok`] if the address passes the checks below, otherwise returns [`Err`] with the given
+	/// `error_message`. This is a pragmatic check inspired by RFC 5321, it does not prove that the address exists.
+	///
+	/// - There is exactly one `@`.
+	/// - The local part before the `@` has 1 to 64 bytes. It is made of dot separated, non-empty runs of letters,
+	///   digits and ``!#$%&'*+-/=?^_`{|}~``, so plus addressing like `ferris+news` is accepted.
+	/// - The domain after the `@` has at most 255 bytes and at least one dot. Each label has 1 to 63
+	///   characters, consists of letters, digits and `-`, and does not start or end with `-`.
+	/// - Letters and digits may be non-ASCII, so internationalized addresses like `jörg@münchen.de` pass.
+	///
+	/// Quoted local parts like `"john doe"@example.com`, comments, and IP address literals like
+	/// `ferris@[127.0.0.1]` are not accepted. Neither are single label domains like `ferris@localhost`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_valid_email("ferris@example.com", "?!"), Ok("ferris@example.com"));
+	/// assert_eq!(Guards::is_valid_email("ferris+news@example.com", "?!"), Ok("ferris+news@example.com"));
+	/// assert_eq!(Guards::is_valid_email("f.the.crab@mail.example.co.uk", "?!"), Ok("f.the.crab@mail.example.co.uk"));
+	/// assert_eq!(Guards::is_valid_email("jörg@münchen.de", "?!"), Ok("jörg@münchen.de"));
+	/// assert_eq!(Guards::is_valid_email("info@例え.jp", "?!"), Ok("info@例え.jp"));
+	///
+	/// assert_eq!(Guards::is_valid_email("ferris", "Invalid email!"), Err(String::from("Invalid email!")));
+	/// assert!(Guards::is_valid_email("@example.com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("ferris@", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("ferris@@example.com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("ferris@localhost", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("ferris.@example.com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("fer..ris@example.com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("ferris@-example.com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("ferris@example..com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("fer ris@example.com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("\"ferris\"@example.com", "Invalid email!").is_err());
+	/// assert!(Guards::is_valid_email("ferris@[127.0.0.1]", "Invalid email!").is_err());
+	///
+	/// let local_part = "a".repeat(65);
+	/// assert!(Guards::is_valid_email(format!("{local_part}@example.com"), "Invalid email!").is_err());
+	/// ```
+	pub fn is_valid_email<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let valid = match value.as_ref().split_once('@') {
+			Some((local_part, domain)) => is_valid_local_part(local_part) && is_valid_domain(domain),
+			None => false,
+		};
+
+		if valid {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` looks like an email address.
+	///
+	/// See [Guards::is_valid_email] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("ferris@example.com").is_trimmed("?!").is_valid_email("?!").result();
+	/// assert_eq!(pass, Ok("ferris@example.com"));
+	///
+	/// let fail = Guard::new(String::from("ferris(at)example.com")).is_valid_email("Please enter a valid email address").result();
+	/// assert_eq!(fail, Err(String::from("Please enter a valid email address")));
+	/// ```
+	pub fn is_valid_email<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_email", |value| {
+			Guards::is_valid_email(value, error_message).map(|_| ())
+		})
+	}
+}
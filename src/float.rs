@@ -0,0 +1,744 @@
+use crate::{
+	Float,
+	Guard,
+	Guards,
+	Integer,
+};
+
+impl Guards {
+	/// Ensures that `value` is neither infinite nor `NaN`.
+	///
+	/// Returns [`Ok`] if the value is finite, otherwise returns [`Err`] with the given `error_message`.
+	/// Subnormal values are finite.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_finite(1.5, "?!"), Ok(1.5));
+	/// assert_eq!(Guards::is_finite(f64::MIN_POSITIVE / 2.0, "?!"), Ok(f64::MIN_POSITIVE / 2.0));
+	/// assert_eq!(Guards::is_finite(f32::MAX, "?!"), Ok(f32::MAX));
+	///
+	/// assert_eq!(Guards::is_finite(f64::INFINITY, "Value was not finite!"), Err(String::from("Value was not finite!")));
+	/// assert_eq!(Guards::is_finite(f64::NEG_INFINITY, "Value was not finite!"), Err(String::from("Value was not finite!")));
+	/// assert_eq!(Guards::is_finite(f32::NAN, "Value was not finite!"), Err(String::from("Value was not finite!")));
+	/// ```
+	pub fn is_finite<T: Float, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.is_finite() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is not `NaN`.
+	///
+	/// Returns [`Ok`] if the value is a number, otherwise returns [`Err`] with the given `error_message`.
+	/// Infinite and subnormal values are not `NaN`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_not_nan(1.5, "?!"), Ok(1.5));
+	/// assert_eq!(Guards::is_not_nan(f64::INFINITY, "?!"), Ok(f64::INFINITY));
+	/// assert_eq!(Guards::is_not_nan(f64::NEG_INFINITY, "?!"), Ok(f64::NEG_INFINITY));
+	/// assert_eq!(Guards::is_not_nan(f32::MIN_POSITIVE / 2.0, "?!"), Ok(f32::MIN_POSITIVE / 2.0));
+	///
+	/// assert_eq!(Guards::is_not_nan(f64::NAN, "Value was NaN!"), Err(String::from("Value was NaN!")));
+	/// ```
+	pub fn is_not_nan<T: Float, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.is_nan() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is within `epsilon` of `target`.
+	///
+	/// Returns [`Ok`] if `|value - target| <= epsilon`, otherwise returns [`Err`] with the given `error_message`.
+	/// `NaN` on either side always fails, an infinity is only approximately equal to the same infinity.
+	/// A negative or `NaN` `epsilon` is invalid and returns [`Err`] with `"invalid epsilon: must be non-negative"`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_approx_equal(0.1 + 0.2, 0.3, 1e-9, "?!"), Ok(0.1 + 0.2));
+	/// assert_eq!(Guards::is_approx_equal(0.5, 0.3, 0.2, "?!"), Ok(0.5));
+	/// assert_eq!(Guards::is_approx_equal(0.31, 0.3, 1e-9, "Not 0.3!"), Err(String::from("Not 0.3!")));
+	///
+	/// assert!(Guards::is_approx_equal(f64::NAN, 0.3, 1.0, "NaN").is_err());
+	/// assert!(Guards::is_approx_equal(0.3, f64::NAN, 1.0, "NaN").is_err());
+	///
+	/// assert!(Guards::is_approx_equal(f64::INFINITY, f64::INFINITY, 0.0, "?!").is_ok());
+	/// assert!(Guards::is_approx_equal(f64::INFINITY, f64::NEG_INFINITY, 1.0, "inf != -inf").is_err());
+	/// assert!(Guards::is_approx_equal(f64::INFINITY, f64::MAX, 1.0, "inf != max").is_err());
+	///
+	/// assert_eq!(
+	/// 	Guards::is_approx_equal(0.3, 0.3, -1.0, "?!"),
+	/// 	Err(String::from("invalid epsilon: must be non-negative"))
+	/// );
+	/// ```
+	pub fn is_approx_equal<T: Float, E: Into<String>>(
+		value: T,
+		target: T,
+		epsilon: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if epsilon.is_nan() || epsilon < T::ZERO {
+			return Err(String::from("invalid epsilon: must be non-negative"));
+		}
+
+		if value == target || (value - target).abs() <= epsilon {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is within `percent` percent of `target`.
+	///
+	/// Returns [`Ok`] if `|value - target| <= |target| * (percent / 100)`, otherwise returns [`Err`] with the
+	/// given `error_message`. When `target` is zero the allowed band is empty, so only zero passes; use
+	/// [`Guards::is_approx_equal`] for an absolute tolerance around zero. An infinite `target` only accepts the
+	/// same infinity, and `NaN` on either side always fails.
+	/// A negative or `NaN` `percent` is invalid and returns [`Err`] with `"invalid percent: must be non-negative"`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_within_percent(104.9, 100.0, 5.0, "?!"), Ok(104.9));
+	/// assert_eq!(Guards::is_within_percent(95.1, 100.0, 5.0, "?!"), Ok(95.1));
+	/// assert_eq!(Guards::is_within_percent(105.1, 100.0, 5.0, "Off by more than 5%!"), Err(String::from("Off by more than 5%!")));
+	/// assert_eq!(Guards::is_within_percent(94.9, 100.0, 5.0, "Off by more than 5%!"), Err(String::from("Off by more than 5%!")));
+	///
+	/// assert_eq!(Guards::is_within_percent(-104.0, -100.0, 5.0, "?!"), Ok(-104.0));
+	///
+	/// assert_eq!(Guards::is_within_percent(0.0, 0.0, 5.0, "?!"), Ok(0.0));
+	/// assert!(Guards::is_within_percent(1e-300, 0.0, 5.0, "Not zero").is_err());
+	/// assert!(Guards::is_within_percent(f64::NAN, 100.0, 5.0, "NaN").is_err());
+	///
+	/// // The band does not overflow for huge targets
+	/// assert_eq!(Guards::is_within_percent(f64::MAX, f64::MAX, 5.0, "?!"), Ok(f64::MAX));
+	/// assert!(Guards::is_within_percent(0.0, f64::MAX, 5.0, "Off by more than 5%!").is_err());
+	/// assert!(Guards::is_within_percent(1.0, f64::MAX / 2.0, 5.0, "Off by more than 5%!").is_err());
+	/// assert_eq!(Guards::is_within_percent(f64::INFINITY, f64::INFINITY, 5.0, "?!"), Ok(f64::INFINITY));
+	/// assert!(Guards::is_within_percent(100.0, f64::INFINITY, 5.0, "Off by more than 5%!").is_err());
+	///
+	/// assert_eq!(
+	/// 	Guards::is_within_percent(100.0, 100.0, -5.0, "?!"),
+	/// 	Err(String::from("invalid percent: must be non-negative"))
+	/// );
+	/// ```
+	pub fn is_within_percent<T: Float, E: Into<String>>(
+		value: T,
+		target: T,
+		percent: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if percent.is_nan() || percent < T::ZERO {
+			return Err(String::from("invalid percent: must be non-negative"));
+		}
+
+		let band = target.abs() * (percent / T::from(100));
+		if value == target || (target.is_finite() && (value - target).abs() <= band) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is at most `max_ulps` representable floats away from `target`.
+	///
+	/// Returns [`Ok`] if the ULP (unit in the last place) distance between `value` and `target` is at most
+	/// `max_ulps`, otherwise returns [`Err`] with the given `error_message`.
+	/// The distance is counted across zero, so the smallest positive and negative subnormals are two ULPs apart,
+	/// and `0.0` equals `-0.0`. `NaN` or infinite values on either side always fail.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_within_ulps(1.0, 1.0, 0, "?!"), Ok(1.0));
+	/// assert_eq!(Guards::is_within_ulps(1.0f32.next_up(), 1.0, 1, "?!"), Ok(1.0f32.next_up()));
+	/// assert_eq!(Guards::is_within_ulps(1.0f64.next_down(), 1.0, 1, "?!"), Ok(1.0f64.next_down()));
+	/// assert_eq!(Guards::is_within_ulps(1e300f64.next_up().next_up(), 1e300, 2, "?!"), Ok(1e300f64.next_up().next_up()));
+	/// assert_eq!(Guards::is_within_ulps(1.0f64.next_up(), 1.0, 0, "Not 1.0!"), Err(String::from("Not 1.0!")));
+	///
+	/// // Straddling zero
+	/// let tiny = f64::from_bits(1);
+	/// assert_eq!(Guards::is_within_ulps(-0.0, 0.0, 0, "?!"), Ok(-0.0));
+	/// assert_eq!(Guards::is_within_ulps(tiny, -tiny, 2, "?!"), Ok(tiny));
+	/// assert_eq!(Guards::is_within_ulps(tiny, -tiny, 1, "Too far!"), Err(String::from("Too far!")));
+	///
+	/// assert!(Guards::is_within_ulps(f64::NAN, f64::NAN, u64::MAX, "NaN").is_err());
+	/// assert!(Guards::is_within_ulps(f64::INFINITY, f64::INFINITY, u64::MAX, "Infinite").is_err());
+	/// assert!(Guards::is_within_ulps(f64::MAX, f64::INFINITY, u64::MAX, "Infinite").is_err());
+	/// ```
+	pub fn is_within_ulps<T: Float, E: Into<String>>(
+		value: T,
+		target: T,
+		max_ulps: u64,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.is_finite() && target.is_finite() && value.ulp_distance(target) <= max_ulps {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a normal float.
+	///
+	/// Returns [`Ok`] if the value is normal, otherwise returns [`Err`] with the given `error_message`.
+	/// This follows [`f64::is_normal`] exactly, so zero, subnormal, infinite and `NaN` values all fail.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_normal(1.0, "?!"), Ok(1.0));
+	/// assert_eq!(Guards::is_normal(f64::MIN_POSITIVE, "?!"), Ok(f64::MIN_POSITIVE));
+	///
+	/// assert_eq!(Guards::is_normal(0.0, "Value was not normal!"), Err(String::from("Value was not normal!")));
+	/// assert!(Guards::is_normal(-0.0, "Value was not normal!").is_err());
+	/// assert!(Guards::is_normal(f64::from_bits(1), "Value was not normal!").is_err());
+	/// assert!(Guards::is_normal(f32::from_bits(1), "Value was not normal!").is_err());
+	/// assert!(Guards::is_normal(f64::NAN, "Value was not normal!").is_err());
+	/// assert!(Guards::is_normal(f64::INFINITY, "Value was not normal!").is_err());
+	/// ```
+	pub fn is_normal<T: Float, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.is_normal() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a finite whole number.
+	///
+	/// Returns [`Ok`] if the value has no fractional part, otherwise returns [`Err`] with the given `error_message`.
+	/// Infinite and `NaN` values fail. Note that every float with a large enough magnitude is whole.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_integer_valued(42.0, "?!"), Ok(42.0));
+	/// assert_eq!(Guards::is_integer_valued(-3.0, "?!"), Ok(-3.0));
+	/// assert_eq!(Guards::is_integer_valued(-0.0, "?!"), Ok(-0.0));
+	/// assert_eq!(Guards::is_integer_valued(1e300, "?!"), Ok(1e300));
+	/// assert_eq!(Guards::is_integer_valued(9007199254740993.0, "?!"), Ok(9007199254740992.0));
+	///
+	/// assert_eq!(Guards::is_integer_valued(42.5, "Not a whole number!"), Err(String::from("Not a whole number!")));
+	/// assert!(Guards::is_integer_valued(-0.5, "Not a whole number!").is_err());
+	/// assert!(Guards::is_integer_valued(f64::INFINITY, "Not a whole number!").is_err());
+	/// assert!(Guards::is_integer_valued(f64::NAN, "Not a whole number!").is_err());
+	/// ```
+	pub fn is_integer_valued<T: Float, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.is_finite() && value.fract() == T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a finite whole number that fits in `I` and converts it.
+	///
+	/// Returns [`Ok`] with the converted value, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::into_integer::<i64>(42.0, "?!"), Ok(42));
+	/// assert_eq!(Guards::into_integer::<u8>(255.0f32, "?!"), Ok(255));
+	/// assert_eq!(Guards::into_integer::<i64>(-9.223372036854775808e18, "?!"), Ok(i64::MIN));
+	///
+	/// assert_eq!(Guards::into_integer::<u8>(256.0, "Does not fit!"), Err(String::from("Does not fit!")));
+	/// assert!(Guards::into_integer::<u32>(-1.0, "Does not fit!").is_err());
+	/// assert!(Guards::into_integer::<i64>(9.223372036854775808e18, "Does not fit!").is_err());
+	/// assert!(Guards::into_integer::<i64>(1e300, "Does not fit!").is_err());
+	/// assert!(Guards::into_integer::<i64>(-0.5, "Not a whole number!").is_err());
+	/// ```
+	pub fn into_integer<I: Integer>(
+		value: impl Float,
+		error_message: impl Into<String>,
+	) -> Result<I, String> {
+		let value = value.to_f64();
+		if value.is_finite() && value.fract() == 0.0 {
+			if let Some(integer) = I::from_whole_f64(value) {
+				return Ok(integer);
+			}
+		}
+
+		Err(error_message.into())
+	}
+
+	/// Ensures that `value` is a probability, between `0.0` and `1.0` (inclusive).
+	///
+	/// Returns [`Ok`] if the value is a probability, otherwise returns [`Err`] with the given `error_message`.
+	/// `-0.0` passes, `NaN` fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_probability(0.0, "?!"), Ok(0.0));
+	/// assert_eq!(Guards::is_probability(-0.0, "?!"), Ok(-0.0));
+	/// assert_eq!(Guards::is_probability(0.25, "?!"), Ok(0.25));
+	/// assert_eq!(Guards::is_probability(1.0, "?!"), Ok(1.0));
+	///
+	/// assert_eq!(Guards::is_probability(1.0 + f64::EPSILON, "Not a probability!"), Err(String::from("Not a probability!")));
+	/// assert!(Guards::is_probability(-f64::MIN_POSITIVE, "Not a probability!").is_err());
+	/// assert!(Guards::is_probability(f32::NAN, "Not a probability!").is_err());
+	/// ```
+	pub fn is_probability<T: Float, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value >= T::ZERO && value <= T::from(1) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is zero within the precision of its type, relative to `scale`.
+	///
+	/// Returns [`Ok`] if `|value| <= EPSILON * max(|scale|, 1)`, otherwise returns [`Err`] with the given
+	/// `error_message`, where `EPSILON` is [`f32::EPSILON`] or [`f64::EPSILON`]. `scale` is the magnitude of
+	/// the values `value` was computed from, a `NaN` `scale` counts as `1`. `NaN` values always fail.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_effectively_zero(0.0, 1.0, "?!"), Ok(0.0));
+	/// assert_eq!(Guards::is_effectively_zero(1e-17, 1.0, "?!"), Ok(1e-17));
+	/// assert_eq!(Guards::is_effectively_zero(1e-310, 1e-300, "?!"), Ok(1e-310));
+	/// assert_eq!(Guards::is_effectively_zero(-1e-10, 1e6, "?!"), Ok(-1e-10));
+	///
+	/// assert_eq!(Guards::is_effectively_zero(1e-10, 1.0, "Not zero!"), Err(String::from("Not zero!")));
+	/// assert!(Guards::is_effectively_zero(f64::NAN, 1.0, "Not zero!").is_err());
+	/// ```
+	pub fn is_effectively_zero<T: Float, E: Into<String>>(
+		value: T,
+		scale: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.abs() <= effective_zero_tolerance(scale) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is not zero within the precision of its type, relative to `scale`.
+	///
+	/// Returns [`Ok`] if `|value| > EPSILON * max(|scale|, 1)`, otherwise returns [`Err`] with the given
+	/// `error_message`. Useful before dividing by `value`. See [`Guards::is_effectively_zero`] for
+	/// the meaning of `scale`. `NaN` values always fail.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_not_effectively_zero(0.5, 1.0, "?!"), Ok(0.5));
+	/// assert_eq!(Guards::is_not_effectively_zero(1e-10, 1.0, "?!"), Ok(1e-10));
+	///
+	/// assert_eq!(Guards::is_not_effectively_zero(1e-10, 1e8, "Division by zero!"), Err(String::from("Division by zero!")));
+	/// assert!(Guards::is_not_effectively_zero(f64::from_bits(1), 1.0, "Division by zero!").is_err());
+	/// assert!(Guards::is_not_effectively_zero(-0.0, 1.0, "Division by zero!").is_err());
+	/// assert!(Guards::is_not_effectively_zero(f64::NAN, 1.0, "Division by zero!").is_err());
+	/// ```
+	pub fn is_not_effectively_zero<T: Float, E: Into<String>>(
+		value: T,
+		scale: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.abs() > effective_zero_tolerance(scale) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` can be stored in an [`f32`] without losing precision.
+	///
+	/// Returns [`Ok`] if converting `value` to `f32` and back gives the same value, otherwise returns [`Err`]
+	/// with the given `error_message`. Finite values too large for `f32` fail instead of becoming infinite,
+	/// the infinities themselves pass. `NaN` always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::fits_f32_exactly(1.5, "?!"), Ok(1.5));
+	/// assert_eq!(Guards::fits_f32_exactly(-0.0, "?!"), Ok(-0.0));
+	/// assert_eq!(Guards::fits_f32_exactly(f64::from(f32::MAX), "?!"), Ok(f64::from(f32::MAX)));
+	/// assert_eq!(Guards::fits_f32_exactly(f64::INFINITY, "?!"), Ok(f64::INFINITY));
+	///
+	/// assert_eq!(Guards::fits_f32_exactly(0.1, "Lossy!"), Err(String::from("Lossy!")));
+	/// assert!(Guards::fits_f32_exactly(f64::from(f32::MAX) * 2.0, "Lossy!").is_err());
+	/// assert!(Guards::fits_f32_exactly(f64::MAX, "Lossy!").is_err());
+	/// assert!(Guards::fits_f32_exactly(f64::NAN, "Lossy!").is_err());
+	/// ```
+	pub fn fits_f32_exactly<E: Into<String>>(
+		value: f64,
+		error_message: E,
+	) -> Result<f64, String> {
+		Guards::into_f32_lossless(value, error_message).map(|_| value)
+	}
+
+	/// Ensures that `value` can be stored in an [`f32`] without losing precision and converts it.
+	///
+	/// Returns [`Ok`] with the converted value, otherwise returns [`Err`] with the given `error_message`.
+	/// See [`Guards::fits_f32_exactly`] for the exact rules.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::into_f32_lossless(1.5, "?!"), Ok(1.5f32));
+	/// assert_eq!(Guards::into_f32_lossless(16777216.0, "?!"), Ok(16777216.0f32));
+	///
+	/// assert_eq!(Guards::into_f32_lossless(16777217.0, "Lossy!"), Err(String::from("Lossy!")));
+	/// assert!(Guards::into_f32_lossless(0.1, "Lossy!").is_err());
+	/// ```
+	pub fn into_f32_lossless<E: Into<String>>(
+		value: f64,
+		error_message: E,
+	) -> Result<f32, String> {
+		let converted = value as f32;
+		if !value.is_nan() && f64::from(converted) == value {
+			Ok(converted)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+fn effective_zero_tolerance<T: Float>(scale: T) -> T {
+	let scale = scale.abs();
+	if scale > T::from(1) {
+		T::EPSILON * scale
+	} else {
+		T::EPSILON
+	}
+}
+
+impl<T: Float> Guard<T> {
+	/// Ensures that `value` is neither infinite nor `NaN`.
+	///
+	/// See [Guards::is_finite] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(21.5).is_not_nan("?!").is_finite("?!").result();
+	/// assert_eq!(pass, Ok(21.5));
+	///
+	/// let fail = Guard::new(f64::INFINITY).is_not_nan("?!").is_finite("Reading was infinite").result();
+	/// assert_eq!(fail, Err(String::from("Reading was infinite")));
+	/// ```
+	pub fn is_finite<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_finite", |value| {
+			Guards::is_finite(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is not `NaN`.
+	///
+	/// See [Guards::is_not_nan] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(f32::INFINITY).is_not_nan("?!").result();
+	/// assert_eq!(pass, Ok(f32::INFINITY));
+	///
+	/// let fail = Guard::new(f32::NAN).is_not_nan("Reading was NaN").result();
+	/// assert_eq!(fail, Err(String::from("Reading was NaN")));
+	/// ```
+	pub fn is_not_nan<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_not_nan", |value| {
+			Guards::is_not_nan(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is within `epsilon` of `target`.
+	///
+	/// See [Guards::is_approx_equal] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0.1 + 0.2).is_approx_equal(0.3, 1e-9, "?!").result();
+	/// assert_eq!(pass, Ok(0.1 + 0.2));
+	///
+	/// let fail = Guard::new(0.1 + 0.2).is_equal_to(0.3, "0.1 + 0.2 != 0.3").result();
+	/// assert_eq!(fail, Err(String::from("0.1 + 0.2 != 0.3")));
+	/// ```
+	pub fn is_approx_equal<E: Into<String>>(
+		self,
+		target: T,
+		epsilon: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_approx_equal", |value| {
+			Guards::is_approx_equal(*value, target, epsilon, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is within `percent` percent of `target`.
+	///
+	/// See [Guards::is_within_percent] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let expected_throughput = 1200.0;
+	///
+	/// let pass = Guard::new(1150.0).is_within_percent(expected_throughput, 5.0, "?!").result();
+	/// assert_eq!(pass, Ok(1150.0));
+	///
+	/// let fail = Guard::new(1000.0).is_within_percent(expected_throughput, 5.0, "Throughput is off").result();
+	/// assert_eq!(fail, Err(String::from("Throughput is off")));
+	/// ```
+	pub fn is_within_percent<E: Into<String>>(
+		self,
+		target: T,
+		percent: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_within_percent", |value| {
+			Guards::is_within_percent(*value, target, percent, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is at most `max_ulps` representable floats away from `target`.
+	///
+	/// See [Guards::is_within_ulps] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0.1f32 + 0.2).is_within_ulps(0.3, 4, "?!").result();
+	/// assert!(pass.is_ok());
+	///
+	/// let fail = Guard::new(0.3001f32).is_within_ulps(0.3, 4, "Result drifted").result();
+	/// assert_eq!(fail, Err(String::from("Result drifted")));
+	/// ```
+	pub fn is_within_ulps<E: Into<String>>(
+		self,
+		target: T,
+		max_ulps: u64,
+		error_message: E,
+	) -> Self {
+		self.check("is_within_ulps", |value| {
+			Guards::is_within_ulps(*value, target, max_ulps, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a normal float.
+	///
+	/// See [Guards::is_normal] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0.25f32).is_normal("?!").result();
+	/// assert_eq!(pass, Ok(0.25));
+	///
+	/// let fail = Guard::new(f32::MIN_POSITIVE / 4.0).is_normal("Sample was subnormal").result();
+	/// assert_eq!(fail, Err(String::from("Sample was subnormal")));
+	/// ```
+	pub fn is_normal<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_normal", |value| {
+			Guards::is_normal(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a finite whole number.
+	///
+	/// See [Guards::is_integer_valued] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(3.0).is_integer_valued("?!").result();
+	/// assert_eq!(pass, Ok(3.0));
+	///
+	/// let fail = Guard::new(3.5).is_integer_valued("Count must be a whole number").result();
+	/// assert_eq!(fail, Err(String::from("Count must be a whole number")));
+	/// ```
+	pub fn is_integer_valued<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_integer_valued", |value| {
+			Guards::is_integer_valued(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a finite whole number that fits in `I` and continues the chain with it.
+	///
+	/// See [Guards::into_integer] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(8080.0).into_integer::<u16>("Port must be a whole number").is_greater_than(1024, "?!").result();
+	/// assert_eq!(pass, Ok(8080u16));
+	///
+	/// let fail = Guard::new(80.5).into_integer::<u16>("Port must be a whole number").result();
+	/// assert_eq!(fail, Err(String::from("Port must be a whole number")));
+	/// ```
+	pub fn into_integer<I: Integer>(
+		self,
+		error_message: impl Into<String>,
+	) -> Guard<I> {
+		self.convert("into_integer", |value| Guards::into_integer(value, error_message))
+	}
+
+	/// Ensures that `value` is a probability, between `0.0` and `1.0` (inclusive).
+	///
+	/// See [Guards::is_probability] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0.1f32).is_probability("?!").result();
+	/// assert_eq!(pass, Ok(0.1));
+	///
+	/// let fail = Guard::new(f32::NAN).is_probability("Dropout must be a probability").result();
+	/// assert_eq!(fail, Err(String::from("Dropout must be a probability")));
+	/// ```
+	pub fn is_probability<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_probability", |value| {
+			Guards::is_probability(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is zero within the precision of its type, relative to `scale`.
+	///
+	/// See [Guards::is_effectively_zero] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let total = 1e6;
+	///
+	/// let pass = Guard::new(total - (total - 1e-12)).is_effectively_zero(total, "?!").result();
+	/// assert!(pass.is_ok());
+	///
+	/// let fail = Guard::new(0.5).is_effectively_zero(total, "Balance must be zero").result();
+	/// assert_eq!(fail, Err(String::from("Balance must be zero")));
+	/// ```
+	pub fn is_effectively_zero<E: Into<String>>(
+		self,
+		scale: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_effectively_zero", |value| {
+			Guards::is_effectively_zero(*value, scale, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is not zero within the precision of its type, relative to `scale`.
+	///
+	/// See [Guards::is_not_effectively_zero] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0.25f32).is_not_effectively_zero(1.0, "?!").result();
+	/// assert_eq!(pass, Ok(0.25));
+	///
+	/// let fail = Guard::new(1e-9f32).is_not_effectively_zero(1.0, "Divisor is effectively zero").result();
+	/// assert_eq!(fail, Err(String::from("Divisor is effectively zero")));
+	/// ```
+	pub fn is_not_effectively_zero<E: Into<String>>(
+		self,
+		scale: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_not_effectively_zero", |value| {
+			Guards::is_not_effectively_zero(*value, scale, error_message).map(|_| ())
+		})
+	}
+}
+
+impl Guard<f64> {
+	/// Ensures that `value` can be stored in an [`f32`] without losing precision.
+	///
+	/// See [Guards::fits_f32_exactly] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0.25).fits_f32_exactly("?!").result();
+	/// assert_eq!(pass, Ok(0.25));
+	///
+	/// let fail = Guard::new(0.1).fits_f32_exactly("Reading cannot be sent as f32").result();
+	/// assert_eq!(fail, Err(String::from("Reading cannot be sent as f32")));
+	/// ```
+	pub fn fits_f32_exactly<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("fits_f32_exactly", |value| {
+			Guards::fits_f32_exactly(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` can be stored in an [`f32`] without losing precision and continues the chain with it.
+	///
+	/// See [Guards::into_f32_lossless] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(440.0).into_f32_lossless("Frequency is not exact").is_positive("?!").result();
+	/// assert_eq!(pass, Ok(440.0f32));
+	///
+	/// let fail = Guard::new(f64::from(f32::MAX) * 2.0).into_f32_lossless("Frequency is not exact").result();
+	/// assert_eq!(fail, Err(String::from("Frequency is not exact")));
+	/// ```
+	pub fn into_f32_lossless<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Guard<f32> {
+		self.convert("into_f32_lossless", |value| {
+			Guards::into_f32_lossless(value, error_message)
+		})
+	}
+}
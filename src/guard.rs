@@ -1,4 +1,16 @@
-use crate::{guards::Guards, Bound};
+use std::ops::RangeBounds;
+
+use crate::{
+	guard_mut::GuardMut,
+	guards::Guards,
+	trace::{
+		CheckOutcome,
+		CheckTrace,
+	},
+	validated::Validated,
+	Bound,
+	Rule,
+};
 
 /// Provides chainable functions for multiple guards
 ///
@@ -31,19 +43,144 @@ use crate::{guards::Guards, Bound};
 /// assert_eq!(set_tv(7, 0.05), Err(String::from("Volume must be more than 10%!")));
 /// assert_eq!(set_tv(7, 1.1), Err(String::from("Volume cannot be more than 100%!")));
 /// ```
-pub struct Guard<T: PartialOrd> {
-	value: T,
+///
+/// ## Borrowed strings
+///
+/// The string guards work on any `T: AsRef<str>`, including `&str`. A `Guard<&'a str>` hands back the same
+/// `&'a str` from [`Guard::result`], and the error message is only turned into a [`String`] when a check
+/// fails, so validating a borrowed string does not allocate on the success path.
+///
+/// ```
+/// # use std::alloc::{GlobalAlloc, Layout, System};
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// #
+/// # struct CountingAllocator;
+/// # static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+/// #
+/// # unsafe impl GlobalAlloc for CountingAllocator {
+/// # 	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+/// # 		ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+/// # 		unsafe { System.alloc(layout) }
+/// # 	}
+/// # 	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+/// # 		unsafe { System.dealloc(ptr, layout) }
+/// # 	}
+/// # }
+/// #
+/// # #[global_allocator]
+/// # static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// #
+/// use fluent_guards::{Bound, Guard};
+///
+/// fn username(input: &str) -> Result<&str, String> {
+/// 	Guard::new(input.trim())
+/// 		.is_not_empty("Username is required!")
+/// 		.has_chars_between(3, 16, Bound::Inclusive, "Username must be 3 to 16 characters!")
+/// 		.chars_are_alphanumeric("Username must be alphanumeric!")
+/// 		.does_not_start_with("admin", "Username is reserved!")
+/// 		.result()
+/// }
+///
+/// # fn main() {
+/// let before = ALLOCATIONS.load(Ordering::SeqCst);
+/// let name = username("  ferris42 ");
+/// assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before);
+/// assert_eq!(name, Ok("ferris42"));
+///
+/// assert_eq!(username("  "), Err(String::from("Username is required!")));
+/// assert_eq!(username("admin1"), Err(String::from("Username is reserved!")));
+/// # }
+/// ```
+///
+/// Custom string types only need [`AsRef<str>`], they do not have to be comparable:
+///
+/// ```
+/// use fluent_guards::Guard;
+///
+/// struct Name(String);
+///
+/// impl AsRef<str> for Name {
+/// 	fn as_ref(&self) -> &str {
+/// 		&self.0
+/// 	}
+/// }
+///
+/// let fail = Guard::new(Name(String::from("Al"))).has_min_chars(3, "Name is too short!").result();
+/// assert_eq!(fail.err(), Some(String::from("Name is too short!")));
+/// ```
+pub struct Guard<T> {
+	/// Only `None` after a failed conversion, in which case `error` is always set.
+	value: Option<T>,
 	error: Option<String>,
+	eager: bool,
+	trace: Option<Vec<CheckTrace>>,
 }
 
-impl<T: PartialOrd> Guard<T> {
-	fn error(
+impl<T> Guard<T> {
+	fn record(
+		&mut self,
+		name: &'static str,
+		outcome: CheckOutcome,
+	) {
+		if let Some(trace) = &mut self.trace {
+			trace.push(CheckTrace { name, outcome });
+		}
+	}
+
+	pub(crate) fn check<F: FnOnce(&T) -> Result<(), String>>(
+		mut self,
+		name: &'static str,
+		check: F,
+	) -> Self {
+		let outcome = match &self.value {
+			Some(value) if self.error.is_none() || self.eager => match check(value) {
+				Ok(()) => CheckOutcome::Passed,
+				Err(error) => {
+					self.error.get_or_insert(error);
+					CheckOutcome::Failed
+				},
+			},
+			_ => CheckOutcome::Skipped,
+		};
+
+		self.record(name, outcome);
+		self
+	}
+
+	pub(crate) fn convert<U, F: FnOnce(T) -> Result<U, String>>(
+		mut self,
+		name: &'static str,
+		convert: F,
+	) -> Guard<U> {
+		let (value, outcome) = match self.value.take() {
+			Some(value) if self.error.is_none() || self.eager => match convert(value) {
+				Ok(value) => (Some(value), CheckOutcome::Passed),
+				Err(error) => {
+					self.error.get_or_insert(error);
+					(None, CheckOutcome::Failed)
+				},
+			},
+			_ => (None, CheckOutcome::Skipped),
+		};
+
+		self.record(name, outcome);
+		Guard {
+			value,
+			error: self.error,
+			eager: self.eager,
+			trace: self.trace,
+		}
+	}
+
+	pub(crate) fn map_value<U, F: FnOnce(T) -> U>(
 		self,
-		error: String,
-	) -> Guard<T> {
+		map: F,
+	) -> Guard<U> {
 		Guard {
-			value: self.value,
-			error: Some(error),
+			value: self.value.map(map),
+			error: self.error,
+			eager: self.eager,
+			trace: self.trace,
 		}
 	}
 
@@ -61,8 +198,87 @@ impl<T: PartialOrd> Guard<T> {
 	/// ```
 	pub fn new(value: T) -> Guard<T> {
 		Guard {
-			value,
+			value: Some(value),
 			error: Option::None,
+			eager: false,
+			trace: Option::None,
+		}
+	}
+
+	/// Keeps evaluating the checks that follow, even after one of them failed.
+	///
+	/// Only the first error is kept, later failures are ignored. This is useful when checks
+	/// have side effects, for example closures passed to [`Guard::all_of`] or [`Guard::any_of`],
+	/// that should always run. A single check still stops as early as it can, so
+	/// [`Guard::all_of`] stops at its first failing rule. Use [`Guard::each_element_all`] to
+	/// report every failing element of a collection.
+	///
+	/// ## Example
+	/// ```
+	/// use std::cell::Cell;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let ran = Cell::new(0);
+	/// let count: &dyn Fn(&i32) -> bool = &|_| {
+	/// 	ran.set(ran.get() + 1);
+	/// 	true
+	/// };
+	///
+	/// let lazy = Guard::new(4).is_equal_to(5, "4 != 5").all_of(&[(count, "?!")]).result();
+	/// assert_eq!(lazy, Err(String::from("4 != 5")));
+	/// assert_eq!(ran.get(), 0);
+	///
+	/// let eager = Guard::new(4)
+	/// 	.eager()
+	/// 	.is_equal_to(5, "4 != 5")
+	/// 	.is_equal_to(6, "4 != 6")
+	/// 	.all_of(&[(count, "?!")])
+	/// 	.result();
+	/// assert_eq!(eager, Err(String::from("4 != 5")));
+	/// assert_eq!(ran.get(), 1);
+	/// ```
+	pub fn eager(self) -> Self {
+		Guard { eager: true, ..self }
+	}
+
+	pub(crate) fn is_eager(&self) -> bool {
+		self.eager
+	}
+
+	pub(crate) fn value(&self) -> Option<&T> {
+		self.value.as_ref()
+	}
+
+	/// Records the name and outcome of every check that follows.
+	///
+	/// Use [`Guard::result_traced`] to get the recorded checks. Chains that are not traced
+	/// do not record anything.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, CheckOutcome, Guard};
+	///
+	/// let (result, trace) = Guard::new(13)
+	/// 	.traced()
+	/// 	.is_between(1, 15, Bound::Inclusive, "Invalid channel!")
+	/// 	.is_not_equal_to(13, "Channel 13 is blocked!")
+	/// 	.is_less_than(10, "?!")
+	/// 	.result_traced();
+	///
+	/// assert_eq!(result, Err(String::from("Channel 13 is blocked!")));
+	/// assert_eq!(trace[0].name, "is_between");
+	/// assert_eq!(trace[0].outcome, CheckOutcome::Passed);
+	/// assert_eq!(trace[1].outcome, CheckOutcome::Failed);
+	/// assert_eq!(trace[2].outcome, CheckOutcome::Skipped);
+	///
+	/// let log = trace.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+	/// assert_eq!(log, "is_between: pass, is_not_equal_to: FAIL, is_less_than: skipped");
+	/// ```
+	pub fn traced(self) -> Self {
+		Guard {
+			trace: Some(Vec::new()),
+			..self
 		}
 	}
 
@@ -82,12 +298,171 @@ impl<T: PartialOrd> Guard<T> {
 	/// assert_eq!(fail, Err(String::from("4 != 5")));
 	/// ```
 	pub fn result(self) -> Result<T, String> {
-		match self.error {
-			None => Ok(self.value),
-			Some(message) => Err(message),
+		match (self.error, self.value) {
+			(None, Some(value)) => Ok(value),
+			(Some(message), _) => Err(message),
+			(None, None) => unreachable!("a guard without a value always has an error"),
 		}
 	}
 
+	/// Returns the result of the guard chain together with the recorded checks.
+	///
+	/// The list of checks is empty unless [`Guard::traced`] was called before the checks.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{CheckOutcome, CheckTrace, Guard};
+	///
+	/// let (result, trace) = Guard::new(5).traced().is_equal_to(5, "?!").result_traced();
+	/// assert_eq!(result, Ok(5));
+	/// assert_eq!(
+	/// 	trace,
+	/// 	vec![CheckTrace {
+	/// 		name: "is_equal_to",
+	/// 		outcome: CheckOutcome::Passed,
+	/// 	}]
+	/// );
+	///
+	/// let (result, trace) = Guard::new(4).is_equal_to(5, "4 != 5").result_traced();
+	/// assert_eq!(result, Err(String::from("4 != 5")));
+	/// assert!(trace.is_empty());
+	/// ```
+	pub fn result_traced(mut self) -> (Result<T, String>, Vec<CheckTrace>) {
+		let trace = self.trace.take().unwrap_or_default();
+		(self.result(), trace)
+	}
+
+	/// Returns the result of the guard chain as a [`Validated`] value.
+	///
+	/// This is the only way to create a [`Validated`], so a function accepting one
+	/// knows that the value went through a guard chain.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guard, Validated};
+	///
+	/// fn double(value: Validated<i32>) -> i32 {
+	/// 	*value * 2
+	/// }
+	///
+	/// let pass = Guard::new(5).is_less_than(10, "?!").validated().map(double);
+	/// assert_eq!(pass, Ok(10));
+	///
+	/// let fail = Guard::new(15).is_less_than(10, "15 >= 10").validated().map(double);
+	/// assert_eq!(fail, Err(String::from("15 >= 10")));
+	/// ```
+	pub fn validated(self) -> Result<Validated<T>, String> {
+		self.validated_as()
+	}
+
+	/// Returns the result of the guard chain as a [`Validated`] value marked with `Tag`.
+	///
+	/// Values validated with different tags are distinct types.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard, Validated};
+	///
+	/// struct Percentage;
+	///
+	/// fn as_fraction(value: Validated<f32, Percentage>) -> f32 {
+	/// 	*value / 100.0
+	/// }
+	///
+	/// let pass = Guard::new(50.0)
+	/// 	.is_between(0.0, 100.0, Bound::Inclusive, "?!")
+	/// 	.validated_as::<Percentage>()
+	/// 	.map(as_fraction);
+	/// assert_eq!(pass, Ok(0.5));
+	///
+	/// let fail = Guard::new(150.0)
+	/// 	.is_between(0.0, 100.0, Bound::Inclusive, "Not a percentage")
+	/// 	.validated_as::<Percentage>()
+	/// 	.map(as_fraction);
+	/// assert_eq!(fail, Err(String::from("Not a percentage")));
+	/// ```
+	pub fn validated_as<Tag>(self) -> Result<Validated<T, Tag>, String> {
+		self.result().map(Validated::new)
+	}
+
+	/// Ensures that `value` passes every rule in `rules`.
+	///
+	/// See [Guards::all_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guard, Rule};
+	///
+	/// let rules: Vec<Rule<u32>> = vec![
+	/// 	(&|port| *port != 0, "Port 0 is reserved"),
+	/// 	(&|port| *port >= 1024, "Port is privileged"),
+	/// ];
+	///
+	/// let pass = Guard::new(8080).all_of(&rules).result();
+	/// assert_eq!(pass, Ok(8080));
+	///
+	/// let fail = Guard::new(80).all_of(&rules).result();
+	/// assert_eq!(fail, Err(String::from("Port is privileged")));
+	///
+	/// let pass = Guard::new(80).all_of(&[]).result();
+	/// assert_eq!(pass, Ok(80));
+	/// ```
+	pub fn all_of(
+		self,
+		rules: &[Rule<T>],
+	) -> Self {
+		self.check("all_of", |value| Guards::all_of(value, rules).map(|_| ()))
+	}
+
+	/// Ensures that `value` passes at least one rule in `rules`.
+	///
+	/// See [Guards::any_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guard, Rule};
+	///
+	/// let rules: Vec<Rule<u32>> = vec![
+	/// 	(&|port| *port == 80, "Not HTTP"),
+	/// 	(&|port| *port == 443, "Not HTTPS"),
+	/// ];
+	///
+	/// let pass = Guard::new(443).any_of(&rules).result();
+	/// assert_eq!(pass, Ok(443));
+	///
+	/// let fail = Guard::new(8080).any_of(&rules).result();
+	/// assert_eq!(fail, Err(String::from("Not HTTP; Not HTTPS")));
+	///
+	/// let fail = Guard::new(80).any_of(&[]).result();
+	/// assert_eq!(fail, Err(String::from("no rules given")));
+	/// ```
+	pub fn any_of(
+		self,
+		rules: &[Rule<T>],
+	) -> Self {
+		self.check("any_of", |value| Guards::any_of(value, rules).map(|_| ()))
+	}
+
+	/// Create a new guard that can change the provided `value` in place before checking it.
+	///
+	/// See [`GuardMut`] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut name = String::from("Ferris");
+	///
+	/// let result = Guard::from_mut(&mut name)
+	/// 	.modify(|name| *name = name.to_lowercase())
+	/// 	.has_max_chars(6, "Name is too long!")
+	/// 	.result();
+	/// assert_eq!(result, Ok(()));
+	/// assert_eq!(name, "ferris");
+	/// ```
+	pub fn from_mut(value: &mut T) -> GuardMut<'_, T> {
+		GuardMut::new(value)
+	}
+}
+
+impl<T: PartialOrd> Guard<T> {
 	/// Ensures that `value` and `test_value` have the same value.
 	///
 	/// ## Example
@@ -105,14 +480,9 @@ impl<T: PartialOrd> Guard<T> {
 		test_value: T,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
-
-		match Guards::is_equal_to(&self.value, &test_value, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+		self.check("is_equal_to", |value| {
+			Guards::is_equal_to(value, &test_value, error_message).map(|_| ())
+		})
 	}
 
 	/// Ensures that `value` and `test_value` are not the same value.
@@ -132,14 +502,9 @@ impl<T: PartialOrd> Guard<T> {
 		test_value: T,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
-
-		match Guards::is_not_equal_to(&self.value, &test_value, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+		self.check("is_not_equal_to", |value| {
+			Guards::is_not_equal_to(value, &test_value, error_message).map(|_| ())
+		})
 	}
 
 	/// Ensures that `value` is less than `test_value`.
@@ -159,14 +524,9 @@ impl<T: PartialOrd> Guard<T> {
 		test_value: T,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
-
-		match Guards::is_less_than(&self.value, &test_value, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+		self.check("is_less_than", |value| {
+			Guards::is_less_than(value, &test_value, error_message).map(|_| ())
+		})
 	}
 
 	/// Ensures that `value` is less than or equal to `test_value`.
@@ -189,14 +549,9 @@ impl<T: PartialOrd> Guard<T> {
 		test_value: T,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
-
-		match Guards::is_less_or_equal(&self.value, &test_value, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+		self.check("is_less_or_equal", |value| {
+			Guards::is_less_or_equal(value, &test_value, error_message).map(|_| ())
+		})
 	}
 
 	/// Ensures that `value` is greater than `test_value`.
@@ -219,14 +574,9 @@ impl<T: PartialOrd> Guard<T> {
 		test_value: T,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
-
-		match Guards::is_greater_than(&self.value, &test_value, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+		self.check("is_greater_than", |value| {
+			Guards::is_greater_than(value, &test_value, error_message).map(|_| ())
+		})
 	}
 
 	/// Ensures that `value` is greater than or equal to `test_value`.
@@ -249,14 +599,9 @@ impl<T: PartialOrd> Guard<T> {
 		test_value: T,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
-
-		match Guards::is_greater_or_equal(&self.value, &test_value, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+		self.check("is_greater_or_equal", |value| {
+			Guards::is_greater_or_equal(value, &test_value, error_message).map(|_| ())
+		})
 	}
 
 	/// Ensures that `value` is between `lower_bound` and `upper_bound`.
@@ -272,6 +617,9 @@ impl<T: PartialOrd> Guard<T> {
 	///
 	/// let pass = Guard::new(4).is_between(4, 6, Bound::Inclusive, "?!").result();
 	/// assert_eq!(pass, Ok(4));
+	///
+	/// let fail = Guard::new(4).is_between(6, 4, Bound::Inclusive, "?!").result();
+	/// assert_eq!(fail, Err(String::from("invalid bounds: lower > upper")));
 	/// ```
 	pub fn is_between<E: Into<String>>(
 		self,
@@ -280,14 +628,9 @@ impl<T: PartialOrd> Guard<T> {
 		bound_mode: Bound,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
-
-		match Guards::is_between(&self.value, &lower_bound, &upper_bound, bound_mode, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+		self.check("is_between", |value| {
+			Guards::is_between(value, &lower_bound, &upper_bound, bound_mode, error_message).map(|_| ())
+		})
 	}
 
 	/// Ensures that `value` is outside `lower_bound` and `upper_bound`.
@@ -311,13 +654,340 @@ impl<T: PartialOrd> Guard<T> {
 		bound_mode: Bound,
 		error_message: E,
 	) -> Self {
-		if self.error.is_some() {
-			return self;
-		}
+		self.check("is_outside", |value| {
+			Guards::is_outside(value, &lower_bound, &upper_bound, bound_mode, error_message).map(|_| ())
+		})
+	}
 
-		match Guards::is_outside(&self.value, &lower_bound, &upper_bound, bound_mode, error_message) {
-			Ok(_) => self,
-			Err(error) => self.error(error),
-		}
+	/// Ensures that `value` is between `bound_a` and `bound_b`, whichever order they are in.
+	///
+	/// See [Guards::is_between_unordered] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let (drag_start, drag_end) = (80.0, 20.0);
+	///
+	/// let pass = Guard::new(42.0).is_between_unordered(drag_start, drag_end, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(42.0));
+	///
+	/// let fail = Guard::new(90.0).is_between_unordered(drag_start, drag_end, Bound::Inclusive, "Point is not selected").result();
+	/// assert_eq!(fail, Err(String::from("Point is not selected")));
+	/// ```
+	pub fn is_between_unordered<E: Into<String>>(
+		self,
+		bound_a: T,
+		bound_b: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("is_between_unordered", |value| {
+			Guards::is_between_unordered(value, &bound_a, &bound_b, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is outside `bound_a` and `bound_b`, whichever order they are in.
+	///
+	/// See [Guards::is_outside_unordered] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let (drag_start, drag_end) = (80.0, 20.0);
+	///
+	/// let pass = Guard::new(90.0).is_outside_unordered(drag_start, drag_end, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(90.0));
+	///
+	/// let fail = Guard::new(42.0).is_outside_unordered(drag_start, drag_end, Bound::Inclusive, "Point is selected").result();
+	/// assert_eq!(fail, Err(String::from("Point is selected")));
+	/// ```
+	pub fn is_outside_unordered<E: Into<String>>(
+		self,
+		bound_a: T,
+		bound_b: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("is_outside_unordered", |value| {
+			Guards::is_outside_unordered(value, &bound_a, &bound_b, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound`, with a separate [`Bound`] for each end.
+	///
+	/// See [Guards::is_between_bounds] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// // [0, 1)
+	/// let pass = Guard::new(0.0).is_between_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "?!").result();
+	/// assert_eq!(pass, Ok(0.0));
+	///
+	/// let fail = Guard::new(1.0).is_between_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "Opacity must be in [0, 1)").result();
+	/// assert_eq!(fail, Err(String::from("Opacity must be in [0, 1)")));
+	/// ```
+	pub fn is_between_bounds<E: Into<String>>(
+		self,
+		lower_bound: T,
+		lower_mode: Bound,
+		upper_bound: T,
+		upper_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("is_between_bounds", |value| {
+			Guards::is_between_bounds(value, &lower_bound, lower_mode, &upper_bound, upper_mode, error_message)
+				.map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is outside `lower_bound` and `upper_bound`, with a separate [`Bound`] for each end.
+	///
+	/// See [Guards::is_outside_bounds] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// // Outside [0, 1)
+	/// let pass = Guard::new(1.0).is_outside_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "?!").result();
+	/// assert_eq!(pass, Ok(1.0));
+	///
+	/// let fail = Guard::new(0.0).is_outside_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "Must be outside [0, 1)").result();
+	/// assert_eq!(fail, Err(String::from("Must be outside [0, 1)")));
+	/// ```
+	pub fn is_outside_bounds<E: Into<String>>(
+		self,
+		lower_bound: T,
+		lower_mode: Bound,
+		upper_bound: T,
+		upper_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("is_outside_bounds", |value| {
+			Guards::is_outside_bounds(value, &lower_bound, lower_mode, &upper_bound, upper_mode, error_message)
+				.map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is in `range`.
+	///
+	/// See [Guards::is_in_range] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(7).is_in_range(1..=15, "?!").result();
+	/// assert_eq!(pass, Ok(7));
+	///
+	/// let fail = Guard::new(0).is_in_range(1..=15, "Channel must be between 1 and 15").result();
+	/// assert_eq!(fail, Err(String::from("Channel must be between 1 and 15")));
+	///
+	/// let pass = Guard::new(0.25).is_in_range(..1.0, "?!").result();
+	/// assert_eq!(pass, Ok(0.25));
+	/// ```
+	pub fn is_in_range<R: RangeBounds<T>, E: Into<String>>(
+		self,
+		range: R,
+		error_message: E,
+	) -> Self {
+		self.check("is_in_range", |value| {
+			if range.contains(value) {
+				Ok(())
+			} else {
+				Err(error_message.into())
+			}
+		})
+	}
+
+	/// Ensures that `value` is not in `range`.
+	///
+	/// See [Guards::is_not_in_range] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(8080).is_not_in_range(..1024, "?!").result();
+	/// assert_eq!(pass, Ok(8080));
+	///
+	/// let fail = Guard::new(443).is_not_in_range(..1024, "Privileged ports are not allowed").result();
+	/// assert_eq!(fail, Err(String::from("Privileged ports are not allowed")));
+	/// ```
+	pub fn is_not_in_range<R: RangeBounds<T>, E: Into<String>>(
+		self,
+		range: R,
+		error_message: E,
+	) -> Self {
+		self.check("is_not_in_range", |value| {
+			if range.contains(value) {
+				Err(error_message.into())
+			} else {
+				Ok(())
+			}
+		})
+	}
+
+	/// Ensures that `value` is greater than every value in `others`.
+	///
+	/// See [Guards::is_greater_than_all] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let high_scores = vec![120, 340, 95];
+	///
+	/// let pass = Guard::new(400).is_greater_than_all(high_scores.clone(), "?!").result();
+	/// assert_eq!(pass, Ok(400));
+	///
+	/// let fail = Guard::new(200).is_greater_than_all(high_scores, "Not a new high score").result();
+	/// assert_eq!(fail, Err(String::from("Not a new high score")));
+	/// ```
+	pub fn is_greater_than_all<E: Into<String>>(
+		self,
+		others: impl IntoIterator<Item = T>,
+		error_message: E,
+	) -> Self {
+		self.check("is_greater_than_all", |value| {
+			Guards::is_greater_than_all(value, others, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is less than every value in `others`.
+	///
+	/// See [Guards::is_less_than_all] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(1).is_less_than_all([2, 3, 4], "?!").result();
+	/// assert_eq!(pass, Ok(1));
+	///
+	/// let fail = Guard::new(3).is_less_than_all([2, 3, 4], "3 is not the smallest").result();
+	/// assert_eq!(fail, Err(String::from("3 is not the smallest")));
+	/// ```
+	pub fn is_less_than_all<E: Into<String>>(
+		self,
+		others: impl IntoIterator<Item = T>,
+		error_message: E,
+	) -> Self {
+		self.check("is_less_than_all", |value| {
+			Guards::is_less_than_all(value, others, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is greater than at least one value in `others`.
+	///
+	/// See [Guards::is_greater_than_any] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(3).is_greater_than_any([2, 3, 4], "?!").result();
+	/// assert_eq!(pass, Ok(3));
+	///
+	/// let fail = Guard::new(2).is_greater_than_any([2, 3, 4], "2 is the smallest").result();
+	/// assert_eq!(fail, Err(String::from("2 is the smallest")));
+	/// ```
+	pub fn is_greater_than_any<E: Into<String>>(
+		self,
+		others: impl IntoIterator<Item = T>,
+		error_message: E,
+	) -> Self {
+		self.check("is_greater_than_any", |value| {
+			Guards::is_greater_than_any(value, others, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is less than at least one value in `others`.
+	///
+	/// See [Guards::is_less_than_any] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(3).is_less_than_any([2, 3, 4], "?!").result();
+	/// assert_eq!(pass, Ok(3));
+	///
+	/// let fail = Guard::new(3).is_less_than_any(Vec::new(), "Nothing to compare against").result();
+	/// assert_eq!(fail, Err(String::from("Nothing to compare against")));
+	/// ```
+	pub fn is_less_than_any<E: Into<String>>(
+		self,
+		others: impl IntoIterator<Item = T>,
+		error_message: E,
+	) -> Self {
+		self.check("is_less_than_any", |value| {
+			Guards::is_less_than_any(value, others, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` can be converted into `U` and continues the chain with the converted value.
+	///
+	/// See [Guards::fits_in] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let length: u64 = 1_000;
+	/// let pass = Guard::new(length).fits_in::<u32>("Length does not fit in u32").is_less_than(4096, "?!").result();
+	/// assert_eq!(pass, Ok(1_000u32));
+	///
+	/// let fail = Guard::new(256).fits_in::<u8>("256 does not fit in u8").result();
+	/// assert_eq!(fail, Err(String::from("256 does not fit in u8")));
+	///
+	/// let fail = Guard::new(-1).fits_in::<u32>("-1 does not fit in u32").result();
+	/// assert_eq!(fail, Err(String::from("-1 does not fit in u32")));
+	///
+	/// let pass = Guard::new(255).fits_in::<u8>("?!").result();
+	/// assert_eq!(pass, Ok(255u8));
+	/// ```
+	pub fn fits_in<U: TryFrom<T>>(
+		self,
+		error_message: impl Into<String>,
+	) -> Guard<U> {
+		self.convert("fits_in", |value| Guards::fits_in(value, error_message))
+	}
+}
+
+impl<T: Clone> Guard<&T> {
+	/// Maps a `Guard<&T>` to a `Guard<T>` by cloning the guarded value.
+	///
+	/// Any pending error is kept, no additional checks are run.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let name = String::from("Ferris");
+	///
+	/// let pass = Guard::new(&name).is_not_equal_to(&String::new(), "?!").cloned().result();
+	/// assert_eq!(pass, Ok(String::from("Ferris")));
+	///
+	/// let fail = Guard::new(&name).is_equal_to(&String::new(), "Name was not empty").cloned().result();
+	/// assert_eq!(fail, Err(String::from("Name was not empty")));
+	/// ```
+	pub fn cloned(self) -> Guard<T> {
+		self.map_value(Clone::clone)
+	}
+}
+
+impl<T: Copy> Guard<&T> {
+	/// Maps a `Guard<&T>` to a `Guard<T>` by copying the guarded value.
+	///
+	/// Any pending error is kept, no additional checks are run.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let channel: u32 = 7;
+	///
+	/// let pass = Guard::new(&channel).is_less_than(&15, "?!").copied().result();
+	/// assert_eq!(pass, Ok(7));
+	///
+	/// let fail = Guard::new(&channel).is_greater_than(&15, "7 <= 15").copied().result();
+	/// assert_eq!(fail, Err(String::from("7 <= 15")));
+	/// ```
+	pub fn copied(self) -> Guard<T> {
+		self.map_value(|value| *value)
 	}
 }
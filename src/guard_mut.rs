@@ -0,0 +1,573 @@
+use crate::{
+	guards::Guards,
+	Bound,
+	HasLength,
+	Rule,
+};
+
+/// Provides chainable functions to normalize and then guard a value in place
+///
+/// Created with [`Guard::from_mut`](crate::Guard::from_mut). The caller keeps ownership
+/// of the value, so [`GuardMut::result`] only reports whether the checks passed.
+/// Besides the comparison guards, the length and character count guards are available.
+/// Any other check can be written as a [`Rule`] and passed to [`GuardMut::all_of`].
+///
+/// ## Example
+/// ```
+/// use fluent_guards::Guard;
+///
+/// let mut username = String::from("  Ferris ");
+///
+/// let result = Guard::from_mut(&mut username)
+/// 	.modify(|name| *name = name.trim().to_lowercase())
+/// 	.is_not_equal_to(String::from("admin"), "Username is reserved!")
+/// 	.has_max_chars(8, "Username is too long!")
+/// 	.result();
+///
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(username, "ferris");
+/// ```
+pub struct GuardMut<'a, T> {
+	value: &'a mut T,
+	error: Option<String>,
+}
+
+impl<'a, T> GuardMut<'a, T> {
+	fn check<F: FnOnce(&T) -> Result<(), String>>(
+		mut self,
+		check: F,
+	) -> Self {
+		if self.error.is_none() {
+			if let Err(error) = check(self.value) {
+				self.error = Some(error);
+			}
+		}
+
+		self
+	}
+
+	pub(crate) fn new(value: &'a mut T) -> GuardMut<'a, T> {
+		GuardMut { value, error: None }
+	}
+
+	/// Returns the result of the guard chain.
+	///
+	/// If the guard passed an [`Ok`] is returned, otherwise an [`Err`] with the error message.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 5;
+	/// let pass = Guard::from_mut(&mut value).is_equal_to(5, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_equal_to(4, "5 != 4").result();
+	/// assert_eq!(fail, Err(String::from("5 != 4")));
+	/// ```
+	pub fn result(self) -> Result<(), String> {
+		match self.error {
+			None => Ok(()),
+			Some(message) => Err(message),
+		}
+	}
+
+	/// Changes `value` in place with `modify`.
+	///
+	/// `modify` only runs if no previous check failed, checks that follow see the changed value.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 7;
+	/// let pass = Guard::from_mut(&mut value).modify(|value| *value *= 2).is_equal_to(14, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(value, 14);
+	///
+	/// let mut value = 7;
+	/// let fail = Guard::from_mut(&mut value)
+	/// 	.is_less_than(5, "7 >= 5")
+	/// 	.modify(|value| *value *= 2)
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("7 >= 5")));
+	/// assert_eq!(value, 7);
+	/// ```
+	pub fn modify<F: FnOnce(&mut T)>(
+		self,
+		modify: F,
+	) -> Self {
+		if self.error.is_none() {
+			modify(self.value);
+		}
+
+		self
+	}
+
+	/// Ensures that `value` passes every rule in `rules`.
+	///
+	/// See [Guards::all_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guard, Rule};
+	///
+	/// let mut name = String::from("FERRIS");
+	/// let is_lowercase: Rule<String> = (&|name| !name.chars().any(char::is_uppercase), "Name must be lowercase!");
+	///
+	/// let pass = Guard::from_mut(&mut name).modify(|name| name.make_ascii_lowercase()).all_of(&[is_lowercase]).result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(name, "ferris");
+	///
+	/// let fail = Guard::from_mut(&mut name).modify(|name| name.push('X')).all_of(&[is_lowercase]).result();
+	/// assert_eq!(fail, Err(String::from("Name must be lowercase!")));
+	/// ```
+	pub fn all_of(
+		self,
+		rules: &[Rule<T>],
+	) -> Self {
+		self.check(|value| Guards::all_of(value, rules).map(|_| ()))
+	}
+
+	/// Ensures that `value` passes at least one rule in `rules`.
+	///
+	/// See [Guards::any_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guard, Rule};
+	///
+	/// let mut port = 8080;
+	/// let rules: [Rule<u32>; 2] = [(&|port| *port == 80, "Not HTTP"), (&|port| *port == 443, "Not HTTPS")];
+	///
+	/// let pass = Guard::from_mut(&mut port).modify(|port| *port = 443).any_of(&rules).result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut port).modify(|port| *port += 1).any_of(&rules).result();
+	/// assert_eq!(fail, Err(String::from("Not HTTP; Not HTTPS")));
+	/// ```
+	pub fn any_of(
+		self,
+		rules: &[Rule<T>],
+	) -> Self {
+		self.check(|value| Guards::any_of(value, rules).map(|_| ()))
+	}
+}
+
+impl<T: PartialOrd> GuardMut<'_, T> {
+	/// Ensures that `value` and `test_value` have the same value.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 5;
+	/// let pass = Guard::from_mut(&mut value).is_equal_to(5, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_equal_to(4, "5 != 4").result();
+	/// assert_eq!(fail, Err(String::from("5 != 4")));
+	/// ```
+	pub fn is_equal_to<E: Into<String>>(
+		self,
+		test_value: T,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_equal_to(value, &test_value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` and `test_value` are not the same value.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 5;
+	/// let pass = Guard::from_mut(&mut value).is_not_equal_to(4, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_not_equal_to(5, "5 == 5").result();
+	/// assert_eq!(fail, Err(String::from("5 == 5")));
+	/// ```
+	pub fn is_not_equal_to<E: Into<String>>(
+		self,
+		test_value: T,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_not_equal_to(value, &test_value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is less than `test_value`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 5;
+	/// let pass = Guard::from_mut(&mut value).is_less_than(6, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_less_than(5, "5 >= 5").result();
+	/// assert_eq!(fail, Err(String::from("5 >= 5")));
+	/// ```
+	pub fn is_less_than<E: Into<String>>(
+		self,
+		test_value: T,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_less_than(value, &test_value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is less than or equal to `test_value`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 5;
+	/// let pass = Guard::from_mut(&mut value).is_less_or_equal(5, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_less_or_equal(4, "5 > 4").result();
+	/// assert_eq!(fail, Err(String::from("5 > 4")));
+	/// ```
+	pub fn is_less_or_equal<E: Into<String>>(
+		self,
+		test_value: T,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_less_or_equal(value, &test_value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is greater than `test_value`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 5;
+	/// let pass = Guard::from_mut(&mut value).is_greater_than(4, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_greater_than(5, "5 <= 5").result();
+	/// assert_eq!(fail, Err(String::from("5 <= 5")));
+	/// ```
+	pub fn is_greater_than<E: Into<String>>(
+		self,
+		test_value: T,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_greater_than(value, &test_value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is greater than or equal to `test_value`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut value = 5;
+	/// let pass = Guard::from_mut(&mut value).is_greater_or_equal(5, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_greater_or_equal(6, "5 < 6").result();
+	/// assert_eq!(fail, Err(String::from("5 < 6")));
+	/// ```
+	pub fn is_greater_or_equal<E: Into<String>>(
+		self,
+		test_value: T,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_greater_or_equal(value, &test_value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound`.
+	///
+	/// See [Guards::is_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let mut value = 4;
+	/// let pass = Guard::from_mut(&mut value).is_between(4, 6, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_between(4, 6, Bound::Exclusive, "4 not between 4 and 6").result();
+	/// assert_eq!(fail, Err(String::from("4 not between 4 and 6")));
+	/// ```
+	pub fn is_between<E: Into<String>>(
+		self,
+		lower_bound: T,
+		upper_bound: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_between(value, &lower_bound, &upper_bound, bound_mode, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is outside `lower_bound` and `upper_bound`.
+	///
+	/// See [Guards::is_outside] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let mut value = 4;
+	/// let pass = Guard::from_mut(&mut value).is_outside(4, 6, Bound::Exclusive, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let fail = Guard::from_mut(&mut value).is_outside(4, 6, Bound::Inclusive, "4 is not outside 4 and 6").result();
+	/// assert_eq!(fail, Err(String::from("4 is not outside 4 and 6")));
+	/// ```
+	pub fn is_outside<E: Into<String>>(
+		self,
+		lower_bound: T,
+		upper_bound: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_outside(value, &lower_bound, &upper_bound, bound_mode, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound`, with a separate [`Bound`] for each end.
+	///
+	/// See [Guards::is_between_bounds] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// // [0, 1)
+	/// let mut value = 0.0;
+	/// let pass = Guard::from_mut(&mut value).is_between_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let mut value = 1.0;
+	/// let fail = Guard::from_mut(&mut value)
+	/// 	.is_between_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "1 not in [0, 1)")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("1 not in [0, 1)")));
+	/// ```
+	pub fn is_between_bounds<E: Into<String>>(
+		self,
+		lower_bound: T,
+		lower_mode: Bound,
+		upper_bound: T,
+		upper_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check(|value| {
+			Guards::is_between_bounds(value, &lower_bound, lower_mode, &upper_bound, upper_mode, error_message)
+				.map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is outside `lower_bound` and `upper_bound`, with a separate [`Bound`] for each end.
+	///
+	/// See [Guards::is_outside_bounds] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// // Outside [0, 1)
+	/// let mut value = 1.0;
+	/// let pass = Guard::from_mut(&mut value).is_outside_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let mut value = 0.0;
+	/// let fail = Guard::from_mut(&mut value)
+	/// 	.is_outside_bounds(0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "0 is in [0, 1)")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("0 is in [0, 1)")));
+	/// ```
+	pub fn is_outside_bounds<E: Into<String>>(
+		self,
+		lower_bound: T,
+		lower_mode: Bound,
+		upper_bound: T,
+		upper_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check(|value| {
+			Guards::is_outside_bounds(value, &lower_bound, lower_mode, &upper_bound, upper_mode, error_message)
+				.map(|_| ())
+		})
+	}
+}
+
+impl<T: HasLength> GuardMut<'_, T> {
+	/// Ensures that `value` is not empty.
+	///
+	/// See [Guards::is_not_empty] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut tags = vec![String::from(" "), String::from("rust")];
+	/// let pass = Guard::from_mut(&mut tags).modify(|tags| tags.retain(|tag| !tag.trim().is_empty())).is_not_empty("?!").result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(tags, vec![String::from("rust")]);
+	///
+	/// let fail = Guard::from_mut(&mut tags).modify(|tags| tags.clear()).is_not_empty("At least one tag is required").result();
+	/// assert_eq!(fail, Err(String::from("At least one tag is required")));
+	/// ```
+	pub fn is_not_empty<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_not_empty(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is empty.
+	///
+	/// See [Guards::is_empty] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut honeypot = String::from("   ");
+	/// let pass = Guard::from_mut(&mut honeypot).modify(|field| *field = field.trim().to_string()).is_empty("?!").result();
+	/// assert_eq!(pass, Ok(()));
+	///
+	/// let mut honeypot = String::from(" spam ");
+	/// let fail = Guard::from_mut(&mut honeypot).modify(|field| *field = field.trim().to_string()).is_empty("Honeypot field must stay empty").result();
+	/// assert_eq!(fail, Err(String::from("Honeypot field must stay empty")));
+	/// ```
+	pub fn is_empty<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::is_empty(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` has exactly `len` elements.
+	///
+	/// See [Guards::has_len] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut code = String::from(" ab12 ");
+	/// let pass = Guard::from_mut(&mut code).modify(|code| *code = code.trim().to_uppercase()).has_len(4, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(code, "AB12");
+	///
+	/// let fail = Guard::from_mut(&mut code).modify(|code| code.push('3')).has_len(4, "Code must be 4 characters").result();
+	/// assert_eq!(fail, Err(String::from("Code must be 4 characters")));
+	/// ```
+	pub fn has_len<E: Into<String>>(
+		self,
+		len: usize,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::has_len(value, len, error_message).map(|_| ()))
+	}
+
+	/// Ensures that the length of `value` is between `min` and `max`.
+	///
+	/// See [Guards::len_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let mut ids = vec![3, 1, 3, 2];
+	/// let pass = Guard::from_mut(&mut ids)
+	/// 	.modify(|ids| {
+	/// 		ids.sort();
+	/// 		ids.dedup();
+	/// 	})
+	/// 	.len_between(1, 3, Bound::Inclusive, "?!")
+	/// 	.result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(ids, vec![1, 2, 3]);
+	///
+	/// let fail = Guard::from_mut(&mut ids).modify(|ids| ids.push(4)).len_between(1, 3, Bound::Inclusive, "Too many ids").result();
+	/// assert_eq!(fail, Err(String::from("Too many ids")));
+	/// ```
+	pub fn len_between<E: Into<String>>(
+		self,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::len_between(value, min, max, bound_mode, error_message).map(|_| ()))
+	}
+}
+
+impl<T: AsRef<str>> GuardMut<'_, T> {
+	/// Ensures that `value` has at least `min` characters.
+	///
+	/// See [Guards::has_min_chars] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut name = String::from("  Ferris  ");
+	/// let pass = Guard::from_mut(&mut name).modify(|name| *name = name.trim().to_lowercase()).has_min_chars(3, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(name, "ferris");
+	///
+	/// let mut name = String::from(" Al ");
+	/// let fail = Guard::from_mut(&mut name).modify(|name| *name = name.trim().to_string()).has_min_chars(3, "Name is too short").result();
+	/// assert_eq!(fail, Err(String::from("Name is too short")));
+	/// ```
+	pub fn has_min_chars<E: Into<String>>(
+		self,
+		min: usize,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::has_min_chars(value, min, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` has at most `max` characters.
+	///
+	/// See [Guards::has_max_chars] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let mut name = String::from("FERRIS");
+	/// let pass = Guard::from_mut(&mut name).modify(|name| *name = name.to_lowercase()).has_max_chars(6, "?!").result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(name, "ferris");
+	///
+	/// let fail = Guard::from_mut(&mut name).modify(|name| name.push_str("!!")).has_max_chars(6, "Name is too long").result();
+	/// assert_eq!(fail, Err(String::from("Name is too long")));
+	/// ```
+	pub fn has_max_chars<E: Into<String>>(
+		self,
+		max: usize,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::has_max_chars(value, max, error_message).map(|_| ()))
+	}
+
+	/// Ensures that the number of characters in `value` is between `min` and `max`.
+	///
+	/// See [Guards::has_chars_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let mut title = String::from("  Crab Rave  ");
+	/// let pass = Guard::from_mut(&mut title)
+	/// 	.modify(|title| *title = title.trim().to_string())
+	/// 	.has_chars_between(1, 9, Bound::Inclusive, "?!")
+	/// 	.result();
+	/// assert_eq!(pass, Ok(()));
+	/// assert_eq!(title, "Crab Rave");
+	///
+	/// let fail = Guard::from_mut(&mut title)
+	/// 	.modify(|title| title.clear())
+	/// 	.has_chars_between(1, 9, Bound::Inclusive, "Title must be 1 to 9 characters")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("Title must be 1 to 9 characters")));
+	/// ```
+	pub fn has_chars_between<E: Into<String>>(
+		self,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check(|value| Guards::has_chars_between(value, min, max, bound_mode, error_message).map(|_| ()))
+	}
+}
@@ -1,4 +1,12 @@
-use crate::Bound;
+use std::{
+	borrow::Borrow,
+	ops::RangeBounds,
+};
+
+use crate::{
+	Bound,
+	Rule,
+};
 
 /// Provides functions for simple, single use guards
 pub struct Guards;
@@ -199,6 +207,8 @@ impl Guards {
 	/// Ensures that `value` is between `lower_bound` and `upper_bound` (exclusive).
 	///
 	/// Returns [`Ok`] if the value is exclusively between, otherwise returns [`Err`] with the given `error_message`.
+	/// Equal bounds are a range of a single value. If `lower_bound` is greater than `upper_bound`, the bounds are
+	/// most likely swapped and [`Err`] with `"invalid bounds: lower > upper"` is returned instead.
 	///
 	/// ## Example
 	/// ```
@@ -219,6 +229,9 @@ impl Guards {
 	///
 	/// assert_eq!(is_between_4_and_6(6, Bound::Inclusive), true);
 	/// assert_eq!(is_between_4_and_6(7, Bound::Inclusive), false);
+	///
+	/// assert_eq!(Guards::is_between(5, 5, 5, Bound::Inclusive, "?!"), Ok(5));
+	/// assert_eq!(Guards::is_between(5, 10, 2, Bound::Inclusive, "?!"), Err(String::from("invalid bounds: lower > upper")));
 	/// ```
 	pub fn is_between<T: PartialOrd, E: Into<String>>(
 		value: T,
@@ -227,6 +240,8 @@ impl Guards {
 		bound_mode: Bound,
 		error_message: E,
 	) -> Result<T, String> {
+		ensure_ordered(&lower_bound, &upper_bound)?;
+
 		match bound_mode {
 			Bound::Exclusive => {
 				if value > lower_bound && value < upper_bound {
@@ -248,6 +263,8 @@ impl Guards {
 	/// Ensures that `value` is outside `lower_bound` and `upper_bound` (exclusive).
 	///
 	/// Returns [`Ok`] if the value is exclusively outside, otherwise returns [`Err`] with the given `error_message`.
+	/// If `lower_bound` is greater than `upper_bound`, [`Err`] with `"invalid bounds: lower > upper"` is returned
+	/// instead.
 	///
 	/// ## Example
 	/// ```
@@ -268,6 +285,8 @@ impl Guards {
 	///
 	/// assert_eq!(is_not_between_4_and_6(6, Bound::Inclusive), false);
 	/// assert_eq!(is_not_between_4_and_6(7, Bound::Inclusive), true);
+	///
+	/// assert_eq!(Guards::is_outside(1, 10, 2, Bound::Inclusive, "?!"), Err(String::from("invalid bounds: lower > upper")));
 	/// ```
 	pub fn is_outside<T: PartialOrd, E: Into<String>>(
 		value: T,
@@ -276,6 +295,8 @@ impl Guards {
 		bound_mode: Bound,
 		error_message: E,
 	) -> Result<T, String> {
+		ensure_ordered(&lower_bound, &upper_bound)?;
+
 		match bound_mode {
 			Bound::Exclusive => {
 				if value <= lower_bound || value >= upper_bound {
@@ -293,4 +314,452 @@ impl Guards {
 			},
 		}
 	}
+
+	/// Ensures that `value` is between `bound_a` and `bound_b`, whichever order they are in.
+	///
+	/// Works like [`Guards::is_between`] after sorting the two bounds, so `is_between_unordered(x, 10, 2, ...)` is
+	/// the same as `is_between_unordered(x, 2, 10, ...)`. Useful when the ends of a range come from user input,
+	/// like a selection dragged in either direction.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::is_between_unordered(5, 2, 10, Bound::Inclusive, "?!"), Ok(5));
+	/// assert_eq!(Guards::is_between_unordered(5, 10, 2, Bound::Inclusive, "?!"), Ok(5));
+	/// assert_eq!(Guards::is_between_unordered(10, 10, 2, Bound::Inclusive, "?!"), Ok(10));
+	///
+	/// assert_eq!(Guards::is_between_unordered(10, 10, 2, Bound::Exclusive, "Outside selection!"), Err(String::from("Outside selection!")));
+	/// assert_eq!(Guards::is_between_unordered(10, 2, 10, Bound::Exclusive, "Outside selection!"), Err(String::from("Outside selection!")));
+	/// assert!(Guards::is_between_unordered(11, 10, 2, Bound::Inclusive, "Outside selection!").is_err());
+	/// ```
+	pub fn is_between_unordered<T: PartialOrd, E: Into<String>>(
+		value: T,
+		bound_a: T,
+		bound_b: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		let (lower_bound, upper_bound) = ordered(bound_a, bound_b);
+		Guards::is_between(value, lower_bound, upper_bound, bound_mode, error_message)
+	}
+
+	/// Ensures that `value` is outside `bound_a` and `bound_b`, whichever order they are in.
+	///
+	/// Works like [`Guards::is_outside`] after sorting the two bounds.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::is_outside_unordered(1, 2, 10, Bound::Inclusive, "?!"), Ok(1));
+	/// assert_eq!(Guards::is_outside_unordered(1, 10, 2, Bound::Inclusive, "?!"), Ok(1));
+	/// assert_eq!(Guards::is_outside_unordered(2, 10, 2, Bound::Exclusive, "?!"), Ok(2));
+	///
+	/// assert_eq!(Guards::is_outside_unordered(5, 10, 2, Bound::Inclusive, "Inside selection!"), Err(String::from("Inside selection!")));
+	/// assert_eq!(Guards::is_outside_unordered(5, 2, 10, Bound::Inclusive, "Inside selection!"), Err(String::from("Inside selection!")));
+	/// ```
+	pub fn is_outside_unordered<T: PartialOrd, E: Into<String>>(
+		value: T,
+		bound_a: T,
+		bound_b: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		let (lower_bound, upper_bound) = ordered(bound_a, bound_b);
+		Guards::is_outside(value, lower_bound, upper_bound, bound_mode, error_message)
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound`, with a separate [`Bound`] for each end.
+	///
+	/// Returns [`Ok`] if the value is above `lower_bound` and below `upper_bound`, otherwise returns [`Err`] with
+	/// the given `error_message`. `lower_mode` and `upper_mode` say whether the value may equal each bound, so
+	/// half-open ranges like `[0, 1)` can be checked. Use [`Guards::is_between`] when both ends are the same. If
+	/// `lower_bound` is greater than `upper_bound`, [`Err`] with `"invalid bounds: lower > upper"` is returned
+	/// instead.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// // [0, 1)
+	/// let is_normalized = |value: f64| Guards::is_between_bounds(value, 0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "Not in [0, 1)!");
+	///
+	/// assert_eq!(is_normalized(0.0), Ok(0.0));
+	/// assert_eq!(is_normalized(0.5), Ok(0.5));
+	/// assert_eq!(is_normalized(1.0), Err(String::from("Not in [0, 1)!")));
+	/// assert!(is_normalized(-0.1).is_err());
+	/// assert!(is_normalized(f64::NAN).is_err());
+	///
+	/// // (0, 1]
+	/// assert_eq!(Guards::is_between_bounds(1, 0, Bound::Exclusive, 1, Bound::Inclusive, "?!"), Ok(1));
+	/// assert!(Guards::is_between_bounds(0, 0, Bound::Exclusive, 1, Bound::Inclusive, "Not in (0, 1]!").is_err());
+	///
+	/// assert_eq!(
+	/// 	Guards::is_between_bounds(0.5, 1.0, Bound::Inclusive, 0.0, Bound::Exclusive, "?!"),
+	/// 	Err(String::from("invalid bounds: lower > upper"))
+	/// );
+	/// ```
+	pub fn is_between_bounds<T: PartialOrd, E: Into<String>>(
+		value: T,
+		lower_bound: T,
+		lower_mode: Bound,
+		upper_bound: T,
+		upper_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		ensure_ordered(&lower_bound, &upper_bound)?;
+
+		let above_lower = match lower_mode {
+			Bound::Inclusive => value >= lower_bound,
+			Bound::Exclusive => value > lower_bound,
+		};
+		let below_upper = match upper_mode {
+			Bound::Inclusive => value <= upper_bound,
+			Bound::Exclusive => value < upper_bound,
+		};
+
+		if above_lower && below_upper {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is outside `lower_bound` and `upper_bound`, with a separate [`Bound`] for each end.
+	///
+	/// Returns [`Ok`] if the value is below `lower_bound` or above `upper_bound`, otherwise returns [`Err`] with
+	/// the given `error_message`. `lower_mode` and `upper_mode` say whether each bound belongs to the range that
+	/// is excluded, so this is the opposite of [`Guards::is_between_bounds`] with the same arguments, except that
+	/// values like [`f64::NAN`] fail both. If `lower_bound` is greater than `upper_bound`, [`Err`] with
+	/// `"invalid bounds: lower > upper"` is returned instead.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// // Outside [0, 1)
+	/// let is_out_of_range = |value: f64| Guards::is_outside_bounds(value, 0.0, Bound::Inclusive, 1.0, Bound::Exclusive, "In [0, 1)!");
+	///
+	/// assert_eq!(is_out_of_range(1.0), Ok(1.0));
+	/// assert_eq!(is_out_of_range(-0.1), Ok(-0.1));
+	/// assert_eq!(is_out_of_range(0.0), Err(String::from("In [0, 1)!")));
+	/// assert!(is_out_of_range(0.5).is_err());
+	/// assert!(is_out_of_range(f64::NAN).is_err());
+	/// ```
+	pub fn is_outside_bounds<T: PartialOrd, E: Into<String>>(
+		value: T,
+		lower_bound: T,
+		lower_mode: Bound,
+		upper_bound: T,
+		upper_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		ensure_ordered(&lower_bound, &upper_bound)?;
+
+		let below_lower = match lower_mode {
+			Bound::Inclusive => value < lower_bound,
+			Bound::Exclusive => value <= lower_bound,
+		};
+		let above_upper = match upper_mode {
+			Bound::Inclusive => value > upper_bound,
+			Bound::Exclusive => value >= upper_bound,
+		};
+
+		if below_lower || above_upper {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is in `range`.
+	///
+	/// Returns [`Ok`] if `range` contains the value, otherwise returns [`Err`] with the given `error_message`. Any
+	/// standard range works, like `1..=15`, `0..10`, `5..` or `..=100`, and each end is included, excluded or
+	/// unbounded as in [`std::ops::Bound`], which is unrelated to this crate's [`Bound`]. The full range `..`
+	/// contains every value.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_in_range(15, 1..=15, "?!"), Ok(15));
+	/// assert_eq!(Guards::is_in_range(0, 0..10, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_in_range(1_000, 5.., "?!"), Ok(1_000));
+	/// assert_eq!(Guards::is_in_range(-3, ..=100, "?!"), Ok(-3));
+	/// assert_eq!(Guards::is_in_range(i64::MIN, .., "?!"), Ok(i64::MIN));
+	/// assert_eq!(Guards::is_in_range("m", "a".."n", "?!"), Ok("m"));
+	///
+	/// assert_eq!(Guards::is_in_range(16, 1..=15, "Invalid channel!"), Err(String::from("Invalid channel!")));
+	/// assert!(Guards::is_in_range(10, 0..10, "Invalid digit!").is_err());
+	/// assert!(Guards::is_in_range(4, 5.., "Too small!").is_err());
+	/// assert!(Guards::is_in_range(f64::NAN, 0.0..=1.0, "Not a ratio!").is_err());
+	/// ```
+	pub fn is_in_range<T: PartialOrd, R: RangeBounds<T>, E: Into<String>>(
+		value: T,
+		range: R,
+		error_message: E,
+	) -> Result<T, String> {
+		if range.contains(&value) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is not in `range`.
+	///
+	/// Returns [`Ok`] if `range` does not contain the value, otherwise returns [`Err`] with the given
+	/// `error_message`. See [`Guards::is_in_range`] for the ranges that can be used. The full range `..` contains
+	/// every value, so it always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_not_in_range(8080, 0..1024, "?!"), Ok(8080));
+	/// assert_eq!(Guards::is_not_in_range(1024, 0..1024, "?!"), Ok(1024));
+	/// assert_eq!(Guards::is_not_in_range(5, 6.., "?!"), Ok(5));
+	///
+	/// assert_eq!(Guards::is_not_in_range(80, 0..1024, "Privileged port!"), Err(String::from("Privileged port!")));
+	/// assert!(Guards::is_not_in_range(100, ..=100, "Too small!").is_err());
+	/// assert!(Guards::is_not_in_range(42, .., "Always fails!").is_err());
+	/// ```
+	pub fn is_not_in_range<T: PartialOrd, R: RangeBounds<T>, E: Into<String>>(
+		value: T,
+		range: R,
+		error_message: E,
+	) -> Result<T, String> {
+		if range.contains(&value) {
+			Err(error_message.into())
+		} else {
+			Ok(value)
+		}
+	}
+
+	/// Ensures that `value` is greater than every value in `others`.
+	///
+	/// Returns [`Ok`] if `value > other` for every value, otherwise returns [`Err`] with the given `error_message`.
+	/// An empty `others` always passes, as there is nothing to be less than or equal to.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let scores = [120, 340, 95];
+	///
+	/// assert_eq!(Guards::is_greater_than_all(400, scores, "?!"), Ok(400));
+	/// assert_eq!(Guards::is_greater_than_all(340, scores, "Not a high score!"), Err(String::from("Not a high score!")));
+	/// assert_eq!(Guards::is_greater_than_all(200, scores, "Not a high score!"), Err(String::from("Not a high score!")));
+	///
+	/// assert_eq!(Guards::is_greater_than_all(0, [], "?!"), Ok(0));
+	/// assert!(Guards::is_greater_than_all(1.0, [0.5, f64::NAN], "NaN").is_err());
+	/// ```
+	pub fn is_greater_than_all<T: Borrow<U>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		others: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Result<T, String> {
+		if others.into_iter().all(|other| *value.borrow() > other) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is less than every value in `others`.
+	///
+	/// Returns [`Ok`] if `value < other` for every value, otherwise returns [`Err`] with the given `error_message`.
+	/// An empty `others` always passes, as there is nothing to be greater than or equal to.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let times = [12.5, 13.1, 12.9];
+	///
+	/// assert_eq!(Guards::is_less_than_all(12.4, times, "?!"), Ok(12.4));
+	/// assert_eq!(Guards::is_less_than_all(12.5, times, "Not a record!"), Err(String::from("Not a record!")));
+	/// assert_eq!(Guards::is_less_than_all(13.0, times, "Not a record!"), Err(String::from("Not a record!")));
+	///
+	/// assert_eq!(Guards::is_less_than_all(99.0, [], "?!"), Ok(99.0));
+	/// ```
+	pub fn is_less_than_all<T: Borrow<U>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		others: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Result<T, String> {
+		if others.into_iter().all(|other| *value.borrow() < other) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is greater than at least one value in `others`.
+	///
+	/// Returns [`Ok`] if `value > other` for some value, otherwise returns [`Err`] with the given `error_message`.
+	/// An empty `others` always fails, as there is nothing to be greater than.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let scores = [120, 340, 95];
+	///
+	/// assert_eq!(Guards::is_greater_than_any(100, scores, "?!"), Ok(100));
+	/// assert_eq!(Guards::is_greater_than_any(95, scores, "Lowest score!"), Err(String::from("Lowest score!")));
+	///
+	/// assert_eq!(Guards::is_greater_than_any(0, [], "Nothing to beat!"), Err(String::from("Nothing to beat!")));
+	/// ```
+	pub fn is_greater_than_any<T: Borrow<U>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		others: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Result<T, String> {
+		if others.into_iter().any(|other| *value.borrow() > other) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is less than at least one value in `others`.
+	///
+	/// Returns [`Ok`] if `value < other` for some value, otherwise returns [`Err`] with the given `error_message`.
+	/// An empty `others` always fails, as there is nothing to be less than.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let times = [12.5, 13.1, 12.9];
+	///
+	/// assert_eq!(Guards::is_less_than_any(13.0, times, "?!"), Ok(13.0));
+	/// assert_eq!(Guards::is_less_than_any(13.1, times, "Slowest time!"), Err(String::from("Slowest time!")));
+	///
+	/// assert_eq!(Guards::is_less_than_any(0.0, [], "Nothing to beat!"), Err(String::from("Nothing to beat!")));
+	/// ```
+	pub fn is_less_than_any<T: Borrow<U>, U: PartialOrd, E: Into<String>>(
+		value: T,
+		others: impl IntoIterator<Item = U>,
+		error_message: E,
+	) -> Result<T, String> {
+		if others.into_iter().any(|other| *value.borrow() < other) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` passes every rule in `rules`.
+	///
+	/// Each rule is a predicate paired with the error message to use when it returns `false`.
+	/// Returns [`Ok`] if every predicate passes, otherwise returns [`Err`] with the message of the first failing rule.
+	/// An empty list of rules always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guards, Rule};
+	///
+	/// let rules: &[Rule<i32>] = &[
+	/// 	(&|value| *value > 0, "Value was not positive!"),
+	/// 	(&|value| value % 2 == 0, "Value was not even!"),
+	/// ];
+	///
+	/// assert_eq!(Guards::all_of(4, rules), Ok(4));
+	/// assert_eq!(Guards::all_of(-4, rules), Err(String::from("Value was not positive!")));
+	/// assert_eq!(Guards::all_of(3, rules), Err(String::from("Value was not even!")));
+	/// assert_eq!(Guards::all_of(3, &[]), Ok(3));
+	/// ```
+	pub fn all_of<T: Borrow<U>, U: ?Sized>(
+		value: T,
+		rules: &[Rule<U>],
+	) -> Result<T, String> {
+		match rules.iter().find(|(rule, _)| !rule(value.borrow())) {
+			None => Ok(value),
+			Some((_, error_message)) => Err(error_message.to_string()),
+		}
+	}
+
+	/// Ensures that `value` passes at least one rule in `rules`.
+	///
+	/// Each rule is a predicate paired with the error message to use when it returns `false`.
+	/// Returns [`Ok`] if any predicate passes, otherwise returns [`Err`] with the messages of all rules joined by `"; "`.
+	/// An empty list of rules always fails with `"no rules given"`, as no rule can pass.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guards, Rule};
+	///
+	/// let rules: &[Rule<i32>] = &[
+	/// 	(&|value| *value > 10, "Value was not greater than 10!"),
+	/// 	(&|value| value % 2 == 0, "Value was not even!"),
+	/// ];
+	///
+	/// assert_eq!(Guards::any_of(11, rules), Ok(11));
+	/// assert_eq!(Guards::any_of(4, rules), Ok(4));
+	/// assert_eq!(
+	/// 	Guards::any_of(3, rules),
+	/// 	Err(String::from("Value was not greater than 10!; Value was not even!"))
+	/// );
+	/// assert_eq!(Guards::any_of(3, &[]), Err(String::from("no rules given")));
+	/// ```
+	pub fn any_of<T: Borrow<U>, U: ?Sized>(
+		value: T,
+		rules: &[Rule<U>],
+	) -> Result<T, String> {
+		if rules.is_empty() {
+			Err(String::from("no rules given"))
+		} else if rules.iter().any(|(rule, _)| rule(value.borrow())) {
+			Ok(value)
+		} else {
+			Err(rules.iter().map(|(_, error_message)| *error_message).collect::<Vec<_>>().join("; "))
+		}
+	}
+
+	/// Ensures that `value` can be converted into `U` without losing information.
+	///
+	/// Returns [`Ok`] with the converted value if [`TryFrom`] succeeds, otherwise returns [`Err`] with the given
+	/// `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::fits_in::<u8>(255u64, "?!"), Ok(255u8));
+	/// assert_eq!(Guards::fits_in::<u8>(256u64, "Value does not fit in u8!"), Err(String::from("Value does not fit in u8!")));
+	/// assert_eq!(Guards::fits_in::<u32>(-1i64, "Value does not fit in u32!"), Err(String::from("Value does not fit in u32!")));
+	/// ```
+	pub fn fits_in<U>(
+		value: impl TryInto<U>,
+		error_message: impl Into<String>,
+	) -> Result<U, String> {
+		value.try_into().map_err(|_| error_message.into())
+	}
+}
+
+/// Returns [`Err`] with `"invalid bounds: lower > upper"` if `lower` is greater than `upper`.
+pub(crate) fn ensure_ordered<T: PartialOrd>(
+	lower: &T,
+	upper: &T,
+) -> Result<(), String> {
+	if lower > upper {
+		Err(String::from("invalid bounds: lower > upper"))
+	} else {
+		Ok(())
+	}
+}
+
+/// Returns `a` and `b` with the smaller one first.
+fn ordered<T: PartialOrd>(
+	a: T,
+	b: T,
+) -> (T, T) {
+	if b < a {
+		(b, a)
+	} else {
+		(a, b)
+	}
 }
@@ -0,0 +1,122 @@
+use std::iter::FusedIterator;
+
+use crate::Guards;
+
+/// An iterator that checks each item as it is pulled.
+///
+/// Created by [`Guards::iter`]. Yields [`Ok`] with every item that passes the check and [`Err`] with the error of
+/// every item that fails it. By default the iterator ends after the first [`Err`], call [`GuardIter::eager`] to
+/// keep going. Items are never collected or cloned, so huge or endless streams can be validated while they are
+/// consumed.
+///
+/// ## Example
+/// ```
+/// use fluent_guards::Guards;
+///
+/// let is_port = |port: &u32| Guards::is_less_than(*port, 65536, "Invalid port").map(|_| ());
+///
+/// let ports: Result<Vec<u32>, String> = Guards::iter([80, 443, 8080], is_port).collect();
+/// assert_eq!(ports, Ok(vec![80, 443, 8080]));
+///
+/// let ports: Result<Vec<u32>, String> = Guards::iter([80, 70000, 443], is_port).collect();
+/// assert_eq!(ports, Err(String::from("Invalid port")));
+/// ```
+pub struct GuardIter<I, F> {
+	iter: I,
+	check: F,
+	eager: bool,
+	done: bool,
+}
+
+impl<I: Iterator, F: FnMut(&I::Item) -> Result<(), String>> GuardIter<I, F> {
+	/// Keeps checking and yielding items after an item fails, instead of ending the iterator.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_even = |number: &i32| Guards::is_multiple_of(*number, 2, "Odd number").map(|_| ());
+	///
+	/// let lazy: Vec<Result<i32, String>> = Guards::iter([2, 3, 4], is_even).collect();
+	/// assert_eq!(lazy, vec![Ok(2), Err(String::from("Odd number"))]);
+	///
+	/// let eager: Vec<Result<i32, String>> = Guards::iter([2, 3, 4], is_even).eager().collect();
+	/// assert_eq!(eager, vec![Ok(2), Err(String::from("Odd number")), Ok(4)]);
+	/// ```
+	pub fn eager(self) -> Self {
+		GuardIter { eager: true, ..self }
+	}
+
+	/// Yields the items that pass the check, stopping at the first item that fails it.
+	///
+	/// The error of the failing item is dropped, use the [`GuardIter`] itself when it is needed.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_header = |line: &&str| Guards::contains_str(*line, ":", "Not a header").map(|_| ());
+	///
+	/// let request = "Host: example.com\nAccept: */*\n\n{\"id\": 1}";
+	/// let headers: Vec<&str> = Guards::iter(request.lines(), is_header).take_valid().collect();
+	/// assert_eq!(headers, vec!["Host: example.com", "Accept: */*"]);
+	/// ```
+	pub fn take_valid(self) -> impl Iterator<Item = I::Item> {
+		self.map_while(Result::ok)
+	}
+}
+
+impl<I: Iterator, F: FnMut(&I::Item) -> Result<(), String>> Iterator for GuardIter<I, F> {
+	type Item = Result<I::Item, String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+
+		let item = self.iter.next()?;
+		match (self.check)(&item) {
+			Ok(()) => Some(Ok(item)),
+			Err(error) => {
+				self.done = !self.eager;
+				Some(Err(error))
+			},
+		}
+	}
+}
+
+impl<I: FusedIterator, F: FnMut(&I::Item) -> Result<(), String>> FusedIterator for GuardIter<I, F> {}
+
+impl Guards {
+	/// Checks each item of `iter` with `check` as the items are pulled.
+	///
+	/// Returns a [`GuardIter`] yielding [`Ok`] with every item that passes and [`Err`] with the error of the first
+	/// item that fails, after which it ends. See [`GuardIter`] for more details.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_positive = |amount: &i64| Guards::is_greater_than(*amount, 0, "Amounts must be positive").map(|_| ());
+	///
+	/// let total: Result<i64, String> = Guards::iter(vec![10, 25, 5], is_positive).sum();
+	/// assert_eq!(total, Ok(40));
+	///
+	/// let total: Result<i64, String> = Guards::iter(vec![10, -25, 5], is_positive).sum();
+	/// assert_eq!(total, Err(String::from("Amounts must be positive")));
+	///
+	/// let mut amounts = Guards::iter(Vec::new(), is_positive);
+	/// assert_eq!(amounts.next(), None);
+	/// ```
+	pub fn iter<I: IntoIterator, F: FnMut(&I::Item) -> Result<(), String>>(
+		iter: I,
+		check: F,
+	) -> GuardIter<I::IntoIter, F> {
+		GuardIter {
+			iter: iter.into_iter(),
+			check,
+			eager: false,
+			done: false,
+		}
+	}
+}
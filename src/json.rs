@@ -0,0 +1,112 @@
+use serde_json::Value;
+
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is a valid JSON document.
+	///
+	/// Returns [`Ok`] if the string parses as a single JSON value, otherwise returns [`Err`] with the given
+	/// `error_message`. Whitespace around the value is allowed, anything else after it is not. Any JSON value
+	/// passes, including scalars like `42` or `null`, see [`Guards::is_json_object`] to require an object.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_valid_json(r#"{"name": "Ferris"}"#, "?!"), Ok(r#"{"name": "Ferris"}"#));
+	/// assert_eq!(Guards::is_valid_json(" [1, 2, 3]\n", "?!"), Ok(" [1, 2, 3]\n"));
+	/// assert_eq!(Guards::is_valid_json("null", "?!"), Ok("null"));
+	///
+	/// assert_eq!(Guards::is_valid_json(r#"{"name": "Ferris""#, "Invalid JSON!"), Err(String::from("Invalid JSON!")));
+	/// assert!(Guards::is_valid_json(r#"{"name": "Ferris"} trailing"#, "Invalid JSON!").is_err());
+	/// assert!(Guards::is_valid_json("{} {}", "Invalid JSON!").is_err());
+	/// assert!(Guards::is_valid_json("{'name': 'Ferris'}", "Invalid JSON!").is_err());
+	/// assert!(Guards::is_valid_json("", "Invalid JSON!").is_err());
+	/// ```
+	pub fn is_valid_json<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if serde_json::from_str::<Value>(value.as_ref()).is_ok() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a valid JSON document with an object at the top level.
+	///
+	/// Returns [`Ok`] if the string passes [`Guards::is_valid_json`] and the value is an object, otherwise returns
+	/// [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_json_object(r#"{"name": "Ferris"}"#, "?!"), Ok(r#"{"name": "Ferris"}"#));
+	/// assert_eq!(Guards::is_json_object("{}", "?!"), Ok("{}"));
+	///
+	/// assert_eq!(Guards::is_json_object("[{}]", "Not an object!"), Err(String::from("Not an object!")));
+	/// assert!(Guards::is_json_object(r#""{}""#, "Not an object!").is_err());
+	/// assert!(Guards::is_json_object("{}]", "Not an object!").is_err());
+	/// ```
+	pub fn is_json_object<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if let Ok(Value::Object(_)) = serde_json::from_str(value.as_ref()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` is a valid JSON document.
+	///
+	/// See [Guards::is_valid_json] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(r#"["red", "green"]"#).is_valid_json("?!").result();
+	/// assert_eq!(pass, Ok(r#"["red", "green"]"#));
+	///
+	/// let fail = Guard::new(String::from(r#"["red", "green",]"#)).is_valid_json("Settings must be valid JSON").result();
+	/// assert_eq!(fail, Err(String::from("Settings must be valid JSON")));
+	/// ```
+	pub fn is_valid_json<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_json", |value| {
+			Guards::is_valid_json(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a valid JSON document with an object at the top level.
+	///
+	/// See [Guards::is_json_object] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(r#"{"theme": "dark"}"#).is_json_object("?!").result();
+	/// assert_eq!(pass, Ok(r#"{"theme": "dark"}"#));
+	///
+	/// let fail = Guard::new(r#"["theme", "dark"]"#).is_json_object("Settings must be a JSON object").result();
+	/// assert_eq!(fail, Err(String::from("Settings must be a JSON object")));
+	/// ```
+	pub fn is_json_object<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_json_object", |value| {
+			Guards::is_json_object(value, error_message).map(|_| ())
+		})
+	}
+}
@@ -1,12 +1,111 @@
 //! Provides various functions to guard your code.
 
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "bigint")]
+mod bigint;
+mod boolean;
+mod bytes;
+mod character;
+mod collection;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "email")]
+mod email;
+mod float;
 mod guard;
+mod guard_mut;
 mod guards;
+mod iter;
+#[cfg(feature = "json")]
+mod json;
+mod number;
+mod numeric;
+mod option;
+mod password;
+#[cfg(feature = "regex")]
+mod pattern;
+mod phone;
+#[cfg(feature = "math")]
+mod prime;
+mod result;
+mod string;
+mod trace;
+#[cfg(feature = "unicode")]
+mod unicode;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
+mod validated;
 
+#[cfg(feature = "bigint")]
+pub use bigint::BigInteger;
+pub use collection::{
+	ContainsKey,
+	Entries,
+	HasLength,
+	Interval,
+};
 pub use guard::Guard;
+pub use guard_mut::GuardMut;
 pub use guards::Guards;
+pub use iter::GuardIter;
+pub use number::{
+	DecimalPlaces,
+	Divisible,
+	Float,
+	Integer,
+	Number,
+	Signed,
+	Unsigned,
+};
+pub use numeric::NumericStringOptions;
+pub use password::PasswordPolicy;
+#[cfg(feature = "math")]
+pub use prime::PrimeCandidate;
+pub use trace::{
+	CheckOutcome,
+	CheckTrace,
+};
+pub use validated::Validated;
 
+/// Whether the bounds of a range guard belong to the range.
+///
+/// Defaults to [`Bound::Inclusive`].
+///
+/// ## Example
+/// ```
+/// use fluent_guards::{Bound, Guards};
+///
+/// let bound_mode = Bound::from_inclusive(false);
+/// assert_eq!(bound_mode, Bound::Exclusive);
+/// assert_eq!(Bound::default(), Bound::Inclusive);
+///
+/// assert!(Guards::is_between(1, 1, 5, bound_mode, "?!").is_err());
+/// assert!(Guards::is_outside(1, 1, 5, bound_mode, "?!").is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum Bound {
+	/// The bounds are part of the range.
+	#[default]
 	Inclusive,
+	/// The bounds are not part of the range.
 	Exclusive,
 }
+
+impl Bound {
+	/// Returns [`Bound::Inclusive`] if `inclusive` is `true`, otherwise [`Bound::Exclusive`].
+	pub fn from_inclusive(inclusive: bool) -> Self {
+		if inclusive {
+			Bound::Inclusive
+		} else {
+			Bound::Exclusive
+		}
+	}
+}
+
+/// A predicate paired with the error message to use when it returns `false`.
+///
+/// Used by [`Guard::all_of`] and [`Guard::any_of`].
+pub type Rule<'a, T> = (&'a dyn Fn(&T) -> bool, &'a str);
@@ -0,0 +1,1962 @@
+use std::{
+	cmp::Ordering,
+	num::NonZero,
+	ops::{
+		Add,
+		BitAnd,
+		Div,
+		Mul,
+		Rem,
+		Sub,
+	},
+};
+
+use crate::{
+	Bound,
+	Guard,
+	Guards,
+};
+
+pub(crate) mod sealed {
+	pub trait Sealed {}
+
+	impl<T: Sealed> Sealed for &T {}
+}
+
+/// Implemented for the primitive integer and float types, and for `rust_decimal::Decimal` with the
+/// `decimal` feature.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Number:
+	Copy
+	+ PartialOrd
+	+ Add<Output = Self>
+	+ Sub<Output = Self>
+	+ Mul<Output = Self>
+	+ Div<Output = Self>
+	+ Rem<Output = Self>
+	+ sealed::Sealed
+{
+	/// The zero value of the type.
+	const ZERO: Self;
+
+	/// The value one hundred of the type.
+	const ONE_HUNDRED: Self;
+
+	/// The type sums and means are computed in, wide enough that adding up a collection of values does not
+	/// overflow it in practice: [`i128`] for the integers narrower than 128 bits, [`f64`] for the floats, and the
+	/// type itself otherwise.
+	type Accumulator: Number;
+
+	/// Compares the absolute difference between `self` and `other` with `delta`, without overflowing.
+	///
+	/// Returns [`None`] if the difference and `delta` are unordered, for example when a float is `NaN`.
+	fn cmp_difference(
+		self,
+		other: Self,
+		delta: Self,
+	) -> Option<Ordering>;
+
+	/// Checked addition, see [`i32::checked_add`].
+	///
+	/// For floats, returns [`None`] if adding two finite values results in an infinite value.
+	fn checked_add(
+		self,
+		rhs: Self,
+	) -> Option<Self>;
+
+	/// Checked multiplication, see [`i32::checked_mul`].
+	///
+	/// For floats, returns [`None`] if multiplying two finite values results in an infinite value.
+	fn checked_mul(
+		self,
+		rhs: Self,
+	) -> Option<Self>;
+
+	/// Converts a count, like the length of a collection, if it fits in the type.
+	fn from_count(count: usize) -> Option<Self>;
+
+	/// Converts the value into [`Number::Accumulator`] without losing precision.
+	fn to_accumulator(self) -> Self::Accumulator;
+}
+
+/// Implemented for the primitive integer types.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Integer: Number + Divisible + Eq + Ord + BitAnd<Output = Self> {
+	/// The value one of the type.
+	const ONE: Self;
+
+	/// The matching non-zero type, for example [`NonZeroU32`](std::num::NonZeroU32) for [`u32`].
+	type NonZero: Copy + PartialOrd;
+
+	/// Converts into the matching non-zero type, see [`NonZero::new`].
+	fn to_non_zero(self) -> Option<Self::NonZero>;
+
+	/// Converts a finite whole number `value` if it fits in the type.
+	fn from_whole_f64(value: f64) -> Option<Self>;
+
+	/// Checked subtraction, see [`i32::checked_sub`].
+	fn checked_sub(
+		self,
+		rhs: Self,
+	) -> Option<Self>;
+
+	/// Checked remainder, see [`i32::checked_rem`].
+	fn checked_rem(
+		self,
+		rhs: Self,
+	) -> Option<Self>;
+}
+
+/// Implemented for the primitive signed integer and float types, and for `rust_decimal::Decimal` with the
+/// `decimal` feature.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Signed: Number {}
+
+/// Implemented for the primitive unsigned integer types.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Unsigned: Integer {
+	/// Returns `true` if the value is a power of two, see [`u32::is_power_of_two`].
+	fn is_power_of_two(self) -> bool;
+}
+
+/// Implemented for the primitive float types.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Float: Number + From<u8> {
+	/// The machine epsilon of the type, see [`f64::EPSILON`].
+	const EPSILON: Self;
+
+	/// Returns `true` if the value is neither infinite nor `NaN`, see [`f64::is_finite`].
+	fn is_finite(self) -> bool;
+
+	/// Returns `true` if the value is `NaN`, see [`f64::is_nan`].
+	fn is_nan(self) -> bool;
+
+	/// Returns `true` if the value is neither zero, infinite, subnormal nor `NaN`, see [`f64::is_normal`].
+	fn is_normal(self) -> bool;
+
+	/// Returns the absolute value, see [`f64::abs`].
+	fn abs(self) -> Self;
+
+	/// Returns the fractional part, see [`f64::fract`].
+	fn fract(self) -> Self;
+
+	/// Converts the value into an [`f64`] without losing precision.
+	fn to_f64(self) -> f64;
+
+	/// Returns the number of representable values between `self` and `other`, counting across zero.
+	///
+	/// `0.0` and `-0.0` are the same value. The result is meaningless if either value is `NaN`.
+	fn ulp_distance(
+		self,
+		other: Self,
+	) -> u64;
+}
+
+/// Implemented for types whose values can be checked for a maximum number of decimal places.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait DecimalPlaces: Copy + PartialOrd + sealed::Sealed {
+	/// Returns `true` if the value has at most `decimal_places` digits after the decimal point.
+	fn has_max_decimal_places(
+		self,
+		decimal_places: u32,
+	) -> bool;
+}
+
+/// Implemented for types that can be checked for parity and divisibility.
+///
+/// This is implemented for the primitive integer types, and for `num_bigint::BigInt` and `num_bigint::BigUint`
+/// with the `bigint` feature. It is also implemented for references to those types.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Divisible: PartialOrd + sealed::Sealed {
+	/// Returns `true` if the value is even.
+	fn is_even(&self) -> bool;
+
+	/// Returns `true` if the value is a multiple of `divisor`, or [`None`] if `divisor` is zero.
+	fn checked_is_multiple_of(
+		&self,
+		divisor: &Self,
+	) -> Option<bool>;
+}
+
+impl<T: Divisible> Divisible for &T {
+	fn is_even(&self) -> bool {
+		T::is_even(self)
+	}
+
+	fn checked_is_multiple_of(
+		&self,
+		divisor: &Self,
+	) -> Option<bool> {
+		T::checked_is_multiple_of(self, divisor)
+	}
+}
+
+macro_rules! impl_integer {
+	($($t:ty => $accumulator:ty),* $(,)?) => {
+		$(
+			impl sealed::Sealed for $t {}
+
+			impl Number for $t {
+				const ZERO: Self = 0;
+				const ONE_HUNDRED: Self = 100;
+
+				type Accumulator = $accumulator;
+
+				fn cmp_difference(
+					self,
+					other: Self,
+					delta: Self,
+				) -> Option<Ordering> {
+					// `abs_diff` returns the unsigned type of the same width, which always fits the difference
+					match delta.try_into() {
+						Ok(delta) => Some(self.abs_diff(other).cmp(&delta)),
+						Err(_) => Some(Ordering::Greater),
+					}
+				}
+
+				fn checked_add(
+					self,
+					rhs: Self,
+				) -> Option<Self> {
+					<$t>::checked_add(self, rhs)
+				}
+
+				fn checked_mul(
+					self,
+					rhs: Self,
+				) -> Option<Self> {
+					<$t>::checked_mul(self, rhs)
+				}
+
+				fn from_count(count: usize) -> Option<Self> {
+					<$t>::try_from(count).ok()
+				}
+
+				fn to_accumulator(self) -> Self::Accumulator {
+					self as $accumulator
+				}
+			}
+
+			impl Divisible for $t {
+				fn is_even(&self) -> bool {
+					*self % 2 == 0
+				}
+
+				fn checked_is_multiple_of(
+					&self,
+					divisor: &Self,
+				) -> Option<bool> {
+					if *divisor == 0 {
+						return None;
+					}
+
+					// Only `MIN % -1` overflows, which is a multiple
+					Some(<$t>::checked_rem(*self, *divisor).unwrap_or(0) == 0)
+				}
+			}
+
+			impl Integer for $t {
+				const ONE: Self = 1;
+
+				type NonZero = NonZero<$t>;
+
+				fn to_non_zero(self) -> Option<Self::NonZero> {
+					NonZero::new(self)
+				}
+
+				fn from_whole_f64(value: f64) -> Option<Self> {
+					// `MAX` is always `2^n - 1`, so `MAX as f64 + 1.0` is the exact exclusive upper bound
+					if value >= <$t>::MIN as f64 && value < <$t>::MAX as f64 + 1.0 {
+						Some(value as $t)
+					} else {
+						None
+					}
+				}
+
+				fn checked_sub(
+					self,
+					rhs: Self,
+				) -> Option<Self> {
+					<$t>::checked_sub(self, rhs)
+				}
+
+				fn checked_rem(
+					self,
+					rhs: Self,
+				) -> Option<Self> {
+					<$t>::checked_rem(self, rhs)
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_float {
+	($($t:ty => $bits:ty),* $(,)?) => {
+		$(
+			impl sealed::Sealed for $t {}
+
+			impl Number for $t {
+				const ZERO: Self = 0.0;
+				const ONE_HUNDRED: Self = 100.0;
+
+				type Accumulator = f64;
+
+				fn cmp_difference(
+					self,
+					other: Self,
+					delta: Self,
+				) -> Option<Ordering> {
+					(self - other).abs().partial_cmp(&delta)
+				}
+
+				fn checked_add(
+					self,
+					rhs: Self,
+				) -> Option<Self> {
+					let sum = self + rhs;
+					if sum.is_infinite() && self.is_finite() && rhs.is_finite() {
+						None
+					} else {
+						Some(sum)
+					}
+				}
+
+				fn checked_mul(
+					self,
+					rhs: Self,
+				) -> Option<Self> {
+					let product = self * rhs;
+					if product.is_infinite() && self.is_finite() && rhs.is_finite() {
+						None
+					} else {
+						Some(product)
+					}
+				}
+
+				fn from_count(count: usize) -> Option<Self> {
+					Some(count as $t)
+				}
+
+				fn to_accumulator(self) -> Self::Accumulator {
+					f64::from(self)
+				}
+			}
+
+			impl DecimalPlaces for $t {
+				fn has_max_decimal_places(
+					self,
+					decimal_places: u32,
+				) -> bool {
+					let scaled = f64::from(self) * 10f64.powi(i32::try_from(decimal_places).unwrap_or(i32::MAX));
+					if !scaled.is_finite() {
+						return self.is_finite();
+					}
+
+					// Allow for the rounding error of the original type, scaled with the magnitude
+					(scaled - scaled.round()).abs() <= scaled.abs().max(1.0) * f64::from(<$t>::EPSILON) * 2.0
+				}
+			}
+
+			impl Float for $t {
+				const EPSILON: Self = <$t>::EPSILON;
+
+				fn is_finite(self) -> bool {
+					<$t>::is_finite(self)
+				}
+
+				fn is_nan(self) -> bool {
+					<$t>::is_nan(self)
+				}
+
+				fn is_normal(self) -> bool {
+					<$t>::is_normal(self)
+				}
+
+				fn abs(self) -> Self {
+					<$t>::abs(self)
+				}
+
+				fn fract(self) -> Self {
+					<$t>::fract(self)
+				}
+
+				fn to_f64(self) -> f64 {
+					f64::from(self)
+				}
+
+				fn ulp_distance(
+					self,
+					other: Self,
+				) -> u64 {
+					// Map the sign-magnitude bit patterns onto a two's complement scale so that
+					// adjacent floats are adjacent integers, with both zeros at 0
+					fn ordered(value: $t) -> i64 {
+						let bits = value.to_bits() as $bits;
+						i64::from(if bits < 0 { <$bits>::MIN - bits } else { bits })
+					}
+
+					ordered(self).abs_diff(ordered(other))
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_unsigned {
+	($($t:ty),* $(,)?) => {
+		$(
+			impl Unsigned for $t {
+				fn is_power_of_two(self) -> bool {
+					<$t>::is_power_of_two(self)
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_signed {
+	($($t:ty),* $(,)?) => {
+		$(
+			impl Signed for $t {}
+		)*
+	};
+}
+
+impl_integer!(
+	i8 => i128,
+	i16 => i128,
+	i32 => i128,
+	i64 => i128,
+	i128 => i128,
+	isize => i128,
+	u8 => i128,
+	u16 => i128,
+	u32 => i128,
+	u64 => i128,
+	u128 => u128,
+	usize => i128,
+);
+impl_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_float!(f32 => i32, f64 => i64);
+
+impl Guards {
+	/// Ensures that `value` is greater than zero.
+	///
+	/// Returns [`Ok`] if the value is positive, otherwise returns [`Err`] with the given `error_message`.
+	/// Both `0.0` and `-0.0` are zero and not positive, `NaN` is never positive.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_positive(5, "?!"), Ok(5));
+	/// assert_eq!(Guards::is_positive(0, "Value was not positive!"), Err(String::from("Value was not positive!")));
+	/// assert_eq!(Guards::is_positive(-5, "Value was not positive!"), Err(String::from("Value was not positive!")));
+	///
+	/// assert_eq!(Guards::is_positive(0.1, "?!"), Ok(0.1));
+	/// assert!(Guards::is_positive(-0.0, "-0.0 is zero").is_err());
+	/// assert!(Guards::is_positive(f64::NAN, "NaN").is_err());
+	/// ```
+	pub fn is_positive<T: Number, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value > T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is less than zero.
+	///
+	/// Returns [`Ok`] if the value is negative, otherwise returns [`Err`] with the given `error_message`.
+	/// `-0.0` is zero and not negative, `NaN` is never negative.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_negative(-5, "?!"), Ok(-5));
+	/// assert_eq!(Guards::is_negative(0, "Value was not negative!"), Err(String::from("Value was not negative!")));
+	/// assert_eq!(Guards::is_negative(5u8, "Value was not negative!"), Err(String::from("Value was not negative!")));
+	///
+	/// assert_eq!(Guards::is_negative(-0.1, "?!"), Ok(-0.1));
+	/// assert!(Guards::is_negative(-0.0, "-0.0 is zero").is_err());
+	/// assert!(Guards::is_negative(f64::NAN, "NaN").is_err());
+	/// ```
+	pub fn is_negative<T: Number, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value < T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is zero.
+	///
+	/// Returns [`Ok`] if the value is zero, otherwise returns [`Err`] with the given `error_message`.
+	/// Both `0.0` and `-0.0` are zero, `NaN` is not.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_zero(0, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_zero(1, "Value was not zero!"), Err(String::from("Value was not zero!")));
+	///
+	/// assert_eq!(Guards::is_zero(0.0, "?!"), Ok(0.0));
+	/// assert!(Guards::is_zero(-0.0, "?!").is_ok());
+	/// assert!(Guards::is_zero(f64::NAN, "NaN").is_err());
+	/// ```
+	pub fn is_zero<T: Number, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value == T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is greater than or equal to zero.
+	///
+	/// Returns [`Ok`] if the value is not negative, otherwise returns [`Err`] with the given `error_message`.
+	/// `-0.0` is zero and passes, `NaN` fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_non_negative(5, "?!"), Ok(5));
+	/// assert_eq!(Guards::is_non_negative(0, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_non_negative(-5, "Value was negative!"), Err(String::from("Value was negative!")));
+	///
+	/// assert!(Guards::is_non_negative(-0.0, "?!").is_ok());
+	/// assert!(Guards::is_non_negative(f64::NAN, "NaN").is_err());
+	/// ```
+	pub fn is_non_negative<T: Number, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value >= T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a percentage, between `0` and `100` (inclusive).
+	///
+	/// Returns [`Ok`] if the value is a percentage, otherwise returns [`Err`] with the given `error_message`.
+	/// `NaN` fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_percentage(0, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_percentage(100u8, "?!"), Ok(100));
+	/// assert_eq!(Guards::is_percentage(99.5, "?!"), Ok(99.5));
+	///
+	/// assert_eq!(Guards::is_percentage(100.5, "Not a percentage!"), Err(String::from("Not a percentage!")));
+	/// assert_eq!(Guards::is_percentage(-1, "Not a percentage!"), Err(String::from("Not a percentage!")));
+	/// assert!(Guards::is_percentage(f64::NAN, "Not a percentage!").is_err());
+	/// ```
+	pub fn is_percentage<T: Number, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value >= T::ZERO && value <= T::ONE_HUNDRED {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a percentage, between `0` and `100` (exclusive).
+	///
+	/// Returns [`Ok`] if the value is strictly between `0` and `100`, otherwise returns [`Err`] with the given
+	/// `error_message`. `NaN` fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_percentage_exclusive(1, "?!"), Ok(1));
+	/// assert_eq!(Guards::is_percentage_exclusive(99.5, "?!"), Ok(99.5));
+	///
+	/// assert_eq!(Guards::is_percentage_exclusive(0, "Not strictly a percentage!"), Err(String::from("Not strictly a percentage!")));
+	/// assert!(Guards::is_percentage_exclusive(100, "Not strictly a percentage!").is_err());
+	/// assert!(Guards::is_percentage_exclusive(100.5, "Not strictly a percentage!").is_err());
+	/// assert!(Guards::is_percentage_exclusive(-1, "Not strictly a percentage!").is_err());
+	/// assert!(Guards::is_percentage_exclusive(f32::NAN, "Not strictly a percentage!").is_err());
+	/// ```
+	pub fn is_percentage_exclusive<T: Number, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value > T::ZERO && value < T::ONE_HUNDRED {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` differs from `other` by at least `delta`.
+	///
+	/// Returns [`Ok`] if `|value - other| >= delta`, otherwise returns [`Err`] with the given `error_message`.
+	/// The difference is computed without overflowing, so the order of unsigned operands does not matter.
+	/// `NaN` on either side always fails. A negative or `NaN` `delta` is invalid and returns [`Err`] with
+	/// `"invalid delta: must be non-negative"`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::differs_by_at_least(10.5, 10.0, 0.5, "?!"), Ok(10.5));
+	/// assert_eq!(Guards::differs_by_at_least(3u32, 10, 7, "?!"), Ok(3));
+	/// assert_eq!(Guards::differs_by_at_least(10u32, 3, 7, "?!"), Ok(10));
+	/// assert_eq!(Guards::differs_by_at_least(i8::MIN, i8::MAX, 100, "?!"), Ok(i8::MIN));
+	/// assert_eq!(Guards::differs_by_at_least(0u8, 0, 0, "?!"), Ok(0));
+	///
+	/// assert_eq!(Guards::differs_by_at_least(5, 5, 1, "Unchanged!"), Err(String::from("Unchanged!")));
+	/// assert!(Guards::differs_by_at_least(4u32, 10, 7, "Too close!").is_err());
+	/// assert!(Guards::differs_by_at_least(f64::NAN, 10.0, 0.0, "NaN").is_err());
+	///
+	/// assert_eq!(Guards::differs_by_at_least(5, 10, -1, "?!"), Err(String::from("invalid delta: must be non-negative")));
+	/// ```
+	pub fn differs_by_at_least<T: Number, E: Into<String>>(
+		value: T,
+		other: T,
+		delta: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if delta.partial_cmp(&T::ZERO).is_none_or(Ordering::is_lt) {
+			return Err(String::from("invalid delta: must be non-negative"));
+		}
+
+		match value.cmp_difference(other, delta) {
+			Some(Ordering::Greater | Ordering::Equal) => Ok(value),
+			_ => Err(error_message.into()),
+		}
+	}
+
+	/// Ensures that `value` differs from `other` by at most `delta`.
+	///
+	/// Returns [`Ok`] if `|value - other| <= delta`, otherwise returns [`Err`] with the given `error_message`.
+	/// The difference is computed without overflowing, so the order of unsigned operands does not matter.
+	/// `NaN` on either side always fails. A negative or `NaN` `delta` is invalid and returns [`Err`] with
+	/// `"invalid delta: must be non-negative"`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::differs_by_at_most(12.0, 10.0, 2.0, "?!"), Ok(12.0));
+	/// assert_eq!(Guards::differs_by_at_most(3u32, 10, 7, "?!"), Ok(3));
+	/// assert_eq!(Guards::differs_by_at_most(10u32, 3, 7, "?!"), Ok(10));
+	/// assert_eq!(Guards::differs_by_at_most(5, 5, 0, "?!"), Ok(5));
+	///
+	/// assert_eq!(Guards::differs_by_at_most(2u32, 10, 7, "Too far!"), Err(String::from("Too far!")));
+	/// assert!(Guards::differs_by_at_most(i8::MIN, i8::MAX, i8::MAX, "Too far!").is_err());
+	/// assert!(Guards::differs_by_at_most(f64::NAN, f64::NAN, f64::INFINITY, "NaN").is_err());
+	///
+	/// assert_eq!(Guards::differs_by_at_most(5.0, 5.0, f64::NAN, "?!"), Err(String::from("invalid delta: must be non-negative")));
+	/// ```
+	pub fn differs_by_at_most<T: Number, E: Into<String>>(
+		value: T,
+		other: T,
+		delta: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if delta.partial_cmp(&T::ZERO).is_none_or(Ordering::is_lt) {
+			return Err(String::from("invalid delta: must be non-negative"));
+		}
+
+		match value.cmp_difference(other, delta) {
+			Some(Ordering::Less | Ordering::Equal) => Ok(value),
+			_ => Err(error_message.into()),
+		}
+	}
+
+	/// Ensures that `value` is within `tolerance` of at least one value in `candidates`.
+	///
+	/// Returns [`Ok`] if `|value - candidate| <= tolerance` for some candidate, otherwise returns [`Err`] with the
+	/// given `error_message`. An empty `candidates` always fails, and `NaN` never counts as close. A negative or
+	/// `NaN` `tolerance` is invalid and returns [`Err`] with `"invalid tolerance: must be non-negative"`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let setpoints = [1.0, 2.5, 5.0];
+	///
+	/// assert_eq!(Guards::is_close_to_any(2.45, setpoints, 0.1, "?!"), Ok(2.45));
+	/// assert_eq!(Guards::is_close_to_any(5.1, setpoints, 0.1, "?!"), Ok(5.1));
+	/// assert_eq!(Guards::is_close_to_any(3.5, setpoints, 0.1, "Not near a setpoint!"), Err(String::from("Not near a setpoint!")));
+	///
+	/// assert_eq!(Guards::is_close_to_any(10u32, [8, 16], 2, "?!"), Ok(10));
+	/// assert!(Guards::is_close_to_any(f64::NAN, setpoints, 0.1, "NaN").is_err());
+	/// assert!(Guards::is_close_to_any(1.0, [], 0.1, "No setpoints!").is_err());
+	///
+	/// assert_eq!(
+	/// 	Guards::is_close_to_any(1.0, setpoints, -0.1, "?!"),
+	/// 	Err(String::from("invalid tolerance: must be non-negative"))
+	/// );
+	/// ```
+	pub fn is_close_to_any<T: Number, E: Into<String>>(
+		value: T,
+		candidates: impl IntoIterator<Item = T>,
+		tolerance: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if tolerance.partial_cmp(&T::ZERO).is_none_or(Ordering::is_lt) {
+			return Err(String::from("invalid tolerance: must be non-negative"));
+		}
+
+		let mut candidates = candidates.into_iter();
+		if candidates.any(|candidate| value.cmp_difference(candidate, tolerance).is_some_and(Ordering::is_le)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has at most `decimal_places` digits after the decimal point.
+	///
+	/// Returns [`Ok`] if the value has few enough decimal places, otherwise returns [`Err`] with the given
+	/// `error_message`. `NaN` and infinite values fail.
+	///
+	/// Floats cannot represent most decimal fractions exactly, `19.99` is stored as `19.989999999999998...`,
+	/// so for floats the check allows for the rounding error of the type. This also means that a value
+	/// like `0.1 + 0.2` counts as having one decimal place. Values that need exact decimal places, such as
+	/// money, are better stored as a `rust_decimal::Decimal`, which is supported with the `decimal` feature.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_max_decimal_places(19.99, 2, "?!"), Ok(19.99));
+	/// assert_eq!(Guards::has_max_decimal_places(19.99f32, 2, "?!"), Ok(19.99));
+	/// assert_eq!(Guards::has_max_decimal_places(20.0, 0, "?!"), Ok(20.0));
+	/// assert_eq!(Guards::has_max_decimal_places(0.1 + 0.2, 1, "?!"), Ok(0.1 + 0.2));
+	/// assert_eq!(Guards::has_max_decimal_places(-0.05, 2, "?!"), Ok(-0.05));
+	///
+	/// assert_eq!(Guards::has_max_decimal_places(19.999, 2, "Too many decimals!"), Err(String::from("Too many decimals!")));
+	/// assert!(Guards::has_max_decimal_places(19.99, 1, "Too many decimals!").is_err());
+	/// assert!(Guards::has_max_decimal_places(f64::NAN, 2, "Too many decimals!").is_err());
+	/// assert!(Guards::has_max_decimal_places(f64::INFINITY, 2, "Too many decimals!").is_err());
+	/// ```
+	pub fn has_max_decimal_places<T: DecimalPlaces, E: Into<String>>(
+		value: T,
+		decimal_places: u32,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.has_max_decimal_places(decimal_places) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is an even number.
+	///
+	/// Returns [`Ok`] if the value is even, otherwise returns [`Err`] with the given `error_message`.
+	/// Negative numbers follow mathematical parity, so `-4` is even.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_even(4, "?!"), Ok(4));
+	/// assert_eq!(Guards::is_even(0, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_even(-4, "?!"), Ok(-4));
+	/// assert_eq!(Guards::is_even(3, "Value was odd!"), Err(String::from("Value was odd!")));
+	///
+	/// assert_eq!(Guards::is_even(i8::MIN, "?!"), Ok(i8::MIN));
+	/// assert_eq!(Guards::is_even(i128::MIN, "?!"), Ok(i128::MIN));
+	/// assert!(Guards::is_even(i64::MAX, "Value was odd!").is_err());
+	/// assert!(Guards::is_even(u64::MAX, "Value was odd!").is_err());
+	/// ```
+	pub fn is_even<T: Divisible, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.is_even() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is an odd number.
+	///
+	/// Returns [`Ok`] if the value is odd, otherwise returns [`Err`] with the given `error_message`.
+	/// Negative numbers follow mathematical parity, so `-3` is odd even though `-3 % 2 == -1`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_odd(3, "?!"), Ok(3));
+	/// assert_eq!(Guards::is_odd(-3, "?!"), Ok(-3));
+	/// assert_eq!(Guards::is_odd(4, "Value was even!"), Err(String::from("Value was even!")));
+	/// assert_eq!(Guards::is_odd(0, "Value was even!"), Err(String::from("Value was even!")));
+	///
+	/// assert!(Guards::is_odd(i8::MIN, "Value was even!").is_err());
+	/// assert_eq!(Guards::is_odd(i8::MIN + 1, "?!"), Ok(-127));
+	/// assert_eq!(Guards::is_odd(usize::MAX, "?!"), Ok(usize::MAX));
+	/// ```
+	pub fn is_odd<T: Divisible, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.is_even() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a multiple of `divisor`.
+	///
+	/// Returns [`Ok`] if `value` divides evenly by `divisor`, otherwise returns [`Err`] with the given `error_message`.
+	/// Zero is a multiple of every divisor and signs are ignored, so `-6` is a multiple of `3` and `6` is a
+	/// multiple of `-3`. A `divisor` of zero is invalid and returns [`Err`] with `"invalid divisor: 0"` instead.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_multiple_of(1024, 512, "?!"), Ok(1024));
+	/// assert_eq!(Guards::is_multiple_of(0, 512, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_multiple_of(1000, 512, "Not a multiple of 512!"), Err(String::from("Not a multiple of 512!")));
+	///
+	/// assert_eq!(Guards::is_multiple_of(-6, 3, "?!"), Ok(-6));
+	/// assert_eq!(Guards::is_multiple_of(6, -3, "?!"), Ok(6));
+	/// assert_eq!(Guards::is_multiple_of(i32::MIN, -1, "?!"), Ok(i32::MIN));
+	///
+	/// assert_eq!(Guards::is_multiple_of(5, 0, "?!"), Err(String::from("invalid divisor: 0")));
+	/// ```
+	pub fn is_multiple_of<T: Divisible, E: Into<String>>(
+		value: T,
+		divisor: T,
+		error_message: E,
+	) -> Result<T, String> {
+		match value.checked_is_multiple_of(&divisor) {
+			None => Err(String::from("invalid divisor: 0")),
+			Some(true) => Ok(value),
+			Some(false) => Err(error_message.into()),
+		}
+	}
+
+	/// Ensures that `value` divides `dividend` evenly.
+	///
+	/// Returns [`Ok`] if `dividend` is a multiple of `value`, otherwise returns [`Err`] with the given `error_message`.
+	/// Signs are ignored, like [`Guards::is_multiple_of`]. A `value` of zero divides nothing and fails with
+	/// `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_divisor_of(4, 100, "?!"), Ok(4));
+	/// assert_eq!(Guards::is_divisor_of(4, 0, "?!"), Ok(4));
+	/// assert_eq!(Guards::is_divisor_of(3, 100, "Does not divide 100!"), Err(String::from("Does not divide 100!")));
+	///
+	/// assert_eq!(Guards::is_divisor_of(4, -100, "?!"), Ok(4));
+	/// assert_eq!(Guards::is_divisor_of(-4, -100, "?!"), Ok(-4));
+	/// assert!(Guards::is_divisor_of(3, -100, "Does not divide -100!").is_err());
+	/// assert_eq!(Guards::is_divisor_of(-1, i32::MIN, "?!"), Ok(-1));
+	///
+	/// assert_eq!(Guards::is_divisor_of(0, 100, "Zero divides nothing!"), Err(String::from("Zero divides nothing!")));
+	/// ```
+	pub fn is_divisor_of<T: Integer, E: Into<String>>(
+		value: T,
+		dividend: T,
+		error_message: E,
+	) -> Result<T, String> {
+		// Only `MIN % -1` overflows, which divides evenly
+		if value != T::ZERO && dividend.checked_rem(value).unwrap_or(T::ZERO) == T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every bit of `mask` is set in `value`.
+	///
+	/// Returns [`Ok`] if `value & mask == mask`, otherwise returns [`Err`] with the given `error_message`.
+	/// A `mask` of zero always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// const READ: u8 = 0b001;
+	/// const WRITE: u8 = 0b010;
+	/// const EXECUTE: u8 = 0b100;
+	///
+	/// assert_eq!(Guards::has_flags_set(READ | WRITE, READ | WRITE, "?!"), Ok(0b011));
+	/// assert_eq!(Guards::has_flags_set(READ | WRITE | EXECUTE, READ, "?!"), Ok(0b111));
+	/// assert_eq!(Guards::has_flags_set(READ, READ | WRITE, "Missing permission!"), Err(String::from("Missing permission!")));
+	/// assert_eq!(Guards::has_flags_set(0, 0, "?!"), Ok(0));
+	/// ```
+	pub fn has_flags_set<T: Integer, E: Into<String>>(
+		value: T,
+		mask: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value & mask == mask {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every bit of `mask` is clear in `value`.
+	///
+	/// Returns [`Ok`] if `value & mask == 0`, otherwise returns [`Err`] with the given `error_message`.
+	/// A `mask` of zero always passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// const WRITE: u8 = 0b010;
+	/// const EXECUTE: u8 = 0b100;
+	///
+	/// assert_eq!(Guards::has_flags_clear(0b001, WRITE | EXECUTE, "?!"), Ok(0b001));
+	/// assert_eq!(Guards::has_flags_clear(0b101, WRITE | EXECUTE, "Forbidden permission!"), Err(String::from("Forbidden permission!")));
+	/// assert_eq!(Guards::has_flags_clear(0b111, 0, "?!"), Ok(0b111));
+	/// ```
+	pub fn has_flags_clear<T: Integer, E: Into<String>>(
+		value: T,
+		mask: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value & mask == T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that at least one bit of `mask` is set in `value`.
+	///
+	/// Returns [`Ok`] if `value & mask != 0`, otherwise returns [`Err`] with the given `error_message`.
+	/// A `mask` of zero always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// const READ: u8 = 0b001;
+	/// const WRITE: u8 = 0b010;
+	///
+	/// assert_eq!(Guards::has_any_flag(0b010, READ | WRITE, "?!"), Ok(0b010));
+	/// assert_eq!(Guards::has_any_flag(0b100, READ | WRITE, "No access!"), Err(String::from("No access!")));
+	/// assert_eq!(Guards::has_any_flag(0b111, 0, "No access!"), Err(String::from("No access!")));
+	/// ```
+	///
+	/// Floats are not supported:
+	/// ```compile_fail
+	/// use fluent_guards::Guards;
+	///
+	/// let _ = Guards::has_any_flag(1.0, 1.0, "?!");
+	/// ```
+	pub fn has_any_flag<T: Integer, E: Into<String>>(
+		value: T,
+		mask: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value & mask != T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a power of two.
+	///
+	/// Returns [`Ok`] if the value is a power of two, otherwise returns [`Err`] with the given `error_message`.
+	/// Zero is not a power of two, one is (`2^0`). Only available for unsigned integers.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_power_of_two(4096u32, "?!"), Ok(4096));
+	/// assert_eq!(Guards::is_power_of_two(1u8, "?!"), Ok(1));
+	/// assert_eq!(Guards::is_power_of_two(0u8, "Not a power of two!"), Err(String::from("Not a power of two!")));
+	/// assert_eq!(Guards::is_power_of_two(6usize, "Not a power of two!"), Err(String::from("Not a power of two!")));
+	/// assert_eq!(Guards::is_power_of_two(1u128 << 127, "?!"), Ok(1 << 127));
+	/// ```
+	///
+	/// Floats and signed integers are not supported:
+	/// ```compile_fail
+	/// use fluent_guards::Guards;
+	///
+	/// let _ = Guards::is_power_of_two(4.0, "?!");
+	/// ```
+	pub fn is_power_of_two<T: Unsigned, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.is_power_of_two() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is aligned to `alignment`.
+	///
+	/// Returns [`Ok`] if `value` is a multiple of `alignment`, otherwise returns [`Err`] with the given
+	/// `error_message`. An `alignment` that is not a power of two (including zero) is invalid and returns
+	/// [`Err`] with `"invalid alignment: must be a power of two"` instead.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_aligned_to(8192usize, 4096, "?!"), Ok(8192));
+	/// assert_eq!(Guards::is_aligned_to(0usize, 4096, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_aligned_to(4095usize, 1, "?!"), Ok(4095));
+	/// assert_eq!(Guards::is_aligned_to(4100usize, 4096, "Not page aligned!"), Err(String::from("Not page aligned!")));
+	///
+	/// assert_eq!(Guards::is_aligned_to(24usize, 12, "?!"), Err(String::from("invalid alignment: must be a power of two")));
+	/// assert_eq!(Guards::is_aligned_to(24usize, 0, "?!"), Err(String::from("invalid alignment: must be a power of two")));
+	/// ```
+	pub fn is_aligned_to<T: Unsigned, E: Into<String>>(
+		value: T,
+		alignment: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !alignment.is_power_of_two() {
+			return Err(String::from("invalid alignment: must be a power of two"));
+		}
+
+		if value & (alignment - T::ONE) == T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value + rhs` does not overflow.
+	///
+	/// Returns [`Ok`] with the sum, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::checked_add(254u8, 1, "?!"), Ok(255));
+	/// assert_eq!(Guards::checked_add(255u8, 1, "Overflow!"), Err(String::from("Overflow!")));
+	/// assert_eq!(Guards::checked_add(i32::MIN, -1, "Overflow!"), Err(String::from("Overflow!")));
+	/// ```
+	pub fn checked_add<T: Integer, E: Into<String>>(
+		value: T,
+		rhs: T,
+		error_message: E,
+	) -> Result<T, String> {
+		value.checked_add(rhs).ok_or_else(|| error_message.into())
+	}
+
+	/// Ensures that `value - rhs` does not overflow.
+	///
+	/// Returns [`Ok`] with the difference, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::checked_sub(5u32, 5, "?!"), Ok(0));
+	/// assert_eq!(Guards::checked_sub(4u32, 5, "Underflow!"), Err(String::from("Underflow!")));
+	/// assert_eq!(Guards::checked_sub(i64::MAX, -1, "Overflow!"), Err(String::from("Overflow!")));
+	/// ```
+	pub fn checked_sub<T: Integer, E: Into<String>>(
+		value: T,
+		rhs: T,
+		error_message: E,
+	) -> Result<T, String> {
+		value.checked_sub(rhs).ok_or_else(|| error_message.into())
+	}
+
+	/// Ensures that `value * rhs` does not overflow.
+	///
+	/// Returns [`Ok`] with the product, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::checked_mul(16u32, 4096, "?!"), Ok(65536));
+	/// assert_eq!(Guards::checked_mul(16u16, 4096, "Overflow!"), Err(String::from("Overflow!")));
+	/// assert_eq!(Guards::checked_mul(i8::MIN, -1, "Overflow!"), Err(String::from("Overflow!")));
+	/// ```
+	pub fn checked_mul<T: Integer, E: Into<String>>(
+		value: T,
+		rhs: T,
+		error_message: E,
+	) -> Result<T, String> {
+		value.checked_mul(rhs).ok_or_else(|| error_message.into())
+	}
+
+	/// Ensures that `value` is not zero.
+	///
+	/// Returns [`Ok`] if the value is not zero, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_non_zero(3u32, "?!"), Ok(3));
+	/// assert_eq!(Guards::is_non_zero(-3, "?!"), Ok(-3));
+	/// assert_eq!(Guards::is_non_zero(0u32, "Count must not be zero!"), Err(String::from("Count must not be zero!")));
+	/// ```
+	pub fn is_non_zero<T: Integer, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value != T::ZERO {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is not zero and converts it into the matching non-zero type.
+	///
+	/// Returns [`Ok`] with the [`NonZero`] value, otherwise returns [`Err`] with the given `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use std::num::{NonZeroI8, NonZeroU32, NonZeroU128, NonZeroUsize};
+	///
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::into_non_zero(3u32, "?!"), Ok(NonZeroU32::new(3).unwrap()));
+	/// assert_eq!(Guards::into_non_zero(-3i8, "?!"), Ok(NonZeroI8::new(-3).unwrap()));
+	/// assert_eq!(Guards::into_non_zero(u128::MAX, "?!"), Ok(NonZeroU128::MAX));
+	/// assert_eq!(Guards::into_non_zero(0usize, "Count must not be zero!"), Err::<NonZeroUsize, _>(String::from("Count must not be zero!")));
+	/// ```
+	pub fn into_non_zero<T: Integer, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T::NonZero, String> {
+		value.to_non_zero().ok_or_else(|| error_message.into())
+	}
+
+	/// Ensures that `value` has the same sign as `other`.
+	///
+	/// Returns [`Ok`] if both are positive or both are negative, otherwise returns [`Err`] with the given
+	/// `error_message`. Zero (including `-0.0`) has no sign, so it never has the same sign as anything,
+	/// and `NaN` always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_same_sign_as(3, 7, "?!"), Ok(3));
+	/// assert_eq!(Guards::has_same_sign_as(-3, -7, "?!"), Ok(-3));
+	/// assert_eq!(Guards::has_same_sign_as(-3, 7, "Signs differ!"), Err(String::from("Signs differ!")));
+	///
+	/// assert!(Guards::has_same_sign_as(0, 7, "Zero has no sign").is_err());
+	/// assert!(Guards::has_same_sign_as(0, 0, "Zero has no sign").is_err());
+	/// assert!(Guards::has_same_sign_as(-0.0, -1.0, "Zero has no sign").is_err());
+	/// assert!(Guards::has_same_sign_as(f64::NAN, 1.0, "NaN").is_err());
+	/// assert!(Guards::has_same_sign_as(1.0, f64::NAN, "NaN").is_err());
+	/// ```
+	pub fn has_same_sign_as<T: Signed, E: Into<String>>(
+		value: T,
+		other: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if (value > T::ZERO && other > T::ZERO) || (value < T::ZERO && other < T::ZERO) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has the opposite sign to `other`.
+	///
+	/// Returns [`Ok`] if one is positive and the other negative, otherwise returns [`Err`] with the given
+	/// `error_message`. Zero (including `-0.0`) has no sign, so it never has the opposite sign to anything,
+	/// and `NaN` always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_opposite_sign_to(-3, 7, "?!"), Ok(-3));
+	/// assert_eq!(Guards::has_opposite_sign_to(3.5, -0.5, "?!"), Ok(3.5));
+	/// assert_eq!(Guards::has_opposite_sign_to(3, 7, "Signs match!"), Err(String::from("Signs match!")));
+	///
+	/// assert!(Guards::has_opposite_sign_to(0, -7, "Zero has no sign").is_err());
+	/// assert!(Guards::has_opposite_sign_to(-0.0, 1.0, "Zero has no sign").is_err());
+	/// assert!(Guards::has_opposite_sign_to(f32::NAN, -1.0, "NaN").is_err());
+	/// ```
+	pub fn has_opposite_sign_to<T: Signed, E: Into<String>>(
+		value: T,
+		other: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if (value > T::ZERO && other < T::ZERO) || (value < T::ZERO && other > T::ZERO) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound` in a cyclic domain, like hours or degrees.
+	///
+	/// The domain runs from zero up to, but not including, `domain_max`, after which it wraps around to zero, so
+	/// hours use a `domain_max` of `24` and degrees `360`. If `lower_bound` is not greater than `upper_bound`, this
+	/// works like [`Guards::is_between`]. Otherwise the range wraps around: the accepted values run from
+	/// `lower_bound` up to `domain_max` and then from zero up to `upper_bound`, so `22` to `3` accepts the hours
+	/// `22, 23, 0, 1, 2, 3`. `bound_mode` applies to `lower_bound` and `upper_bound` only, so a wrapped range
+	/// that ends at zero only accepts zero with [`Bound::Inclusive`].
+	///
+	/// Returns [`Ok`] if the value is in the range, otherwise returns [`Err`] with the given `error_message`.
+	/// Values outside the domain always fail. If `lower_bound` or `upper_bound` is outside the domain, [`Err`]
+	/// with `"invalid bounds: outside the domain"` is returned instead.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// // Night shift from 22:00 to 03:00
+	/// let is_night = |hour: u8, bound_mode| Guards::is_between_wrapping(hour, 22, 3, 24, bound_mode, "Not a night hour!");
+	///
+	/// assert_eq!(is_night(23, Bound::Inclusive), Ok(23));
+	/// assert_eq!(is_night(0, Bound::Inclusive), Ok(0));
+	/// assert_eq!(is_night(22, Bound::Inclusive), Ok(22));
+	/// assert_eq!(is_night(3, Bound::Inclusive), Ok(3));
+	/// assert_eq!(is_night(12, Bound::Inclusive), Err(String::from("Not a night hour!")));
+	/// assert!(is_night(24, Bound::Inclusive).is_err());
+	///
+	/// assert_eq!(is_night(0, Bound::Exclusive), Ok(0));
+	/// assert!(is_night(22, Bound::Exclusive).is_err());
+	/// assert!(is_night(3, Bound::Exclusive).is_err());
+	///
+	/// // Late shift from 22:00 to midnight
+	/// assert_eq!(Guards::is_between_wrapping(0, 22, 0, 24, Bound::Inclusive, "?!"), Ok(0));
+	/// assert_eq!(Guards::is_between_wrapping(23, 22, 0, 24, Bound::Exclusive, "?!"), Ok(23));
+	/// assert!(Guards::is_between_wrapping(0, 22, 0, 24, Bound::Exclusive, "Not a late hour!").is_err());
+	/// assert!(Guards::is_between_wrapping(1, 22, 0, 24, Bound::Inclusive, "Not a late hour!").is_err());
+	///
+	/// // Heading between 350° and 10°
+	/// assert_eq!(Guards::is_between_wrapping(355.0, 350.0, 10.0, 360.0, Bound::Inclusive, "?!"), Ok(355.0));
+	/// assert_eq!(Guards::is_between_wrapping(5.0, 350.0, 10.0, 360.0, Bound::Inclusive, "?!"), Ok(5.0));
+	/// assert!(Guards::is_between_wrapping(180.0, 350.0, 10.0, 360.0, Bound::Inclusive, "Off course!").is_err());
+	/// assert!(Guards::is_between_wrapping(360.0, 350.0, 10.0, 360.0, Bound::Inclusive, "Off course!").is_err());
+	///
+	/// // Ranges that do not wrap
+	/// assert_eq!(Guards::is_between_wrapping(12, 9, 17, 24, Bound::Inclusive, "?!"), Ok(12));
+	/// assert!(Guards::is_between_wrapping(23, 9, 17, 24, Bound::Inclusive, "Not a work hour!").is_err());
+	///
+	/// assert_eq!(
+	/// 	Guards::is_between_wrapping(1, 22, 25, 24, Bound::Inclusive, "?!"),
+	/// 	Err(String::from("invalid bounds: outside the domain"))
+	/// );
+	/// ```
+	pub fn is_between_wrapping<T: Number, E: Into<String>>(
+		value: T,
+		lower_bound: T,
+		upper_bound: T,
+		domain_max: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		let in_domain = |value: T| value >= T::ZERO && value < domain_max;
+		if !in_domain(lower_bound) || !in_domain(upper_bound) {
+			return Err(String::from("invalid bounds: outside the domain"));
+		}
+
+		let above_lower = match bound_mode {
+			Bound::Inclusive => value >= lower_bound,
+			Bound::Exclusive => value > lower_bound,
+		};
+		let below_upper = match bound_mode {
+			Bound::Inclusive => value <= upper_bound,
+			Bound::Exclusive => value < upper_bound,
+		};
+		let in_range = if lower_bound <= upper_bound {
+			above_lower && below_upper
+		} else {
+			above_lower || below_upper
+		};
+
+		if in_domain(value) && in_range {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: Number> Guard<T> {
+	/// Ensures that `value` is greater than zero.
+	///
+	/// See [Guards::is_positive] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(5).is_positive("?!").result();
+	/// assert_eq!(pass, Ok(5));
+	///
+	/// let fail = Guard::new(0.0).is_positive("0.0 is not positive").result();
+	/// assert_eq!(fail, Err(String::from("0.0 is not positive")));
+	/// ```
+	pub fn is_positive<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_positive", |value| {
+			Guards::is_positive(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is less than zero.
+	///
+	/// See [Guards::is_negative] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(-5).is_negative("?!").result();
+	/// assert_eq!(pass, Ok(-5));
+	///
+	/// let fail = Guard::new(-0.0).is_negative("-0.0 is not negative").result();
+	/// assert_eq!(fail, Err(String::from("-0.0 is not negative")));
+	/// ```
+	pub fn is_negative<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_negative", |value| {
+			Guards::is_negative(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is zero.
+	///
+	/// See [Guards::is_zero] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(-0.0).is_zero("?!").result();
+	/// assert_eq!(pass, Ok(-0.0));
+	///
+	/// let fail = Guard::new(f32::NAN).is_zero("NaN is not zero").result();
+	/// assert_eq!(fail, Err(String::from("NaN is not zero")));
+	/// ```
+	pub fn is_zero<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_zero", |value| Guards::is_zero(*value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is greater than or equal to zero.
+	///
+	/// See [Guards::is_non_negative] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0).is_non_negative("?!").result();
+	/// assert_eq!(pass, Ok(0));
+	///
+	/// let fail = Guard::new(-1).is_non_negative("-1 < 0").result();
+	/// assert_eq!(fail, Err(String::from("-1 < 0")));
+	/// ```
+	pub fn is_non_negative<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_non_negative", |value| {
+			Guards::is_non_negative(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a percentage, between `0` and `100` (inclusive).
+	///
+	/// See [Guards::is_percentage] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(100).is_percentage("?!").result();
+	/// assert_eq!(pass, Ok(100));
+	///
+	/// let fail = Guard::new(100.5).is_percentage("Discount must be a percentage").result();
+	/// assert_eq!(fail, Err(String::from("Discount must be a percentage")));
+	/// ```
+	pub fn is_percentage<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_percentage", |value| {
+			Guards::is_percentage(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a percentage, between `0` and `100` (exclusive).
+	///
+	/// See [Guards::is_percentage_exclusive] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(50).is_percentage_exclusive("?!").result();
+	/// assert_eq!(pass, Ok(50));
+	///
+	/// let fail = Guard::new(0).is_percentage_exclusive("Split must leave something on both sides").result();
+	/// assert_eq!(fail, Err(String::from("Split must leave something on both sides")));
+	/// ```
+	pub fn is_percentage_exclusive<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_percentage_exclusive", |value| {
+			Guards::is_percentage_exclusive(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` differs from `other` by at least `delta`.
+	///
+	/// See [Guards::differs_by_at_least] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let current_price = 4.99;
+	///
+	/// let pass = Guard::new(5.49).differs_by_at_least(current_price, 0.01, "?!").result();
+	/// assert_eq!(pass, Ok(5.49));
+	///
+	/// let fail = Guard::new(4.99).differs_by_at_least(current_price, 0.01, "Price did not change").result();
+	/// assert_eq!(fail, Err(String::from("Price did not change")));
+	/// ```
+	pub fn differs_by_at_least<E: Into<String>>(
+		self,
+		other: T,
+		delta: T,
+		error_message: E,
+	) -> Self {
+		self.check("differs_by_at_least", |value| {
+			Guards::differs_by_at_least(*value, other, delta, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` differs from `other` by at most `delta`.
+	///
+	/// See [Guards::differs_by_at_most] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let current_price = 5.0;
+	///
+	/// let pass = Guard::new(5.5).differs_by_at_most(current_price, current_price * 0.2, "?!").result();
+	/// assert_eq!(pass, Ok(5.5));
+	///
+	/// let fail = Guard::new(7.5).differs_by_at_most(current_price, current_price * 0.2, "Price changed by more than 20%").result();
+	/// assert_eq!(fail, Err(String::from("Price changed by more than 20%")));
+	/// ```
+	pub fn differs_by_at_most<E: Into<String>>(
+		self,
+		other: T,
+		delta: T,
+		error_message: E,
+	) -> Self {
+		self.check("differs_by_at_most", |value| {
+			Guards::differs_by_at_most(*value, other, delta, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is within `tolerance` of at least one value in `candidates`.
+	///
+	/// See [Guards::is_close_to_any] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let nominal_volts = [1.0, 2.5, 5.0];
+	///
+	/// let pass = Guard::new(4.97).is_close_to_any(nominal_volts, 0.05, "?!").result();
+	/// assert_eq!(pass, Ok(4.97));
+	///
+	/// let fail = Guard::new(3.3).is_close_to_any(nominal_volts, 0.05, "Calibration is off").result();
+	/// assert_eq!(fail, Err(String::from("Calibration is off")));
+	/// ```
+	pub fn is_close_to_any<E: Into<String>>(
+		self,
+		candidates: impl IntoIterator<Item = T>,
+		tolerance: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_close_to_any", |value| {
+			Guards::is_close_to_any(*value, candidates, tolerance, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is between `lower_bound` and `upper_bound` in a cyclic domain, like hours or degrees.
+	///
+	/// See [Guards::is_between_wrapping] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let pass = Guard::new(2.5).is_between_wrapping(350.0, 10.0, 360.0, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(2.5));
+	///
+	/// let fail = Guard::new(90.0)
+	/// 	.is_between_wrapping(350.0, 10.0, 360.0, Bound::Inclusive, "Heading must be within 10° of north")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("Heading must be within 10° of north")));
+	/// ```
+	pub fn is_between_wrapping<E: Into<String>>(
+		self,
+		lower_bound: T,
+		upper_bound: T,
+		domain_max: T,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("is_between_wrapping", |value| {
+			Guards::is_between_wrapping(*value, lower_bound, upper_bound, domain_max, bound_mode, error_message)
+				.map(|_| ())
+		})
+	}
+}
+
+impl<T: Divisible> Guard<T> {
+	/// Ensures that `value` is an even number.
+	///
+	/// See [Guards::is_even] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(-2).is_even("?!").result();
+	/// assert_eq!(pass, Ok(-2));
+	///
+	/// let fail = Guard::new(7u8).is_even("7 is odd").result();
+	/// assert_eq!(fail, Err(String::from("7 is odd")));
+	/// ```
+	pub fn is_even<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_even", |value| Guards::is_even(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is an odd number.
+	///
+	/// See [Guards::is_odd] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(-3).is_odd("?!").result();
+	/// assert_eq!(pass, Ok(-3));
+	///
+	/// let fail = Guard::new(8u8).is_odd("8 is even").result();
+	/// assert_eq!(fail, Err(String::from("8 is even")));
+	/// ```
+	pub fn is_odd<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_odd", |value| Guards::is_odd(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is a multiple of `divisor`.
+	///
+	/// See [Guards::is_multiple_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(4096u32).is_multiple_of(512, "?!").result();
+	/// assert_eq!(pass, Ok(4096));
+	///
+	/// let fail = Guard::new(4000u32).is_multiple_of(512, "Block size must be a multiple of 512").result();
+	/// assert_eq!(fail, Err(String::from("Block size must be a multiple of 512")));
+	///
+	/// let fail = Guard::new(4096u32).is_multiple_of(0, "?!").result();
+	/// assert_eq!(fail, Err(String::from("invalid divisor: 0")));
+	/// ```
+	pub fn is_multiple_of<E: Into<String>>(
+		self,
+		divisor: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_multiple_of", |value| {
+			Guards::is_multiple_of(value, &divisor, error_message).map(|_| ())
+		})
+	}
+}
+
+impl<T: Integer> Guard<T> {
+	/// Ensures that `value` divides `dividend` evenly.
+	///
+	/// See [Guards::is_divisor_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let total_items = 120;
+	///
+	/// let pass = Guard::new(8).is_divisor_of(total_items, "?!").result();
+	/// assert_eq!(pass, Ok(8));
+	///
+	/// let fail = Guard::new(7).is_divisor_of(total_items, "Chunk count must divide the item count").result();
+	/// assert_eq!(fail, Err(String::from("Chunk count must divide the item count")));
+	/// ```
+	pub fn is_divisor_of<E: Into<String>>(
+		self,
+		dividend: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_divisor_of", |value| {
+			Guards::is_divisor_of(*value, dividend, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every bit of `mask` is set in `value`.
+	///
+	/// See [Guards::has_flags_set] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// const ADMIN: u32 = 1 << 4;
+	/// const AUDIT: u32 = 1 << 7;
+	///
+	/// let pass = Guard::new(ADMIN | AUDIT).has_flags_set(ADMIN, "?!").result();
+	/// assert_eq!(pass, Ok(ADMIN | AUDIT));
+	///
+	/// let fail = Guard::new(AUDIT).has_flags_set(ADMIN, "Admin permission required").result();
+	/// assert_eq!(fail, Err(String::from("Admin permission required")));
+	/// ```
+	pub fn has_flags_set<E: Into<String>>(
+		self,
+		mask: T,
+		error_message: E,
+	) -> Self {
+		self.check("has_flags_set", |value| {
+			Guards::has_flags_set(*value, mask, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every bit of `mask` is clear in `value`.
+	///
+	/// See [Guards::has_flags_clear] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// const GUEST: u32 = 1 << 0;
+	/// const ADMIN: u32 = 1 << 4;
+	///
+	/// let pass = Guard::new(GUEST).has_flags_clear(ADMIN, "?!").result();
+	/// assert_eq!(pass, Ok(GUEST));
+	///
+	/// let fail = Guard::new(GUEST | ADMIN).has_flags_clear(ADMIN, "Guests cannot be admins").result();
+	/// assert_eq!(fail, Err(String::from("Guests cannot be admins")));
+	/// ```
+	pub fn has_flags_clear<E: Into<String>>(
+		self,
+		mask: T,
+		error_message: E,
+	) -> Self {
+		self.check("has_flags_clear", |value| {
+			Guards::has_flags_clear(*value, mask, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that at least one bit of `mask` is set in `value`.
+	///
+	/// See [Guards::has_any_flag] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// const READ: u32 = 1 << 0;
+	/// const WRITE: u32 = 1 << 1;
+	///
+	/// let pass = Guard::new(WRITE).has_any_flag(READ | WRITE, "?!").result();
+	/// assert_eq!(pass, Ok(WRITE));
+	///
+	/// let fail = Guard::new(0).has_any_flag(READ | WRITE, "No access").result();
+	/// assert_eq!(fail, Err(String::from("No access")));
+	/// ```
+	pub fn has_any_flag<E: Into<String>>(
+		self,
+		mask: T,
+		error_message: E,
+	) -> Self {
+		self.check("has_any_flag", |value| {
+			Guards::has_any_flag(*value, mask, error_message).map(|_| ())
+		})
+	}
+
+	/// Replaces `value` with `value + rhs`, failing if the addition overflows.
+	///
+	/// Checks that follow see the sum. See [Guards::checked_add] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let capacity = 4096;
+	///
+	/// let pass = Guard::new(1024u32)
+	/// 	.checked_add(512, "offset + len overflowed")
+	/// 	.is_less_or_equal(capacity, "offset + len exceeds capacity")
+	/// 	.result();
+	/// assert_eq!(pass, Ok(1536));
+	///
+	/// let fail = Guard::new(u32::MAX)
+	/// 	.checked_add(512, "offset + len overflowed")
+	/// 	.is_less_or_equal(capacity, "offset + len exceeds capacity")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("offset + len overflowed")));
+	/// ```
+	pub fn checked_add<E: Into<String>>(
+		self,
+		rhs: T,
+		error_message: E,
+	) -> Self {
+		self.convert("checked_add", |value| Guards::checked_add(value, rhs, error_message))
+	}
+
+	/// Replaces `value` with `value - rhs`, failing if the subtraction overflows.
+	///
+	/// Checks that follow see the difference. See [Guards::checked_sub] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(10u8).checked_sub(4, "?!").is_equal_to(6, "?!").result();
+	/// assert_eq!(pass, Ok(6));
+	///
+	/// let fail = Guard::new(3u8).checked_sub(4, "3 - 4 underflowed").result();
+	/// assert_eq!(fail, Err(String::from("3 - 4 underflowed")));
+	/// ```
+	pub fn checked_sub<E: Into<String>>(
+		self,
+		rhs: T,
+		error_message: E,
+	) -> Self {
+		self.convert("checked_sub", |value| Guards::checked_sub(value, rhs, error_message))
+	}
+
+	/// Replaces `value` with `value * rhs`, failing if the multiplication overflows.
+	///
+	/// Checks that follow see the product. See [Guards::checked_mul] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(512u16).checked_mul(8, "?!").result();
+	/// assert_eq!(pass, Ok(4096));
+	///
+	/// let fail = Guard::new(512u16).checked_mul(512, "512 * 512 overflowed").result();
+	/// assert_eq!(fail, Err(String::from("512 * 512 overflowed")));
+	/// ```
+	pub fn checked_mul<E: Into<String>>(
+		self,
+		rhs: T,
+		error_message: E,
+	) -> Self {
+		self.convert("checked_mul", |value| Guards::checked_mul(value, rhs, error_message))
+	}
+
+	/// Ensures that `value` is not zero.
+	///
+	/// See [Guards::is_non_zero] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(8u16).is_non_zero("?!").result();
+	/// assert_eq!(pass, Ok(8));
+	///
+	/// let fail = Guard::new(0u16).is_non_zero("Count must not be zero").result();
+	/// assert_eq!(fail, Err(String::from("Count must not be zero")));
+	/// ```
+	pub fn is_non_zero<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_non_zero", |value| {
+			Guards::is_non_zero(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is not zero and continues the chain with the matching non-zero type.
+	///
+	/// See [Guards::into_non_zero] for more details.
+	/// ## Example
+	/// ```
+	/// use std::num::NonZeroU32;
+	///
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(4u32).is_less_than(16, "?!").into_non_zero("Count must not be zero").result();
+	/// assert_eq!(pass, Ok(NonZeroU32::new(4).unwrap()));
+	///
+	/// let fail = Guard::new(0u32).into_non_zero("Count must not be zero").result();
+	/// assert_eq!(fail, Err(String::from("Count must not be zero")));
+	/// ```
+	pub fn into_non_zero<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Guard<T::NonZero> {
+		self.convert("into_non_zero", |value| Guards::into_non_zero(value, error_message))
+	}
+}
+
+impl<T: Unsigned> Guard<T> {
+	/// Ensures that `value` is a power of two.
+	///
+	/// See [Guards::is_power_of_two] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(64usize).is_power_of_two("?!").result();
+	/// assert_eq!(pass, Ok(64));
+	///
+	/// let fail = Guard::new(0usize).is_power_of_two("Capacity must be a power of two").result();
+	/// assert_eq!(fail, Err(String::from("Capacity must be a power of two")));
+	/// ```
+	pub fn is_power_of_two<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_power_of_two", |value| {
+			Guards::is_power_of_two(*value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is aligned to `alignment`.
+	///
+	/// See [Guards::is_aligned_to] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(0x7f00_0000_1000usize).is_aligned_to(4096, "?!").result();
+	/// assert_eq!(pass, Ok(0x7f00_0000_1000));
+	///
+	/// let fail = Guard::new(0x7f00_0000_1004usize).is_aligned_to(4096, "mmap address must be page aligned").result();
+	/// assert_eq!(fail, Err(String::from("mmap address must be page aligned")));
+	/// ```
+	pub fn is_aligned_to<E: Into<String>>(
+		self,
+		alignment: T,
+		error_message: E,
+	) -> Self {
+		self.check("is_aligned_to", |value| {
+			Guards::is_aligned_to(*value, alignment, error_message).map(|_| ())
+		})
+	}
+}
+
+impl<T: Signed> Guard<T> {
+	/// Ensures that `value` has the same sign as `other`.
+	///
+	/// See [Guards::has_same_sign_as] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let trend = -0.4;
+	///
+	/// let pass = Guard::new(-0.1).has_same_sign_as(trend, "?!").result();
+	/// assert_eq!(pass, Ok(-0.1));
+	///
+	/// let fail = Guard::new(0.2).has_same_sign_as(trend, "Adjustment goes against the trend").result();
+	/// assert_eq!(fail, Err(String::from("Adjustment goes against the trend")));
+	/// ```
+	pub fn has_same_sign_as<E: Into<String>>(
+		self,
+		other: T,
+		error_message: E,
+	) -> Self {
+		self.check("has_same_sign_as", |value| {
+			Guards::has_same_sign_as(*value, other, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has the opposite sign to `other`.
+	///
+	/// See [Guards::has_opposite_sign_to] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(-5).has_opposite_sign_to(5, "?!").result();
+	/// assert_eq!(pass, Ok(-5));
+	///
+	/// let fail = Guard::new(0).has_opposite_sign_to(5, "Zero has no sign").result();
+	/// assert_eq!(fail, Err(String::from("Zero has no sign")));
+	/// ```
+	pub fn has_opposite_sign_to<E: Into<String>>(
+		self,
+		other: T,
+		error_message: E,
+	) -> Self {
+		self.check("has_opposite_sign_to", |value| {
+			Guards::has_opposite_sign_to(*value, other, error_message).map(|_| ())
+		})
+	}
+}
+
+impl<T: DecimalPlaces> Guard<T> {
+	/// Ensures that `value` has at most `decimal_places` digits after the decimal point.
+	///
+	/// See [Guards::has_max_decimal_places] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(19.99).has_max_decimal_places(2, "?!").result();
+	/// assert_eq!(pass, Ok(19.99));
+	///
+	/// let fail = Guard::new(19.999).has_max_decimal_places(2, "Amount must have at most 2 decimals").result();
+	/// assert_eq!(fail, Err(String::from("Amount must have at most 2 decimals")));
+	/// ```
+	pub fn has_max_decimal_places<E: Into<String>>(
+		self,
+		decimal_places: u32,
+		error_message: E,
+	) -> Self {
+		self.check("has_max_decimal_places", |value| {
+			Guards::has_max_decimal_places(*value, decimal_places, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,230 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+/// The formats [`Guards::is_numeric_string`] accepts.
+///
+/// By default only plain digits without leading zeros are accepted, like `"0"` or `"1234"`. Every option
+/// allows more.
+///
+/// ## Example
+/// ```
+/// use fluent_guards::{Guards, NumericStringOptions};
+///
+/// let options = NumericStringOptions::new().allow_sign().allow_decimal_point().allow_thousands_separators();
+///
+/// assert!(Guards::is_numeric_string("-1,234.56", options, "?!").is_ok());
+/// assert!(Guards::is_numeric_string("007", options, "?!").is_err());
+/// assert!(Guards::is_numeric_string("007", options.allow_leading_zeros(), "?!").is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NumericStringOptions {
+	sign: bool,
+	decimal_point: bool,
+	thousands_separators: bool,
+	leading_zeros: bool,
+}
+
+impl NumericStringOptions {
+	/// Creates options that only accept plain digits without leading zeros.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Allows a single leading `+` or `-`.
+	pub fn allow_sign(self) -> Self {
+		NumericStringOptions { sign: true, ..self }
+	}
+
+	/// Allows a single `.` followed by at least one digit.
+	pub fn allow_decimal_point(self) -> Self {
+		NumericStringOptions {
+			decimal_point: true,
+			..self
+		}
+	}
+
+	/// Allows `,` between groups of three digits before the decimal point, like `1,234,567`.
+	///
+	/// Numbers without separators are still accepted, but a number must either be grouped everywhere or not
+	/// at all.
+	pub fn allow_thousands_separators(self) -> Self {
+		NumericStringOptions {
+			thousands_separators: true,
+			..self
+		}
+	}
+
+	/// Allows the integer part to start with `0` when it has more than one digit, like `007`.
+	pub fn allow_leading_zeros(self) -> Self {
+		NumericStringOptions {
+			leading_zeros: true,
+			..self
+		}
+	}
+
+	fn accepts(
+		&self,
+		value: &str,
+	) -> bool {
+		let value = match value.strip_prefix(['+', '-']) {
+			Some(unsigned) if self.sign => unsigned,
+			Some(_) => return false,
+			None => value,
+		};
+
+		let (integer, fraction) = match value.split_once('.') {
+			Some((integer, fraction)) if self.decimal_point => (integer, Some(fraction)),
+			Some(_) => return false,
+			None => (value, None),
+		};
+
+		let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+		let integer_is_valid = if self.thousands_separators && integer.contains(',') {
+			let mut groups = integer.split(',');
+			let first = groups.next().unwrap_or_default();
+			is_digits(first) && first.len() <= 3 && groups.all(|group| is_digits(group) && group.len() == 3)
+		} else {
+			is_digits(integer)
+		};
+
+		integer_is_valid
+			&& (self.leading_zeros || !integer.starts_with('0') || integer.len() == 1)
+			&& fraction.is_none_or(is_digits)
+	}
+}
+
+impl Guards {
+	/// Ensures that `value` is a number written in one of the formats allowed by `options`.
+	///
+	/// Returns [`Ok`] if the string is made of ASCII digits and, where `options` allow them, a leading sign, a
+	/// decimal point and thousands separators, otherwise returns [`Err`] with the given `error_message`. There
+	/// must be at least one digit on both sides of the decimal point, and no whitespace is allowed. Only the
+	/// format is checked, so the number may still be too large for the type it is parsed into.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guards, NumericStringOptions};
+	///
+	/// let plain = NumericStringOptions::new();
+	/// assert_eq!(Guards::is_numeric_string("1234", plain, "?!"), Ok("1234"));
+	/// assert_eq!(Guards::is_numeric_string("0", plain, "?!"), Ok("0"));
+	/// assert_eq!(Guards::is_numeric_string("", plain, "Not a number!"), Err(String::from("Not a number!")));
+	/// assert!(Guards::is_numeric_string("007", plain, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("-1", plain, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("1.5", plain, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("1,234", plain, "Not a number!").is_err());
+	///
+	/// let signed = plain.allow_sign().allow_decimal_point();
+	/// assert_eq!(Guards::is_numeric_string("-0.5", signed, "?!"), Ok("-0.5"));
+	/// assert_eq!(Guards::is_numeric_string("+12", signed, "?!"), Ok("+12"));
+	/// assert!(Guards::is_numeric_string("-", signed, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("--1", signed, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string(".5", signed, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("5.", signed, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("1.2.3", signed, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string(" 1", signed, "Not a number!").is_err());
+	///
+	/// let grouped = signed.allow_thousands_separators();
+	/// assert_eq!(Guards::is_numeric_string("1,234.56", grouped, "?!"), Ok("1,234.56"));
+	/// assert_eq!(Guards::is_numeric_string("1234.56", grouped, "?!"), Ok("1234.56"));
+	/// assert!(Guards::is_numeric_string("12,34", grouped, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("1,2345", grouped, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string(",123", grouped, "Not a number!").is_err());
+	/// assert!(Guards::is_numeric_string("1.234,5", grouped, "Not a number!").is_err());
+	///
+	/// let zeros = plain.allow_leading_zeros();
+	/// assert_eq!(Guards::is_numeric_string("007", zeros, "?!"), Ok("007"));
+	/// ```
+	pub fn is_numeric_string<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		options: NumericStringOptions,
+		error_message: E,
+	) -> Result<T, String> {
+		if options.accepts(value.as_ref()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` contains only ASCII digits.
+	///
+	/// Returns [`Ok`] if the string is not empty and every character is `0` to `9`, otherwise returns [`Err`]
+	/// with the given `error_message`. Leading zeros are allowed, so this also fits codes like `"007"`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_digits_only("0123456789", "?!"), Ok("0123456789"));
+	/// assert_eq!(Guards::is_digits_only("007", "?!"), Ok("007"));
+	///
+	/// assert_eq!(Guards::is_digits_only("", "Digits only!"), Err(String::from("Digits only!")));
+	/// assert!(Guards::is_digits_only("-1", "Digits only!").is_err());
+	/// assert!(Guards::is_digits_only("1.0", "Digits only!").is_err());
+	/// assert!(Guards::is_digits_only("١٢٣", "Digits only!").is_err());
+	/// ```
+	pub fn is_digits_only<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let value_ref = value.as_ref();
+
+		if !value_ref.is_empty() && value_ref.bytes().all(|byte| byte.is_ascii_digit()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` is a number written in one of the formats allowed by `options`.
+	///
+	/// See [Guards::is_numeric_string] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guard, NumericStringOptions};
+	///
+	/// let options = NumericStringOptions::new().allow_decimal_point().allow_thousands_separators();
+	///
+	/// let pass = Guard::new("12,500.00").is_numeric_string(options, "?!").result();
+	/// assert_eq!(pass, Ok("12,500.00"));
+	///
+	/// let fail = Guard::new(String::from("12.500,00")).is_numeric_string(options, "Invalid amount").result();
+	/// assert_eq!(fail, Err(String::from("Invalid amount")));
+	/// ```
+	pub fn is_numeric_string<E: Into<String>>(
+		self,
+		options: NumericStringOptions,
+		error_message: E,
+	) -> Self {
+		self.check("is_numeric_string", |value| {
+			Guards::is_numeric_string(value, options, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` contains only ASCII digits.
+	///
+	/// See [Guards::is_digits_only] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("04012").is_digits_only("?!").result();
+	/// assert_eq!(pass, Ok("04012"));
+	///
+	/// let fail = Guard::new("4012a").is_digits_only("Postal codes are digits").result();
+	/// assert_eq!(fail, Err(String::from("Postal codes are digits")));
+	/// ```
+	pub fn is_digits_only<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_digits_only", |value| {
+			Guards::is_digits_only(value, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,225 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is [`Some`] and its inner value satisfies `predicate`.
+	///
+	/// Returns [`Ok`] with the unchanged `value` if it is [`Some`] and `predicate` returns `true`, otherwise
+	/// returns [`Err`] with the given `error_message`. [`None`] fails, like [`Option::is_some_and`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_https = |url: &&str| url.starts_with("https://");
+	///
+	/// assert_eq!(Guards::is_some_and(Some("https://example.com"), is_https, "?!"), Ok(Some("https://example.com")));
+	///
+	/// assert_eq!(Guards::is_some_and(Some("http://example.com"), is_https, "HTTPS required!"), Err(String::from("HTTPS required!")));
+	/// assert!(Guards::is_some_and(None, is_https, "HTTPS required!").is_err());
+	/// ```
+	pub fn is_some_and<T, F: FnOnce(&T) -> bool, E: Into<String>>(
+		value: Option<T>,
+		predicate: F,
+		error_message: E,
+	) -> Result<Option<T>, String> {
+		if value.as_ref().is_some_and(predicate) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is [`None`] or its inner value satisfies `predicate`.
+	///
+	/// Returns [`Ok`] with the unchanged `value` if it is [`None`] or `predicate` returns `true`, otherwise
+	/// returns [`Err`] with the given `error_message`. [`None`] passes, like [`Option::is_none_or`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let is_https = |url: &&str| url.starts_with("https://");
+	///
+	/// assert_eq!(Guards::is_none_or(Some("https://example.com"), is_https, "?!"), Ok(Some("https://example.com")));
+	/// assert_eq!(Guards::is_none_or(None, is_https, "?!"), Ok(None));
+	///
+	/// assert_eq!(Guards::is_none_or(Some("http://example.com"), is_https, "HTTPS required!"), Err(String::from("HTTPS required!")));
+	/// ```
+	pub fn is_none_or<T, F: FnOnce(&T) -> bool, E: Into<String>>(
+		value: Option<T>,
+		predicate: F,
+		error_message: E,
+	) -> Result<Option<T>, String> {
+		if value.as_ref().is_none_or(predicate) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T> Guard<Option<T>> {
+	/// Unwraps the guarded [`Option`] and runs the guard chain built by `then` on the inner value.
+	///
+	/// Fails with the given `none_message` if the value is [`None`], in which case the checks in `then` are
+	/// skipped. Otherwise `then` gets a [`Guard`] on the unwrapped value and its outcome becomes the outcome of
+	/// this guard, so [`Guard::result`] returns the inner value. Use [`Guard::if_some`] to let [`None`] pass.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// fn timeout(seconds: Option<u16>) -> Result<u16, String> {
+	/// 	Guard::new(seconds)
+	/// 		.some_and_then("Timeout is required", |timeout| {
+	/// 			timeout.is_between(1, 300, Bound::Inclusive, "Timeout must be between 1 and 300 seconds")
+	/// 		})
+	/// 		.result()
+	/// }
+	///
+	/// assert_eq!(timeout(Some(30)), Ok(30));
+	/// assert_eq!(timeout(Some(0)), Err(String::from("Timeout must be between 1 and 300 seconds")));
+	/// assert_eq!(timeout(Some(301)), Err(String::from("Timeout must be between 1 and 300 seconds")));
+	/// assert_eq!(timeout(None), Err(String::from("Timeout is required")));
+	/// ```
+	pub fn some_and_then<E: Into<String>, F: FnOnce(Guard<T>) -> Guard<T>>(
+		self,
+		none_message: E,
+		then: F,
+	) -> Guard<T> {
+		then(self.convert("some_and_then", |value| value.ok_or_else(|| none_message.into())))
+	}
+
+	/// Unwraps the guarded [`Option`], using `default` when it is [`None`].
+	///
+	/// Never fails, so the checks that follow run on either the inner value or `default`. Any pending error is
+	/// kept.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// fn retries(setting: Option<u8>) -> Result<u8, String> {
+	/// 	Guard::new(setting).unwrap_or(3).is_between(0, 10, Bound::Inclusive, "Retries must be at most 10").result()
+	/// }
+	///
+	/// assert_eq!(retries(Some(5)), Ok(5));
+	/// assert_eq!(retries(None), Ok(3));
+	/// assert_eq!(retries(Some(50)), Err(String::from("Retries must be at most 10")));
+	/// ```
+	pub fn unwrap_or(
+		self,
+		default: T,
+	) -> Guard<T> {
+		self.map_value(|value| value.unwrap_or(default))
+	}
+
+	/// Unwraps the guarded [`Option`], calling `default` when it is [`None`].
+	///
+	/// Never fails, so the checks that follow run on either the inner value or the result of `default`. `default`
+	/// is only called when the value is [`None`]. Any pending error is kept.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// fn cache_dir(setting: Option<String>) -> Result<String, String> {
+	/// 	Guard::new(setting)
+	/// 		.unwrap_or_else(|| String::from("/var/cache/app"))
+	/// 		.starts_with("/", "The cache directory must be an absolute path")
+	/// 		.result()
+	/// }
+	///
+	/// assert_eq!(cache_dir(Some(String::from("/tmp/app"))), Ok(String::from("/tmp/app")));
+	/// assert_eq!(cache_dir(None), Ok(String::from("/var/cache/app")));
+	/// assert_eq!(cache_dir(Some(String::from("cache"))), Err(String::from("The cache directory must be an absolute path")));
+	/// ```
+	pub fn unwrap_or_else<F: FnOnce() -> T>(
+		self,
+		default: F,
+	) -> Guard<T> {
+		self.map_value(|value| value.unwrap_or_else(default))
+	}
+
+	/// Runs the guard chain built by `then` on the inner value of the guarded [`Option`], if there is one.
+	///
+	/// [`None`] passes and skips the checks in `then`, use [`Guard::some_and_then`] to reject it instead. The
+	/// value stays wrapped, so [`Guard::result`] still returns the [`Option`].
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// fn nickname(nickname: Option<&str>) -> Result<Option<&str>, String> {
+	/// 	Guard::new(nickname)
+	/// 		.if_some(|nickname| nickname.has_bytes_between(2, 16, Bound::Inclusive, "Nicknames must be 2 to 16 bytes"))
+	/// 		.result()
+	/// }
+	///
+	/// assert_eq!(nickname(Some("ferris")), Ok(Some("ferris")));
+	/// assert_eq!(nickname(None), Ok(None));
+	/// assert_eq!(nickname(Some("f")), Err(String::from("Nicknames must be 2 to 16 bytes")));
+	///
+	/// // `some_and_then` requires the value instead
+	/// let required = Guard::new(None::<&str>).some_and_then("Nickname is required", |nickname| nickname).result();
+	/// assert_eq!(required, Err(String::from("Nickname is required")));
+	/// ```
+	pub fn if_some<F: FnOnce(Guard<T>) -> Guard<T>>(
+		self,
+		then: F,
+	) -> Self {
+		if let Some(None) = self.value() {
+			return self;
+		}
+
+		then(self.map_value(|value| value.expect("the value is not None"))).map_value(Some)
+	}
+
+	/// Ensures that `value` is [`Some`] and its inner value satisfies `predicate`.
+	///
+	/// See [Guards::is_some_and] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(Some(25u8)).is_some_and(|age| *age >= 18, "?!").result();
+	/// assert_eq!(pass, Ok(Some(25)));
+	///
+	/// let fail = Guard::new(Some(12u8)).is_some_and(|age| *age >= 18, "Adults only").result();
+	/// assert_eq!(fail, Err(String::from("Adults only")));
+	///
+	/// let fail = Guard::new(None::<u8>).is_some_and(|age| *age >= 18, "Adults only").result();
+	/// assert_eq!(fail, Err(String::from("Adults only")));
+	/// ```
+	pub fn is_some_and<F: FnOnce(&T) -> bool, E: Into<String>>(
+		self,
+		predicate: F,
+		error_message: E,
+	) -> Self {
+		self.check("is_some_and", |value| {
+			Guards::is_some_and(value.as_ref(), |inner| predicate(inner), error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is [`None`] or its inner value satisfies `predicate`.
+	///
+	/// See [Guards::is_none_or] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(None::<u8>).is_none_or(|age| *age >= 18, "?!").result();
+	/// assert_eq!(pass, Ok(None));
+	///
+	/// let fail = Guard::new(Some(12u8)).is_none_or(|age| *age >= 18, "Adults only").result();
+	/// assert_eq!(fail, Err(String::from("Adults only")));
+	/// ```
+	pub fn is_none_or<F: FnOnce(&T) -> bool, E: Into<String>>(
+		self,
+		predicate: F,
+		error_message: E,
+	) -> Self {
+		self.check("is_none_or", |value| {
+			Guards::is_none_or(value.as_ref(), |inner| predicate(inner), error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,199 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+/// The rules a password must follow to pass [`Guards::is_strong_password`].
+///
+/// A new policy has no rules, add them with the builder methods. Policies never see or keep a password,
+/// so they are safe to log.
+///
+/// ## Example
+/// ```
+/// use fluent_guards::{Guards, PasswordPolicy};
+///
+/// let policy = PasswordPolicy::new()
+/// 	.min_len(12)
+/// 	.require_upper()
+/// 	.require_lower()
+/// 	.require_digit()
+/// 	.require_symbol()
+/// 	.forbidden_substrings(["alice", "password"]);
+///
+/// assert!(Guards::is_strong_password("correct-Horse-42", &policy, "?!").is_ok());
+/// assert!(Guards::is_strong_password("Alice-is-great-42", &policy, "?!").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PasswordPolicy {
+	min_len: usize,
+	require_upper: bool,
+	require_lower: bool,
+	require_digit: bool,
+	require_symbol: bool,
+	forbidden_substrings: Vec<String>,
+}
+
+impl PasswordPolicy {
+	/// Creates a policy without any rules.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Requires at least `min_len` characters.
+	pub fn min_len(
+		self,
+		min_len: usize,
+	) -> Self {
+		PasswordPolicy { min_len, ..self }
+	}
+
+	/// Requires at least one uppercase letter.
+	pub fn require_upper(self) -> Self {
+		PasswordPolicy {
+			require_upper: true,
+			..self
+		}
+	}
+
+	/// Requires at least one lowercase letter.
+	pub fn require_lower(self) -> Self {
+		PasswordPolicy {
+			require_lower: true,
+			..self
+		}
+	}
+
+	/// Requires at least one digit.
+	pub fn require_digit(self) -> Self {
+		PasswordPolicy {
+			require_digit: true,
+			..self
+		}
+	}
+
+	/// Requires at least one symbol, which is any character that is neither alphanumeric nor whitespace.
+	pub fn require_symbol(self) -> Self {
+		PasswordPolicy {
+			require_symbol: true,
+			..self
+		}
+	}
+
+	/// Forbids the password from containing any of `substrings`, ignoring case.
+	///
+	/// Useful to reject passwords that contain the username or the name of the service. Empty strings are
+	/// ignored. Calling this again adds to the forbidden substrings.
+	pub fn forbidden_substrings<S: AsRef<str>>(
+		mut self,
+		substrings: impl IntoIterator<Item = S>,
+	) -> Self {
+		self.forbidden_substrings.extend(
+			substrings
+				.into_iter()
+				.map(|substring| substring.as_ref().to_lowercase())
+				.filter(|substring| !substring.is_empty()),
+		);
+		self
+	}
+
+	fn violations(
+		&self,
+		password: &str,
+	) -> Vec<&'static str> {
+		let mut violations = Vec::new();
+
+		if password.chars().count() < self.min_len {
+			violations.push("too short");
+		}
+		if self.require_upper && !password.chars().any(char::is_uppercase) {
+			violations.push("missing an uppercase letter");
+		}
+		if self.require_lower && !password.chars().any(char::is_lowercase) {
+			violations.push("missing a lowercase letter");
+		}
+		if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+			violations.push("missing a digit");
+		}
+		if self.require_symbol && !password.chars().any(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+			violations.push("missing a symbol");
+		}
+		if !self.forbidden_substrings.is_empty() {
+			let password = password.to_lowercase();
+			if self.forbidden_substrings.iter().any(|substring| password.contains(substring.as_str())) {
+				violations.push("contains a forbidden word");
+			}
+		}
+
+		violations
+	}
+}
+
+impl Guards {
+	/// Ensures that `value` follows every rule of `policy`.
+	///
+	/// Returns [`Ok`] if the password breaks none of the rules, otherwise returns [`Err`] with the given
+	/// `error_message` followed by the rules that were broken, for example
+	/// `"Weak password: too short, missing a digit"`. The error never contains the password or the forbidden
+	/// substrings.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guards, PasswordPolicy};
+	///
+	/// let policy = PasswordPolicy::new().min_len(8).require_upper().require_digit().require_symbol();
+	///
+	/// assert_eq!(Guards::is_strong_password("Tr0ub4dor&3", &policy, "?!"), Ok("Tr0ub4dor&3"));
+	/// assert_eq!(Guards::is_strong_password("anything", &PasswordPolicy::new(), "?!"), Ok("anything"));
+	///
+	/// assert_eq!(
+	/// 	Guards::is_strong_password("hunter2", &policy, "Weak password"),
+	/// 	Err(String::from("Weak password: too short, missing an uppercase letter, missing a symbol"))
+	/// );
+	///
+	/// let policy = policy.forbidden_substrings(["bob"]);
+	/// assert_eq!(
+	/// 	Guards::is_strong_password("Bob-the-Builder-1", &policy, "Weak password"),
+	/// 	Err(String::from("Weak password: contains a forbidden word"))
+	/// );
+	/// ```
+	pub fn is_strong_password<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		policy: &PasswordPolicy,
+		error_message: E,
+	) -> Result<T, String> {
+		let violations = policy.violations(value.as_ref());
+
+		if violations.is_empty() {
+			Ok(value)
+		} else {
+			Err(format!("{}: {}", error_message.into(), violations.join(", ")))
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` follows every rule of `policy`.
+	///
+	/// See [Guards::is_strong_password] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Guard, PasswordPolicy};
+	///
+	/// let policy = PasswordPolicy::new().min_len(12).require_digit().forbidden_substrings(["alice"]);
+	///
+	/// let pass = Guard::new("sunny-meadow-77").is_strong_password(&policy, "?!").result();
+	/// assert_eq!(pass, Ok("sunny-meadow-77"));
+	///
+	/// let fail = Guard::new(String::from("alice1")).is_strong_password(&policy, "Weak password").result();
+	/// assert_eq!(fail, Err(String::from("Weak password: too short, contains a forbidden word")));
+	/// ```
+	pub fn is_strong_password<E: Into<String>>(
+		self,
+		policy: &PasswordPolicy,
+		error_message: E,
+	) -> Self {
+		self.check("is_strong_password", |value| {
+			Guards::is_strong_password(value, policy, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,123 @@
+use regex::Regex;
+
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` matches the regular expression `pattern`.
+	///
+	/// Returns [`Ok`] if the pattern matches, otherwise returns [`Err`] with the given `error_message`.
+	/// Like [`Regex::is_match`], this finds a match anywhere in the string. To require the whole string to
+	/// match, anchor the pattern with `^` and `$`, and wrap alternations in a group, as in `^(?:a|b)$`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	/// use regex::Regex;
+	///
+	/// let sku = Regex::new(r"^[A-Z]{3}-\d{4}$").unwrap();
+	/// assert_eq!(Guards::matches_regex("ABC-1234", &sku, "?!"), Ok("ABC-1234"));
+	/// assert_eq!(Guards::matches_regex("ABC-12345", &sku, "Invalid SKU!"), Err(String::from("Invalid SKU!")));
+	///
+	/// // Unanchored patterns match any part of the string
+	/// let digits = Regex::new(r"\d{4}").unwrap();
+	/// assert_eq!(Guards::matches_regex("ABC-12345", &digits, "?!"), Ok("ABC-12345"));
+	///
+	/// // `^a|b$` is `(^a)|(b$)`, so it matches more than `a` and `b`
+	/// let unguarded = Regex::new(r"^a|b$").unwrap();
+	/// assert!(Guards::matches_regex("abc", &unguarded, "?!").is_ok());
+	/// let grouped = Regex::new(r"^(?:a|b)$").unwrap();
+	/// assert!(Guards::matches_regex("abc", &grouped, "Not a or b!").is_err());
+	/// ```
+	pub fn matches_regex<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		pattern: &Regex,
+		error_message: E,
+	) -> Result<T, String> {
+		if pattern.is_match(value.as_ref()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` matches the regular expression `pattern`, compiling it first.
+	///
+	/// Returns [`Ok`] if the pattern matches, otherwise returns [`Err`] with the given `error_message`.
+	/// Matching works like in [`Guards::matches_regex`]. An invalid `pattern` returns [`Err`] with
+	/// `"invalid pattern: "` followed by the pattern instead. The pattern is compiled on every call, so prefer
+	/// [`Guards::matches_regex`] with a compiled [`Regex`] for patterns that are used more than once.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::matches_pattern("2024-06-01", r"^\d{4}-\d{2}-\d{2}$", "?!"), Ok("2024-06-01"));
+	/// assert_eq!(Guards::matches_pattern("01.06.2024", r"^\d{4}-\d{2}-\d{2}$", "Not ISO!"), Err(String::from("Not ISO!")));
+	///
+	/// assert_eq!(Guards::matches_pattern("abc", "(unclosed", "?!"), Err(String::from("invalid pattern: (unclosed")));
+	/// ```
+	pub fn matches_pattern<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		pattern: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		match Regex::new(pattern) {
+			Ok(pattern) => Guards::matches_regex(value, &pattern, error_message),
+			Err(_) => Err(format!("invalid pattern: {pattern}")),
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` matches the regular expression `pattern`.
+	///
+	/// See [Guards::matches_regex] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	/// use regex::Regex;
+	///
+	/// let postcode = Regex::new(r"^\d{5}$").unwrap();
+	///
+	/// let pass = Guard::new("10115").matches_regex(&postcode, "?!").result();
+	/// assert_eq!(pass, Ok("10115"));
+	///
+	/// let fail = Guard::new(String::from("1011")).matches_regex(&postcode, "Postcode must have 5 digits").result();
+	/// assert_eq!(fail, Err(String::from("Postcode must have 5 digits")));
+	/// ```
+	pub fn matches_regex<E: Into<String>>(
+		self,
+		pattern: &Regex,
+		error_message: E,
+	) -> Self {
+		self.check("matches_regex", |value| {
+			Guards::matches_regex(value, pattern, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` matches the regular expression `pattern`, compiling it first.
+	///
+	/// See [Guards::matches_pattern] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("v1.2.3").matches_pattern(r"^v\d+\.\d+\.\d+$", "?!").result();
+	/// assert_eq!(pass, Ok("v1.2.3"));
+	///
+	/// let fail = Guard::new("1.2.3").matches_pattern(r"^v\d+\.\d+\.\d+$", "Tag must look like v1.2.3").result();
+	/// assert_eq!(fail, Err(String::from("Tag must look like v1.2.3")));
+	/// ```
+	pub fn matches_pattern<E: Into<String>>(
+		self,
+		pattern: &str,
+		error_message: E,
+	) -> Self {
+		self.check("matches_pattern", |value| {
+			Guards::matches_pattern(value, pattern, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,144 @@
+use crate::{
+	Guard,
+	Guards,
+};
+
+const MIN_DIGITS: usize = 8;
+const MAX_DIGITS: usize = 15;
+
+fn has_valid_digit_count(digits: impl Iterator<Item = char>) -> bool {
+	let mut count = 0;
+
+	for c in digits {
+		if !c.is_ascii_digit() {
+			return false;
+		}
+		count += 1;
+	}
+
+	(MIN_DIGITS..=MAX_DIGITS).contains(&count)
+}
+
+impl Guards {
+	/// Ensures that `value` is a phone number in E.164 format.
+	///
+	/// Returns [`Ok`] if the string is a `+` followed by 8 to 15 ASCII digits and nothing else, otherwise
+	/// returns [`Err`] with the given `error_message`. Only the structure is checked, country codes and number
+	/// plans are not, so use a telephony library when the number has to be reachable.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_e164_phone("+14155552671", "?!"), Ok("+14155552671"));
+	/// assert_eq!(Guards::is_e164_phone("+4930123456", "?!"), Ok("+4930123456"));
+	/// assert_eq!(Guards::is_e164_phone("+12345678", "?!"), Ok("+12345678"));
+	/// assert_eq!(Guards::is_e164_phone("+123456789012345", "?!"), Ok("+123456789012345"));
+	///
+	/// assert_eq!(Guards::is_e164_phone("14155552671", "Invalid phone number!"), Err(String::from("Invalid phone number!")));
+	/// assert!(Guards::is_e164_phone("+1234567", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_e164_phone("+1234567890123456", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_e164_phone("+1 415 555 2671", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_e164_phone("+1415CALLNOW", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_e164_phone("++14155552671", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_e164_phone("+", "Invalid phone number!").is_err());
+	/// ```
+	pub fn is_e164_phone<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let valid = match value.as_ref().strip_prefix('+') {
+			Some(digits) => has_valid_digit_count(digits.chars()),
+			None => false,
+		};
+
+		if valid {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` looks like a phone number.
+	///
+	/// Returns [`Ok`] if the string has 8 to 15 ASCII digits, optionally starting with a `+`, once spaces,
+	/// dashes and parentheses are ignored, otherwise returns [`Err`] with the given `error_message`. This
+	/// accepts the usual ways of writing a number, like `(415) 555-2671`, but does not check where the
+	/// separators are. The returned value is not changed.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_phone_like("+1 (415) 555-2671", "?!"), Ok("+1 (415) 555-2671"));
+	/// assert_eq!(Guards::is_phone_like("(415) 555-2671", "?!"), Ok("(415) 555-2671"));
+	/// assert_eq!(Guards::is_phone_like("+49 30 123456", "?!"), Ok("+49 30 123456"));
+	/// assert_eq!(Guards::is_phone_like("+14155552671", "?!"), Ok("+14155552671"));
+	///
+	/// assert_eq!(Guards::is_phone_like("555-2671", "Invalid phone number!"), Err(String::from("Invalid phone number!")));
+	/// assert!(Guards::is_phone_like("1-800-FLOWERS", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_phone_like("415.555.2671", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_phone_like("1 (415) +555 2671", "Invalid phone number!").is_err());
+	/// assert!(Guards::is_phone_like("", "Invalid phone number!").is_err());
+	/// ```
+	pub fn is_phone_like<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let value_ref = value.as_ref();
+		let number = value_ref.trim_start_matches([' ', '-', '(', ')']);
+		let number = number.strip_prefix('+').unwrap_or(number);
+
+		if has_valid_digit_count(number.chars().filter(|c| !matches!(c, ' ' | '-' | '(' | ')'))) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` is a phone number in E.164 format.
+	///
+	/// See [Guards::is_e164_phone] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("+442071838750").is_e164_phone("?!").result();
+	/// assert_eq!(pass, Ok("+442071838750"));
+	///
+	/// let fail = Guard::new(String::from("020 7183 8750")).is_e164_phone("Please use the international format").result();
+	/// assert_eq!(fail, Err(String::from("Please use the international format")));
+	/// ```
+	pub fn is_e164_phone<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_e164_phone", |value| {
+			Guards::is_e164_phone(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` looks like a phone number.
+	///
+	/// See [Guards::is_phone_like] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("+44 (20) 7183-8750").is_phone_like("?!").result();
+	/// assert_eq!(pass, Ok("+44 (20) 7183-8750"));
+	///
+	/// let fail = Guard::new("call me maybe").is_phone_like("Invalid phone number").result();
+	/// assert_eq!(fail, Err(String::from("Invalid phone number")));
+	/// ```
+	pub fn is_phone_like<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_phone_like", |value| {
+			Guards::is_phone_like(value, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,157 @@
+use crate::{
+	Guard,
+	Guards,
+	Unsigned,
+};
+
+/// Implemented for the primitive unsigned integer types of up to 64 bits.
+///
+/// [`u128`] is not supported, there is no known small set of Miller-Rabin bases that is exact for it.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait PrimeCandidate: Unsigned {
+	/// Converts the value into a [`u64`].
+	fn to_u64(self) -> u64;
+}
+
+macro_rules! impl_prime_candidate {
+	($($t:ty),* $(,)?) => {
+		$(
+			impl PrimeCandidate for $t {
+				fn to_u64(self) -> u64 {
+					self as u64
+				}
+			}
+		)*
+	};
+}
+
+impl_prime_candidate!(u8, u16, u32, u64, usize);
+
+/// These bases make Miller-Rabin exact for every `n < 3.3 * 10^24`, which covers all of [`u64`].
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+fn mul_mod(
+	a: u64,
+	b: u64,
+	modulus: u64,
+) -> u64 {
+	((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+fn pow_mod(
+	mut base: u64,
+	mut exponent: u64,
+	modulus: u64,
+) -> u64 {
+	let mut result = 1;
+	base %= modulus;
+	while exponent > 0 {
+		if exponent & 1 == 1 {
+			result = mul_mod(result, base, modulus);
+		}
+		base = mul_mod(base, base, modulus);
+		exponent >>= 1;
+	}
+	result
+}
+
+fn is_prime(n: u64) -> bool {
+	if n < 2 {
+		return false;
+	}
+
+	for witness in WITNESSES {
+		if n.is_multiple_of(witness) {
+			return n == witness;
+		}
+	}
+
+	let trailing_zeros = (n - 1).trailing_zeros();
+	let odd_part = (n - 1) >> trailing_zeros;
+
+	WITNESSES.iter().all(|&witness| {
+		let mut x = pow_mod(witness, odd_part, n);
+		if x == 1 || x == n - 1 {
+			return true;
+		}
+
+		for _ in 1..trailing_zeros {
+			x = mul_mod(x, x, n);
+			if x == n - 1 {
+				return true;
+			}
+		}
+		false
+	})
+}
+
+impl Guards {
+	/// Ensures that `value` is a prime number.
+	///
+	/// Returns [`Ok`] if the value is prime, otherwise returns [`Err`] with the given `error_message`.
+	/// Uses a deterministic Miller-Rabin test, so the result is exact. Requires the `math` feature.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_prime(2u32, "?!"), Ok(2));
+	/// assert_eq!(Guards::is_prime(0u32, "Not a prime!"), Err(String::from("Not a prime!")));
+	/// assert_eq!(Guards::is_prime(1u32, "Not a prime!"), Err(String::from("Not a prime!")));
+	///
+	/// let primes: [u64; 10] = [3, 5, 97, 251, 65_521, 2_147_483_647, 4_294_967_291, 1_000_000_007, 999_999_999_989, 18_446_744_073_709_551_557];
+	/// for prime in primes {
+	/// 	assert_eq!(Guards::is_prime(prime, "?!"), Ok(prime));
+	/// }
+	///
+	/// let composites: [u64; 8] = [4, 9, 91, 65_535, 4_294_967_297, 1_000_000_007 * 998_244_353, u64::MAX, 18_446_744_073_709_551_555];
+	/// for composite in composites {
+	/// 	assert!(Guards::is_prime(composite, "Not a prime!").is_err());
+	/// }
+	///
+	/// // Carmichael numbers fool the Fermat test
+	/// let carmichael: [u64; 8] = [561, 1105, 1729, 2465, 2821, 6601, 8911, 9_999_109_081];
+	/// for composite in carmichael {
+	/// 	assert!(Guards::is_prime(composite, "Not a prime!").is_err());
+	/// }
+	///
+	/// // Strong pseudoprimes to several small bases
+	/// let pseudoprimes: [u64; 3] = [2047, 3_215_031_751, 3_825_123_056_546_413_051];
+	/// for composite in pseudoprimes {
+	/// 	assert!(Guards::is_prime(composite, "Not a prime!").is_err());
+	/// }
+	/// ```
+	pub fn is_prime<T: PrimeCandidate, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if is_prime(value.to_u64()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: PrimeCandidate> Guard<T> {
+	/// Ensures that `value` is a prime number.
+	///
+	/// See [Guards::is_prime] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(65_537u32).is_prime("?!").result();
+	/// assert_eq!(pass, Ok(65_537));
+	///
+	/// let fail = Guard::new(1024usize).is_prime("Table size must be prime").result();
+	/// assert_eq!(fail, Err(String::from("Table size must be prime")));
+	/// ```
+	pub fn is_prime<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_prime", |value| Guards::is_prime(*value, error_message).map(|_| ()))
+	}
+}
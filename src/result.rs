@@ -0,0 +1,218 @@
+use std::fmt::Display;
+
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is [`Ok`].
+	///
+	/// Returns [`Ok`] with the unchanged `value` if it is [`Ok`], otherwise returns [`Err`] with the given
+	/// `error_message`. The error inside `value` is not part of the message, use [`Guards::is_ok_display`] to
+	/// include it.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let dry_run: Result<u32, &str> = Ok(12);
+	/// assert_eq!(Guards::is_ok(dry_run, "?!"), Ok(Ok(12)));
+	///
+	/// let dry_run: Result<u32, &str> = Err("disk full");
+	/// assert_eq!(Guards::is_ok(dry_run, "Dry run failed!"), Err(String::from("Dry run failed!")));
+	/// ```
+	pub fn is_ok<T, U, E: Into<String>>(
+		value: Result<T, U>,
+		error_message: E,
+	) -> Result<Result<T, U>, String> {
+		if value.is_ok() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is [`Ok`], naming the error inside `value` on failure.
+	///
+	/// Returns [`Ok`] with the unchanged `value` if it is [`Ok`], otherwise returns [`Err`] with the given
+	/// `error_message` followed by the error inside `value`, for example `"Dry run failed: disk full"`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let dry_run: Result<u32, &str> = Ok(12);
+	/// assert_eq!(Guards::is_ok_display(dry_run, "?!"), Ok(Ok(12)));
+	///
+	/// let dry_run: Result<u32, &str> = Err("disk full");
+	/// assert_eq!(Guards::is_ok_display(dry_run, "Dry run failed"), Err(String::from("Dry run failed: disk full")));
+	/// ```
+	pub fn is_ok_display<T, U: Display, E: Into<String>>(
+		value: Result<T, U>,
+		error_message: E,
+	) -> Result<Result<T, U>, String> {
+		match &value {
+			Ok(_) => Ok(value),
+			Err(error) => Err(format!("{}: {}", error_message.into(), error)),
+		}
+	}
+
+	/// Ensures that `value` is [`Err`].
+	///
+	/// Returns [`Ok`] with the unchanged `value` if it is [`Err`], otherwise returns [`Err`] with the given
+	/// `error_message`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let parsed = "-1".parse::<u32>();
+	/// assert!(Guards::is_err(parsed, "?!").is_ok());
+	///
+	/// let parsed = "1".parse::<u32>();
+	/// assert_eq!(Guards::is_err(parsed, "Expected a parse error!"), Err(String::from("Expected a parse error!")));
+	/// ```
+	pub fn is_err<T, U, E: Into<String>>(
+		value: Result<T, U>,
+		error_message: E,
+	) -> Result<Result<T, U>, String> {
+		if value.is_err() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T, U> Guard<Result<T, U>> {
+	/// Ensures that `value` is [`Ok`].
+	///
+	/// See [Guards::is_ok] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(Ok::<u32, String>(3)).is_ok("?!").result();
+	/// assert_eq!(pass, Ok(Ok(3)));
+	///
+	/// let fail = Guard::new(Err::<u32, String>(String::from("timeout"))).is_ok("Dry run must succeed").result();
+	/// assert_eq!(fail, Err(String::from("Dry run must succeed")));
+	/// ```
+	pub fn is_ok<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_ok", |value| {
+			Guards::is_ok(value.as_ref(), error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is [`Ok`], naming the error inside `value` on failure.
+	///
+	/// See [Guards::is_ok_display] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(Ok::<u32, String>(3)).is_ok_display("?!").result();
+	/// assert_eq!(pass, Ok(Ok(3)));
+	///
+	/// let fail = Guard::new(Err::<u32, String>(String::from("timeout"))).is_ok_display("Dry run must succeed").result();
+	/// assert_eq!(fail, Err(String::from("Dry run must succeed: timeout")));
+	/// ```
+	pub fn is_ok_display<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self
+	where
+		U: Display,
+	{
+		self.check("is_ok_display", |value| {
+			Guards::is_ok_display(value.as_ref(), error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is [`Err`].
+	///
+	/// See [Guards::is_err] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(Err::<u32, &str>("forbidden")).is_err("?!").result();
+	/// assert_eq!(pass, Ok(Err("forbidden")));
+	///
+	/// let fail = Guard::new(Ok::<u32, &str>(200)).is_err("Guest access must be rejected").result();
+	/// assert_eq!(fail, Err(String::from("Guest access must be rejected")));
+	/// ```
+	pub fn is_err<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_err", |value| {
+			Guards::is_err(value.as_ref(), error_message).map(|_| ())
+		})
+	}
+
+	/// Unwraps the guarded [`Result`] and runs the guard chain built by `then` on the [`Ok`] value.
+	///
+	/// Fails with the given `err_message` if the value is [`Err`], in which case the checks in `then` are skipped.
+	/// Otherwise `then` gets a [`Guard`] on the unwrapped value and its outcome becomes the outcome of this guard,
+	/// so [`Guard::result`] returns the [`Ok`] value. Use [`Guard::ok_and_then_with`] to build the message from
+	/// the error.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// fn port(input: &str) -> Result<u16, String> {
+	/// 	Guard::new(input.parse::<u16>())
+	/// 		.ok_and_then("Port must be a number", |port| {
+	/// 			port.is_between(1024, 65535, Bound::Inclusive, "Port must not be privileged")
+	/// 		})
+	/// 		.result()
+	/// }
+	///
+	/// assert_eq!(port("8080"), Ok(8080));
+	/// assert_eq!(port("80"), Err(String::from("Port must not be privileged")));
+	/// assert_eq!(port("http"), Err(String::from("Port must be a number")));
+	/// ```
+	pub fn ok_and_then<E: Into<String>, F: FnOnce(Guard<T>) -> Guard<T>>(
+		self,
+		err_message: E,
+		then: F,
+	) -> Guard<T> {
+		then(self.convert("ok_and_then", |value| value.map_err(|_| err_message.into())))
+	}
+
+	/// Unwraps the guarded [`Result`] and runs the guard chain built by `then` on the [`Ok`] value, building the
+	/// message for an [`Err`] with `err_message`.
+	///
+	/// See [Guard::ok_and_then] for more details. `err_message` is only called with the error when the value is
+	/// [`Err`].
+	/// ## Example
+	/// ```
+	/// use std::num::ParseIntError;
+	///
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// fn port(input: &str) -> Result<u16, String> {
+	/// 	Guard::new(input.parse::<u16>())
+	/// 		.ok_and_then_with(
+	/// 			|error: ParseIntError| format!("Invalid port {input:?}: {error}"),
+	/// 			|port| port.is_between(1024, 65535, Bound::Inclusive, "Port must not be privileged"),
+	/// 		)
+	/// 		.result()
+	/// }
+	///
+	/// assert_eq!(port("8080"), Ok(8080));
+	/// assert_eq!(port("70000"), Err(String::from("Invalid port \"70000\": number too large to fit in target type")));
+	/// ```
+	pub fn ok_and_then_with<M: FnOnce(U) -> String, F: FnOnce(Guard<T>) -> Guard<T>>(
+		self,
+		err_message: M,
+		then: F,
+	) -> Guard<T> {
+		then(self.convert("ok_and_then_with", |value| value.map_err(err_message)))
+	}
+}
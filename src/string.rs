@@ -0,0 +1,1954 @@
+use std::str::FromStr;
+
+use crate::{
+	Bound,
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` has at least `min` characters.
+	///
+	/// Returns [`Ok`] if the string has `min` or more characters, otherwise returns [`Err`] with the given
+	/// `error_message`. Characters are Unicode scalar values, see [`str::chars`], not bytes and not graphemes,
+	/// so `"日本語"` has three characters and an `e` followed by a combining accent has two.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_min_chars("Ferris", 3, "?!"), Ok("Ferris"));
+	/// assert_eq!(Guards::has_min_chars("日本語", 3, "?!"), Ok("日本語"));
+	/// assert_eq!(Guards::has_min_chars("e\u{301}", 2, "?!"), Ok("e\u{301}"));
+	///
+	/// assert_eq!(Guards::has_min_chars("Al", 3, "Too short!"), Err(String::from("Too short!")));
+	/// assert!(Guards::has_min_chars("日本", 3, "Too short!").is_err());
+	/// ```
+	pub fn has_min_chars<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		min: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().chars().count() >= min {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has at most `max` characters.
+	///
+	/// Returns [`Ok`] if the string has `max` or fewer characters, otherwise returns [`Err`] with the given
+	/// `error_message`. Characters are counted like in [`Guards::has_min_chars`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_max_chars("Ferris", 6, "?!"), Ok("Ferris"));
+	/// assert_eq!(Guards::has_max_chars("日本語", 3, "?!"), Ok("日本語"));
+	/// assert_eq!(Guards::has_max_chars("", 0, "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::has_max_chars("Ferris!", 6, "Too long!"), Err(String::from("Too long!")));
+	/// assert!(Guards::has_max_chars("e\u{301}", 1, "Too long!").is_err());
+	/// ```
+	pub fn has_max_chars<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		max: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().chars().count() <= max {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the number of characters in `value` is between `min` and `max`.
+	///
+	/// Returns [`Ok`] if the character count is between, otherwise returns [`Err`] with the given
+	/// `error_message`. Characters are counted like in [`Guards::has_min_chars`], and `bound_mode` works like
+	/// in [`Guards::is_between`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::has_chars_between("Ferris", 3, 20, Bound::Inclusive, "?!"), Ok("Ferris"));
+	/// assert_eq!(Guards::has_chars_between("日本語", 3, 20, Bound::Inclusive, "?!"), Ok("日本語"));
+	///
+	/// assert_eq!(
+	/// 	Guards::has_chars_between("日本語", 3, 20, Bound::Exclusive, "Invalid length!"),
+	/// 	Err(String::from("Invalid length!"))
+	/// );
+	/// assert!(Guards::has_chars_between("Al", 3, 20, Bound::Inclusive, "Invalid length!").is_err());
+	/// ```
+	pub fn has_chars_between<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		Guards::is_between(value.as_ref().chars().count(), min, max, bound_mode, error_message)?;
+		Ok(value)
+	}
+
+	/// Ensures that `value` is at least `min` bytes long.
+	///
+	/// Returns [`Ok`] if the UTF-8 encoding of the string has `min` or more bytes, otherwise returns [`Err`] with
+	/// the given `error_message`. This is independent of the character count, `"ü"` is one character but two bytes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_min_bytes("abcd", 4, "?!"), Ok("abcd"));
+	/// assert_eq!(Guards::has_min_bytes("üü", 4, "?!"), Ok("üü"));
+	///
+	/// assert_eq!(Guards::has_min_bytes("abc", 4, "Too short!"), Err(String::from("Too short!")));
+	/// ```
+	pub fn has_min_bytes<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		min: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().len() >= min {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is at most `max` bytes long.
+	///
+	/// Returns [`Ok`] if the UTF-8 encoding of the string has `max` or fewer bytes, otherwise returns [`Err`] with
+	/// the given `error_message`. This is independent of the character count, see [`Guards::has_max_chars`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let umlauts = "ü".repeat(6);
+	/// assert_eq!(Guards::has_max_chars(umlauts.as_str(), 10, "?!"), Ok(umlauts.as_str()));
+	/// assert_eq!(Guards::has_max_bytes(umlauts.as_str(), 10, "Too many bytes!"), Err(String::from("Too many bytes!")));
+	///
+	/// assert_eq!(Guards::has_max_bytes("uuuuuu", 10, "?!"), Ok("uuuuuu"));
+	/// assert_eq!(Guards::has_max_bytes("", 0, "?!"), Ok(""));
+	/// ```
+	pub fn has_max_bytes<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		max: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().len() <= max {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that the number of bytes in `value` is between `min` and `max`.
+	///
+	/// Returns [`Ok`] if the byte count of the UTF-8 encoding is between, otherwise returns [`Err`] with the given
+	/// `error_message`. `bound_mode` works like in [`Guards::is_between`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guards};
+	///
+	/// assert_eq!(Guards::has_bytes_between("üü", 1, 4, Bound::Inclusive, "?!"), Ok("üü"));
+	/// assert_eq!(Guards::has_chars_between("üüü", 1, 4, Bound::Inclusive, "?!"), Ok("üüü"));
+	///
+	/// assert_eq!(
+	/// 	Guards::has_bytes_between("üüü", 1, 4, Bound::Inclusive, "Invalid size!"),
+	/// 	Err(String::from("Invalid size!"))
+	/// );
+	/// assert!(Guards::has_bytes_between("üü", 1, 4, Bound::Exclusive, "Invalid size!").is_err());
+	/// ```
+	pub fn has_bytes_between<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Result<T, String> {
+		Guards::is_between(value.as_ref().len(), min, max, bound_mode, error_message)?;
+		Ok(value)
+	}
+
+	/// Ensures that `value` starts with `prefix`.
+	///
+	/// Returns [`Ok`] if the string starts with the prefix, otherwise returns [`Err`] with the given `error_message`.
+	/// The comparison is case-sensitive and every string starts with the empty prefix.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::starts_with("sk_live_4eC39H", "sk_live_", "?!"), Ok("sk_live_4eC39H"));
+	/// assert_eq!(Guards::starts_with(String::from("sk_live_4eC39H"), "sk_", "?!"), Ok(String::from("sk_live_4eC39H")));
+	/// assert_eq!(Guards::starts_with("", "", "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::starts_with("sk_test_4eC39H", "sk_live_", "Not a live key!"), Err(String::from("Not a live key!")));
+	/// assert!(Guards::starts_with("SK_LIVE_4eC39H", "sk_live_", "Not a live key!").is_err());
+	/// ```
+	pub fn starts_with<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		prefix: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().starts_with(prefix) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` ends with `suffix`.
+	///
+	/// Returns [`Ok`] if the string ends with the suffix, otherwise returns [`Err`] with the given `error_message`.
+	/// The comparison is case-sensitive and every string ends with the empty suffix.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::ends_with("report.pdf", ".pdf", "?!"), Ok("report.pdf"));
+	/// assert_eq!(Guards::ends_with("report.pdf", "", "?!"), Ok("report.pdf"));
+	///
+	/// assert_eq!(Guards::ends_with("report.docx", ".pdf", "Not a PDF!"), Err(String::from("Not a PDF!")));
+	/// assert!(Guards::ends_with("report.PDF", ".pdf", "Not a PDF!").is_err());
+	/// ```
+	pub fn ends_with<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		suffix: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().ends_with(suffix) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` does not start with `prefix`.
+	///
+	/// Returns [`Ok`] if the string does not start with the prefix, otherwise returns [`Err`] with the given
+	/// `error_message`. The comparison is case-sensitive and every string fails for the empty prefix.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::does_not_start_with("config", ".", "?!"), Ok("config"));
+	///
+	/// assert_eq!(Guards::does_not_start_with(".config", ".", "Hidden file!"), Err(String::from("Hidden file!")));
+	/// assert!(Guards::does_not_start_with("config", "", "Hidden file!").is_err());
+	/// ```
+	pub fn does_not_start_with<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		prefix: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().starts_with(prefix) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` does not end with `suffix`.
+	///
+	/// Returns [`Ok`] if the string does not end with the suffix, otherwise returns [`Err`] with the given
+	/// `error_message`. The comparison is case-sensitive and every string fails for the empty suffix.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::does_not_end_with("https://example.com", "/", "?!"), Ok("https://example.com"));
+	///
+	/// assert_eq!(
+	/// 	Guards::does_not_end_with("https://example.com/", "/", "Trailing slash!"),
+	/// 	Err(String::from("Trailing slash!"))
+	/// );
+	/// assert!(Guards::does_not_end_with("https://example.com", "", "Trailing slash!").is_err());
+	/// ```
+	pub fn does_not_end_with<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		suffix: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().ends_with(suffix) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` contains `needle`.
+	///
+	/// Returns [`Ok`] if the needle occurs somewhere in the string, otherwise returns [`Err`] with the given
+	/// `error_message`. This is a plain, case-sensitive substring search, and the empty needle is found in every
+	/// string. For case-insensitive checks lowercase both sides first.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::contains_str("src/lib.rs", "/", "?!"), Ok("src/lib.rs"));
+	/// assert_eq!(Guards::contains_str("lib.rs", "", "?!"), Ok("lib.rs"));
+	///
+	/// assert_eq!(Guards::contains_str("lib.rs", "/", "No slash!"), Err(String::from("No slash!")));
+	/// assert!(Guards::contains_str("Hello", "hello", "Case matters!").is_err());
+	/// ```
+	pub fn contains_str<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		needle: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().contains(needle) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` does not contain `needle`.
+	///
+	/// Returns [`Ok`] if the needle does not occur anywhere in the string, otherwise returns [`Err`] with the given
+	/// `error_message`. This is a plain, case-sensitive substring search, and the empty needle is found in every
+	/// string, so it always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::does_not_contain_str("Ship the parser", "TODO", "?!"), Ok("Ship the parser"));
+	/// assert_eq!(Guards::does_not_contain_str("todo: later", "TODO", "?!"), Ok("todo: later"));
+	///
+	/// assert_eq!(Guards::does_not_contain_str("TODO: fix", "TODO", "Unfinished!"), Err(String::from("Unfinished!")));
+	/// assert!(Guards::does_not_contain_str("Done", "", "Unfinished!").is_err());
+	/// ```
+	pub fn does_not_contain_str<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		needle: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().contains(needle) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every character of `value` is ASCII.
+	///
+	/// Returns [`Ok`] if the string only contains ASCII characters, otherwise returns [`Err`] with the given
+	/// `error_message`. The empty string passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_ascii("user_42", "?!"), Ok("user_42"));
+	/// assert_eq!(Guards::is_ascii("", "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::is_ascii("café", "Not ASCII!"), Err(String::from("Not ASCII!")));
+	/// ```
+	pub fn is_ascii<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().is_ascii() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every character of `value` is alphanumeric.
+	///
+	/// Returns [`Ok`] if every character is a letter or a digit as defined by [`char::is_alphanumeric`],
+	/// otherwise returns [`Err`] with the given `error_message`. Letters and digits of every script count, so
+	/// `"café"` passes, while spaces, underscores and punctuation fail. The empty string passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::chars_are_alphanumeric("user42", "?!"), Ok("user42"));
+	/// assert_eq!(Guards::chars_are_alphanumeric("café", "?!"), Ok("café"));
+	/// assert_eq!(Guards::chars_are_alphanumeric("", "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::chars_are_alphanumeric("user_42", "Not alphanumeric!"), Err(String::from("Not alphanumeric!")));
+	/// assert!(Guards::chars_are_alphanumeric("user 42", "Not alphanumeric!").is_err());
+	/// ```
+	pub fn chars_are_alphanumeric<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().chars().all(char::is_alphanumeric) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every character of `value` is alphabetic.
+	///
+	/// Returns [`Ok`] if every character is a letter as defined by [`char::is_alphabetic`], otherwise returns
+	/// [`Err`] with the given `error_message`. Letters of every script count, so `"café"` passes, while digits
+	/// fail. The empty string passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::chars_are_alphabetic("café", "?!"), Ok("café"));
+	/// assert_eq!(Guards::chars_are_alphabetic("Ölçü", "?!"), Ok("Ölçü"));
+	/// assert_eq!(Guards::chars_are_alphabetic("", "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::chars_are_alphabetic("abc1", "Not alphabetic!"), Err(String::from("Not alphabetic!")));
+	/// assert!(Guards::chars_are_alphabetic("a_b", "Not alphabetic!").is_err());
+	/// ```
+	pub fn chars_are_alphabetic<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().chars().all(char::is_alphabetic) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has no uppercase characters.
+	///
+	/// Returns [`Ok`] if no character is uppercase as defined by [`char::is_uppercase`], otherwise returns [`Err`]
+	/// with the given `error_message`. Characters without case, like digits, punctuation or CJK, are accepted, so
+	/// a string of only caseless characters is both lowercase and uppercase. The check does not allocate.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_lowercase("log_level", "?!"), Ok("log_level"));
+	/// assert_eq!(Guards::is_lowercase("straße", "?!"), Ok("straße"));
+	/// assert_eq!(Guards::is_lowercase("42-日本", "?!"), Ok("42-日本"));
+	///
+	/// assert_eq!(Guards::is_lowercase("logLevel", "Not lowercase!"), Err(String::from("Not lowercase!")));
+	/// assert!(Guards::is_lowercase("ÉTÉ", "Not lowercase!").is_err());
+	/// ```
+	pub fn is_lowercase<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().chars().any(char::is_uppercase) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has no lowercase characters.
+	///
+	/// Returns [`Ok`] if no character is lowercase as defined by [`char::is_lowercase`], otherwise returns [`Err`]
+	/// with the given `error_message`. Characters without case are accepted, like in [`Guards::is_lowercase`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_uppercase("DE", "?!"), Ok("DE"));
+	/// assert_eq!(Guards::is_uppercase("ÉTÉ", "?!"), Ok("ÉTÉ"));
+	/// assert_eq!(Guards::is_uppercase("42-日本", "?!"), Ok("42-日本"));
+	///
+	/// assert_eq!(Guards::is_uppercase("De", "Not uppercase!"), Err(String::from("Not uppercase!")));
+	/// ```
+	pub fn is_uppercase<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().chars().any(char::is_lowercase) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has no leading or trailing whitespace.
+	///
+	/// Returns [`Ok`] if [`str::trim`] would not change the string, otherwise returns [`Err`] with the given
+	/// `error_message`. Whitespace is defined by [`char::is_whitespace`], so tabs, newlines and Unicode spaces
+	/// like the no-break space count. The empty string passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_trimmed("Ferris the Crab", "?!"), Ok("Ferris the Crab"));
+	/// assert_eq!(Guards::is_trimmed("", "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::is_trimmed("Ferris ", "Not trimmed!"), Err(String::from("Not trimmed!")));
+	/// assert!(Guards::is_trimmed("\tFerris", "Not trimmed!").is_err());
+	/// assert!(Guards::is_trimmed("Ferris\n", "Not trimmed!").is_err());
+	/// assert!(Guards::is_trimmed("Ferris\u{a0}", "Not trimmed!").is_err());
+	/// ```
+	pub fn is_trimmed<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let value_str = value.as_ref();
+		if value_str.trim().len() == value_str.len() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has no whitespace between its first and last non-whitespace characters.
+	///
+	/// Returns [`Ok`] if the trimmed string has no whitespace, otherwise returns [`Err`] with the given
+	/// `error_message`. Leading and trailing whitespace is ignored, combine with [`Guards::is_trimmed`] to reject
+	/// it as well. Whitespace is defined like in [`Guards::is_trimmed`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_no_interior_whitespace("ferris", "?!"), Ok("ferris"));
+	/// assert_eq!(Guards::has_no_interior_whitespace(" ferris\n", "?!"), Ok(" ferris\n"));
+	///
+	/// assert_eq!(Guards::has_no_interior_whitespace("fer ris", "Has spaces!"), Err(String::from("Has spaces!")));
+	/// assert!(Guards::has_no_interior_whitespace("fer\u{a0}ris", "Has spaces!").is_err());
+	/// ```
+	pub fn has_no_interior_whitespace<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().trim().contains(char::is_whitespace) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has no control characters.
+	///
+	/// Returns [`Ok`] if no character is a control character as defined by [`char::is_control`], otherwise
+	/// returns [`Err`] with the given `error_message`. This rejects the C0 controls including `\0`, `\t`, `\r`
+	/// and `\n`, as well as DEL and the C1 controls.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_no_control_chars("GET /index.html", "?!"), Ok("GET /index.html"));
+	/// assert_eq!(Guards::has_no_control_chars("", "?!"), Ok(""));
+	///
+	/// let injected = "admin\r\nSet-Cookie: session=1";
+	/// assert_eq!(Guards::has_no_control_chars(injected, "Control characters!"), Err(String::from("Control characters!")));
+	/// assert!(Guards::has_no_control_chars("a\0b", "Control characters!").is_err());
+	/// assert!(Guards::has_no_control_chars("a\u{7f}b", "Control characters!").is_err());
+	/// assert!(Guards::has_no_control_chars("a\u{85}b", "Control characters!").is_err());
+	/// ```
+	pub fn has_no_control_chars<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().contains(char::is_control) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has no whitespace at all.
+	///
+	/// Returns [`Ok`] if no character is whitespace as defined by [`char::is_whitespace`], otherwise returns
+	/// [`Err`] with the given `error_message`. Unlike [`Guards::has_no_control_chars`], regular spaces fail.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_no_whitespace("X-Request-Id", "?!"), Ok("X-Request-Id"));
+	/// assert_eq!(Guards::has_no_control_chars("X Request Id", "?!"), Ok("X Request Id"));
+	///
+	/// assert_eq!(Guards::has_no_whitespace("X Request Id", "Whitespace!"), Err(String::from("Whitespace!")));
+	/// assert!(Guards::has_no_whitespace("id\r\n", "Whitespace!").is_err());
+	/// ```
+	pub fn has_no_whitespace<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().contains(char::is_whitespace) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` can be parsed into `U`.
+	///
+	/// Returns [`Ok`] with the parsed value if [`FromStr`] succeeds, otherwise returns [`Err`] with the given
+	/// `error_message`. The parse error itself is discarded.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::parse_guarded::<u16>("8080", "?!"), Ok(8080));
+	/// assert_eq!(Guards::parse_guarded::<f64>(String::from("2.5"), "?!"), Ok(2.5));
+	/// assert_eq!(Guards::parse_guarded::<bool>("true", "?!"), Ok(true));
+	///
+	/// assert_eq!(Guards::parse_guarded::<u16>("70000", "Not a port!"), Err(String::from("Not a port!")));
+	/// assert!(Guards::parse_guarded::<u16>(" 8080", "Not a port!").is_err());
+	/// ```
+	pub fn parse_guarded<U: FromStr>(
+		value: impl AsRef<str>,
+		error_message: impl Into<String>,
+	) -> Result<U, String> {
+		value.as_ref().parse().map_err(|_| error_message.into())
+	}
+
+	/// Ensures that `value` is a hex encoded byte string.
+	///
+	/// Returns [`Ok`] if the string is a non-empty, even number of hex digits, otherwise returns [`Err`] with the
+	/// given `error_message`. Upper and lower case digits are both accepted, prefixes like `0x` are not.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_hex("deadBEEF", "?!"), Ok("deadBEEF"));
+	/// assert_eq!(Guards::is_hex("00", "?!"), Ok("00"));
+	///
+	/// assert_eq!(Guards::is_hex("", "Not hex!"), Err(String::from("Not hex!")));
+	/// assert!(Guards::is_hex("abc", "Not hex!").is_err());
+	/// assert!(Guards::is_hex("0xab", "Not hex!").is_err());
+	/// assert!(Guards::is_hex("zz", "Not hex!").is_err());
+	/// ```
+	pub fn is_hex<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let value_str = value.as_ref();
+		if !value_str.is_empty() && value_str.len() % 2 == 0 && value_str.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a hex encoded byte string of exactly `len` hex digits.
+	///
+	/// Returns [`Ok`] if the string passes [`Guards::is_hex`] and has `len` digits, otherwise returns [`Err`]
+	/// with the given `error_message`. Note that `len` counts digits, not decoded bytes, so a SHA-256 hash has
+	/// a `len` of `64`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let sha256 = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+	/// assert_eq!(Guards::is_hex_of_len(sha256, 64, "?!"), Ok(sha256));
+	///
+	/// assert_eq!(Guards::is_hex_of_len(&sha256[..62], 64, "Not a SHA-256 hash!"), Err(String::from("Not a SHA-256 hash!")));
+	/// assert!(Guards::is_hex_of_len("abc", 3, "Not hex!").is_err());
+	/// ```
+	pub fn is_hex_of_len<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		len: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().len() != len {
+			return Err(error_message.into());
+		}
+
+		Guards::is_hex(value, error_message)
+	}
+
+	/// Ensures that `value` is a UUID in the canonical hyphenated format.
+	///
+	/// Returns [`Ok`] if the string is 32 hex digits grouped as `8-4-4-4-12`, otherwise returns [`Err`] with the
+	/// given `error_message`. Upper and lower case digits are both accepted. Other formats, like braces, a
+	/// `urn:uuid:` prefix or the 32 digits without hyphens, fail. The version and variant are not checked.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let v4 = "9b2e4c1a-7f3d-4e8b-a5c6-1d2e3f4a5b6c";
+	/// assert_eq!(Guards::is_uuid(v4, "?!"), Ok(v4));
+	/// assert_eq!(Guards::is_uuid("9B2E4C1A-7F3D-4E8B-A5C6-1D2E3F4A5B6C", "?!"), Ok("9B2E4C1A-7F3D-4E8B-A5C6-1D2E3F4A5B6C"));
+	/// assert_eq!(Guards::is_uuid("00000000-0000-0000-0000-000000000000", "?!"), Ok("00000000-0000-0000-0000-000000000000"));
+	///
+	/// assert_eq!(Guards::is_uuid(&v4[..35], "Invalid UUID!"), Err(String::from("Invalid UUID!")));
+	/// assert!(Guards::is_uuid("{9b2e4c1a-7f3d-4e8b-a5c6-1d2e3f4a5b6c}", "Invalid UUID!").is_err());
+	/// assert!(Guards::is_uuid("urn:uuid:9b2e4c1a-7f3d-4e8b-a5c6-1d2e3f4a5b6c", "Invalid UUID!").is_err());
+	/// assert!(Guards::is_uuid("9b2e4c1a7f3d4e8ba5c61d2e3f4a5b6c", "Invalid UUID!").is_err());
+	/// assert!(Guards::is_uuid("9b2e4c1a-7f3d-4e8b-a5c6-1d2e3f4a5b6g", "Invalid UUID!").is_err());
+	/// assert!(Guards::is_uuid("9b2e4c1a7-f3d-4e8b-a5c6-1d2e3f4a5b6c", "Invalid UUID!").is_err());
+	/// ```
+	pub fn is_uuid<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let bytes = value.as_ref().as_bytes();
+		let valid = bytes.len() == 36
+			&& bytes.iter().enumerate().all(|(index, byte)| match index {
+				8 | 13 | 18 | 23 => *byte == b'-',
+				_ => byte.is_ascii_hexdigit(),
+			});
+
+		if valid {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a valid [SemVer 2.0.0](https://semver.org) version.
+	///
+	/// Returns [`Ok`] if the string is `MAJOR.MINOR.PATCH`, optionally followed by `-` and a pre-release and by
+	/// `+` and build metadata, otherwise returns [`Err`] with the given `error_message`. As in the specification,
+	/// numeric identifiers must not have leading zeros, identifiers must not be empty and may only contain ASCII
+	/// letters, digits and `-`, and a `v` prefix is not part of the version.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_semver("1.0.0", "?!"), Ok("1.0.0"));
+	/// assert_eq!(Guards::is_semver("0.12.103", "?!"), Ok("0.12.103"));
+	/// assert_eq!(Guards::is_semver("1.0.0-alpha.1", "?!"), Ok("1.0.0-alpha.1"));
+	/// assert_eq!(Guards::is_semver("1.0.0-x-y-z.--", "?!"), Ok("1.0.0-x-y-z.--"));
+	/// assert_eq!(Guards::is_semver("1.0.0-0A.is.legal", "?!"), Ok("1.0.0-0A.is.legal"));
+	/// assert_eq!(Guards::is_semver("1.0.0-rc.1+build.001", "?!"), Ok("1.0.0-rc.1+build.001"));
+	/// assert_eq!(Guards::is_semver("1.0.0+20130313144700", "?!"), Ok("1.0.0+20130313144700"));
+	///
+	/// assert_eq!(Guards::is_semver("1.0", "Invalid version!"), Err(String::from("Invalid version!")));
+	/// assert!(Guards::is_semver("01.0.0", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("1.0.0-alpha.01", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("1.0.0-alpha..1", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("1.0.0-", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("1.0.0+", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("1.0.0+build+1", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("1.0.0-alpha_1", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("v1.0.0", "Invalid version!").is_err());
+	/// assert!(Guards::is_semver("1.0.0.0", "Invalid version!").is_err());
+	/// ```
+	pub fn is_semver<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let (version, build) = match value.as_ref().split_once('+') {
+			Some((version, build)) => (version, Some(build)),
+			None => (value.as_ref(), None),
+		};
+		let (core, pre_release) = match version.split_once('-') {
+			Some((core, pre_release)) => (core, Some(pre_release)),
+			None => (version, None),
+		};
+
+		let mut core = core.split('.');
+		let core_valid = (0..3).all(|_| core.next().is_some_and(is_semver_number)) && core.next().is_none();
+		let pre_release_valid = pre_release.is_none_or(|pre_release| {
+			pre_release.split('.').all(|identifier| {
+				is_semver_identifier(identifier)
+					&& (is_semver_number(identifier) || !identifier.bytes().all(|byte| byte.is_ascii_digit()))
+			})
+		});
+		let build_valid = build.is_none_or(|build| build.split('.').all(is_semver_identifier));
+
+		if core_valid && pre_release_valid && build_valid {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that every character of `value` is allowed by `allowed`.
+	///
+	/// Returns [`Ok`] if `allowed` returns `true` for every character, otherwise returns [`Err`] with the given
+	/// `error_message`. The empty string passes.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let sku = |c: char| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-';
+	///
+	/// assert_eq!(Guards::contains_only("ABC-123", sku, "?!"), Ok("ABC-123"));
+	/// assert_eq!(Guards::contains_only("", sku, "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::contains_only("abc-123", sku, "Invalid SKU!"), Err(String::from("Invalid SKU!")));
+	/// assert!(Guards::contains_only("ÄBC-123", sku, "Invalid SKU!").is_err());
+	/// assert!(Guards::contains_only("ABC-１２３", sku, "Invalid SKU!").is_err());
+	/// ```
+	pub fn contains_only<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		allowed: impl Fn(char) -> bool,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().chars().all(allowed) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` contains none of the characters in `forbidden`.
+	///
+	/// Returns [`Ok`] if no character of `forbidden` occurs in the string, otherwise returns [`Err`] with the
+	/// given `error_message`. An empty `forbidden` forbids nothing.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::contains_none_of("report.pdf", "/\\:*?\"<>|", "?!"), Ok("report.pdf"));
+	/// assert_eq!(Guards::contains_none_of("report.pdf", "", "?!"), Ok("report.pdf"));
+	///
+	/// assert_eq!(
+	/// 	Guards::contains_none_of("reports/2024.pdf", "/\\:*?\"<>|", "Invalid file name!"),
+	/// 	Err(String::from("Invalid file name!"))
+	/// );
+	/// assert!(Guards::contains_none_of("naïve", "ïü", "Forbidden character!").is_err());
+	/// ```
+	pub fn contains_none_of<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		forbidden: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().contains(|c| forbidden.contains(c)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is a single line.
+	///
+	/// Returns [`Ok`] if the string contains neither `\n` nor `\r`, otherwise returns [`Err`] with the given
+	/// `error_message`. A trailing line break also fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_single_line("Release notes", "?!"), Ok("Release notes"));
+	/// assert_eq!(Guards::is_single_line("", "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::is_single_line("Release\nnotes", "Multiple lines!"), Err(String::from("Multiple lines!")));
+	/// assert!(Guards::is_single_line("Release notes\r\n", "Multiple lines!").is_err());
+	/// assert!(Guards::is_single_line("Release\rnotes", "Multiple lines!").is_err());
+	/// ```
+	pub fn is_single_line<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if !value.as_ref().contains(['\n', '\r']) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` has at most `max` lines.
+	///
+	/// Returns [`Ok`] if the string has `max` or fewer lines, otherwise returns [`Err`] with the given
+	/// `error_message`. Lines are counted like [`str::lines`] does: lines end with `\n` or `\r\n`, a final line
+	/// break does not start another line, and the empty string has no lines. A lone `\r` is not a line break.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::has_max_lines("one\ntwo", 2, "?!"), Ok("one\ntwo"));
+	/// assert_eq!(Guards::has_max_lines("one\r\ntwo\r\n", 2, "?!"), Ok("one\r\ntwo\r\n"));
+	/// assert_eq!(Guards::has_max_lines("one\rtwo\rthree", 1, "?!"), Ok("one\rtwo\rthree"));
+	/// assert_eq!(Guards::has_max_lines("", 0, "?!"), Ok(""));
+	///
+	/// assert_eq!(Guards::has_max_lines("one\ntwo\nthree", 2, "Too many lines!"), Err(String::from("Too many lines!")));
+	/// assert!(Guards::has_max_lines("one\n\n", 1, "Too many lines!").is_err());
+	/// ```
+	pub fn has_max_lines<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		max: usize,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().lines().count() <= max {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` matches the wildcard `pattern`.
+	///
+	/// Returns [`Ok`] if the whole string matches, otherwise returns [`Err`] with the given `error_message`.
+	/// In the pattern `*` matches any run of characters, including none and including `.` or `/`, `?` matches
+	/// exactly one character, and every other character matches itself, case-sensitively. There is no way to
+	/// escape `*` or `?`.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::matches_glob("db1.internal.example.com", "*.internal.example.com", "?!"), Ok("db1.internal.example.com"));
+	/// assert_eq!(Guards::matches_glob("db1", "db?", "?!"), Ok("db1"));
+	/// assert_eq!(Guards::matches_glob("anything", "*", "?!"), Ok("anything"));
+	/// assert_eq!(Guards::matches_glob("", "*", "?!"), Ok(""));
+	/// assert_eq!(Guards::matches_glob("a.b.c", "a**c", "?!"), Ok("a.b.c"));
+	/// assert_eq!(Guards::matches_glob("日本", "?本", "?!"), Ok("日本"));
+	///
+	/// assert_eq!(Guards::matches_glob("internal.example.com", "*.internal.example.com", "No match!"), Err(String::from("No match!")));
+	/// assert!(Guards::matches_glob("db1.internal.example.org", "*.internal.example.com", "No match!").is_err());
+	/// assert!(Guards::matches_glob("DB1", "db?", "No match!").is_err());
+	/// assert!(Guards::matches_glob("db12", "db?", "No match!").is_err());
+	/// assert!(Guards::matches_glob("db", "db?", "No match!").is_err());
+	/// ```
+	pub fn matches_glob<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		pattern: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if glob_matches(value.as_ref(), pattern, false) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` matches the wildcard `pattern`, ignoring ASCII case.
+	///
+	/// Returns [`Ok`] if the whole string matches, otherwise returns [`Err`] with the given `error_message`.
+	/// Works like [`Guards::matches_glob`], but compares characters with [`char::eq_ignore_ascii_case`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::matches_glob_ignore_case("DB1.Internal.Example.com", "*.internal.example.com", "?!"), Ok("DB1.Internal.Example.com"));
+	///
+	/// assert_eq!(Guards::matches_glob_ignore_case("db1.example.org", "*.example.com", "No match!"), Err(String::from("No match!")));
+	/// ```
+	pub fn matches_glob_ignore_case<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		pattern: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if glob_matches(value.as_ref(), pattern, true) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` matches at least one of the wildcard `patterns`.
+	///
+	/// Returns [`Ok`] if the whole string matches a pattern, otherwise returns [`Err`] with the given
+	/// `error_message`. Patterns work like in [`Guards::matches_glob`]. An empty list of patterns always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let allowed = ["*.internal.example.com", "localhost", "127.0.0.?"];
+	///
+	/// assert_eq!(Guards::matches_any_glob("localhost", &allowed, "?!"), Ok("localhost"));
+	/// assert_eq!(Guards::matches_any_glob("127.0.0.1", &allowed, "?!"), Ok("127.0.0.1"));
+	///
+	/// assert_eq!(Guards::matches_any_glob("example.com", &allowed, "Host not allowed!"), Err(String::from("Host not allowed!")));
+	/// assert!(Guards::matches_any_glob("localhost", &[], "Host not allowed!").is_err());
+	/// ```
+	pub fn matches_any_glob<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		patterns: &[&str],
+		error_message: E,
+	) -> Result<T, String> {
+		if patterns.iter().any(|pattern| glob_matches(value.as_ref(), pattern, false)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is an ASCII identifier.
+	///
+	/// Returns [`Ok`] if the string starts with an ASCII letter or `_`, followed by any number of ASCII letters,
+	/// digits and `_`, otherwise returns [`Err`] with the given `error_message`. Keywords are not rejected, and
+	/// the empty string fails. With the `unicode` feature, `Guards::is_valid_identifier_unicode` also accepts
+	/// non-ASCII identifiers.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_valid_identifier("foo", "?!"), Ok("foo"));
+	/// assert_eq!(Guards::is_valid_identifier("_foo", "?!"), Ok("_foo"));
+	/// assert_eq!(Guards::is_valid_identifier("_", "?!"), Ok("_"));
+	/// assert_eq!(Guards::is_valid_identifier("Foo_Bar2", "?!"), Ok("Foo_Bar2"));
+	///
+	/// assert_eq!(Guards::is_valid_identifier("foo-bar", "Invalid identifier!"), Err(String::from("Invalid identifier!")));
+	/// assert!(Guards::is_valid_identifier("1abc", "Invalid identifier!").is_err());
+	/// assert!(Guards::is_valid_identifier("", "Invalid identifier!").is_err());
+	/// assert!(Guards::is_valid_identifier("café", "Invalid identifier!").is_err());
+	/// ```
+	pub fn is_valid_identifier<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let valid = match value.as_ref().as_bytes() {
+			[first, rest @ ..] => {
+				(first.is_ascii_alphabetic() || *first == b'_')
+					&& rest.iter().all(|byte| byte.is_ascii_alphanumeric() || *byte == b'_')
+			},
+			[] => false,
+		};
+
+		if valid {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` equals `expected`, ignoring ASCII case.
+	///
+	/// Returns [`Ok`] if the strings are equal according to [`str::eq_ignore_ascii_case`], otherwise returns
+	/// [`Err`] with the given `error_message`. Only `A` to `Z` and `a` to `z` are folded, every other character
+	/// must match exactly, so for example `"İ"` and `"ı"` never equal `"i"` or `"I"`. The check does not allocate.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::equals_ignore_case("Json", "json", "?!"), Ok("Json"));
+	/// assert_eq!(Guards::equals_ignore_case("JSON", "json", "?!"), Ok("JSON"));
+	///
+	/// assert_eq!(Guards::equals_ignore_case("yaml", "json", "Not JSON!"), Err(String::from("Not JSON!")));
+	/// assert!(Guards::equals_ignore_case("İD", "id", "Not id!").is_err());
+	/// assert!(Guards::equals_ignore_case("ıd", "ID", "Not id!").is_err());
+	/// assert!(Guards::equals_ignore_case("ÉTÉ", "été", "Not été!").is_err());
+	/// ```
+	pub fn equals_ignore_case<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		expected: &str,
+		error_message: E,
+	) -> Result<T, String> {
+		if value.as_ref().eq_ignore_ascii_case(expected) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` equals one of `candidates`, ignoring ASCII case.
+	///
+	/// Returns [`Ok`] if the string equals a candidate like in [`Guards::equals_ignore_case`], otherwise returns
+	/// [`Err`] with the given `error_message`. An empty list of candidates always fails.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// let formats = ["json", "yaml", "toml"];
+	///
+	/// assert_eq!(Guards::is_one_of_ignore_case("YAML", &formats, "?!"), Ok("YAML"));
+	/// assert_eq!(Guards::is_one_of_ignore_case("Toml", &formats, "?!"), Ok("Toml"));
+	///
+	/// assert_eq!(Guards::is_one_of_ignore_case("xml", &formats, "Unknown format!"), Err(String::from("Unknown format!")));
+	/// assert!(Guards::is_one_of_ignore_case("json", &[], "Unknown format!").is_err());
+	/// ```
+	pub fn is_one_of_ignore_case<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		candidates: &[&str],
+		error_message: E,
+	) -> Result<T, String> {
+		if candidates.iter().any(|candidate| value.as_ref().eq_ignore_ascii_case(candidate)) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+/// Returns `true` if `value` is a SemVer numeric identifier, `0` or digits without a leading zero.
+fn is_semver_number(value: &str) -> bool {
+	match value.as_bytes() {
+		[b'0'] => true,
+		[first, rest @ ..] => (b'1'..=b'9').contains(first) && rest.iter().all(u8::is_ascii_digit),
+		[] => false,
+	}
+}
+
+/// Returns `true` if `value` is a non-empty SemVer identifier of ASCII letters, digits and `-`.
+fn is_semver_identifier(value: &str) -> bool {
+	!value.is_empty() && value.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+}
+
+/// Returns `true` if the whole of `value` matches `pattern`, where `*` matches any run and `?` any character.
+fn glob_matches(
+	value: &str,
+	pattern: &str,
+	ignore_case: bool,
+) -> bool {
+	let value: Vec<char> = value.chars().collect();
+	let pattern: Vec<char> = pattern.chars().collect();
+	let (mut value_index, mut pattern_index) = (0, 0);
+	// Position of the last `*` and the value index it currently matches up to
+	let mut backtrack = None;
+
+	while value_index < value.len() {
+		match pattern.get(pattern_index) {
+			Some('*') => {
+				backtrack = Some((pattern_index, value_index));
+				pattern_index += 1;
+			},
+			Some(&c)
+				if c == '?'
+					|| c == value[value_index]
+					|| (ignore_case && c.eq_ignore_ascii_case(&value[value_index])) =>
+			{
+				value_index += 1;
+				pattern_index += 1;
+			},
+			_ => match backtrack {
+				// Let the last `*` match one more character and try again
+				Some((star_index, star_value_index)) => {
+					backtrack = Some((star_index, star_value_index + 1));
+					pattern_index = star_index + 1;
+					value_index = star_value_index + 1;
+				},
+				None => return false,
+			},
+		}
+	}
+
+	pattern[pattern_index..].iter().all(|&c| c == '*')
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` has at least `min` characters.
+	///
+	/// See [Guards::has_min_chars] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("日本語").has_min_chars(3, "?!").result();
+	/// assert_eq!(pass, Ok("日本語"));
+	///
+	/// let fail = Guard::new(String::from("Al")).has_min_chars(3, "Username must have at least 3 characters").result();
+	/// assert_eq!(fail, Err(String::from("Username must have at least 3 characters")));
+	/// ```
+	pub fn has_min_chars<E: Into<String>>(
+		self,
+		min: usize,
+		error_message: E,
+	) -> Self {
+		self.check("has_min_chars", |value| {
+			Guards::has_min_chars(value, min, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has at most `max` characters.
+	///
+	/// See [Guards::has_max_chars] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("Ferris").has_max_chars(20, "?!").result();
+	/// assert_eq!(pass, Ok("Ferris"));
+	///
+	/// let fail = Guard::new("Ferris the Crab, Rust mascot").has_max_chars(20, "Username is too long").result();
+	/// assert_eq!(fail, Err(String::from("Username is too long")));
+	/// ```
+	pub fn has_max_chars<E: Into<String>>(
+		self,
+		max: usize,
+		error_message: E,
+	) -> Self {
+		self.check("has_max_chars", |value| {
+			Guards::has_max_chars(value, max, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the number of characters in `value` is between `min` and `max`.
+	///
+	/// See [Guards::has_chars_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let pass = Guard::new(String::from("日本語")).has_chars_between(3, 20, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(String::from("日本語")));
+	///
+	/// let fail = Guard::new("Al").has_chars_between(3, 20, Bound::Inclusive, "Username must have 3 to 20 characters").result();
+	/// assert_eq!(fail, Err(String::from("Username must have 3 to 20 characters")));
+	/// ```
+	pub fn has_chars_between<E: Into<String>>(
+		self,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("has_chars_between", |value| {
+			Guards::has_chars_between(value, min, max, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is at least `min` bytes long.
+	///
+	/// See [Guards::has_min_bytes] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("correct horse").has_min_bytes(8, "?!").result();
+	/// assert_eq!(pass, Ok("correct horse"));
+	///
+	/// let fail = Guard::new(String::from("hunter")).has_min_bytes(8, "Secret is too short").result();
+	/// assert_eq!(fail, Err(String::from("Secret is too short")));
+	/// ```
+	pub fn has_min_bytes<E: Into<String>>(
+		self,
+		min: usize,
+		error_message: E,
+	) -> Self {
+		self.check("has_min_bytes", |value| {
+			Guards::has_min_bytes(value, min, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is at most `max` bytes long.
+	///
+	/// See [Guards::has_max_bytes] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("Zürich").has_max_bytes(16, "?!").result();
+	/// assert_eq!(pass, Ok("Zürich"));
+	///
+	/// let fail = Guard::new("Llanfairpwllgwyngyll").has_max_bytes(16, "City does not fit the column").result();
+	/// assert_eq!(fail, Err(String::from("City does not fit the column")));
+	/// ```
+	pub fn has_max_bytes<E: Into<String>>(
+		self,
+		max: usize,
+		error_message: E,
+	) -> Self {
+		self.check("has_max_bytes", |value| {
+			Guards::has_max_bytes(value, max, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that the number of bytes in `value` is between `min` and `max`.
+	///
+	/// See [Guards::has_bytes_between] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// let pass = Guard::new(String::from("Zürich")).has_bytes_between(1, 255, Bound::Inclusive, "?!").result();
+	/// assert_eq!(pass, Ok(String::from("Zürich")));
+	///
+	/// let fail = Guard::new("").has_bytes_between(1, 255, Bound::Inclusive, "City must have 1 to 255 bytes").result();
+	/// assert_eq!(fail, Err(String::from("City must have 1 to 255 bytes")));
+	/// ```
+	pub fn has_bytes_between<E: Into<String>>(
+		self,
+		min: usize,
+		max: usize,
+		bound_mode: Bound,
+		error_message: E,
+	) -> Self {
+		self.check("has_bytes_between", |value| {
+			Guards::has_bytes_between(value, min, max, bound_mode, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` starts with `prefix`.
+	///
+	/// See [Guards::starts_with] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("sk_live_4eC39H")).starts_with("sk_live_", "?!").result();
+	/// assert_eq!(pass, Ok(String::from("sk_live_4eC39H")));
+	///
+	/// let fail = Guard::new("sk_test_4eC39H").starts_with("sk_live_", "A live API key is required").result();
+	/// assert_eq!(fail, Err(String::from("A live API key is required")));
+	/// ```
+	pub fn starts_with<E: Into<String>>(
+		self,
+		prefix: &str,
+		error_message: E,
+	) -> Self {
+		self.check("starts_with", |value| {
+			Guards::starts_with(value, prefix, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` ends with `suffix`.
+	///
+	/// See [Guards::ends_with] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("invoice.pdf").ends_with(".pdf", "?!").result();
+	/// assert_eq!(pass, Ok("invoice.pdf"));
+	///
+	/// let fail = Guard::new("invoice.exe").ends_with(".pdf", "Only PDF uploads are allowed").result();
+	/// assert_eq!(fail, Err(String::from("Only PDF uploads are allowed")));
+	/// ```
+	pub fn ends_with<E: Into<String>>(
+		self,
+		suffix: &str,
+		error_message: E,
+	) -> Self {
+		self.check("ends_with", |value| {
+			Guards::ends_with(value, suffix, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` does not start with `prefix`.
+	///
+	/// See [Guards::does_not_start_with] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("reports").does_not_start_with("/", "?!").result();
+	/// assert_eq!(pass, Ok("reports"));
+	///
+	/// let fail = Guard::new("/etc").does_not_start_with("/", "Path must be relative").result();
+	/// assert_eq!(fail, Err(String::from("Path must be relative")));
+	/// ```
+	pub fn does_not_start_with<E: Into<String>>(
+		self,
+		prefix: &str,
+		error_message: E,
+	) -> Self {
+		self.check("does_not_start_with", |value| {
+			Guards::does_not_start_with(value, prefix, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` does not end with `suffix`.
+	///
+	/// See [Guards::does_not_end_with] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("https://example.com/api").does_not_end_with("/", "?!").result();
+	/// assert_eq!(pass, Ok("https://example.com/api"));
+	///
+	/// let fail = Guard::new("https://example.com/api/").does_not_end_with("/", "Base URL must not end with a slash").result();
+	/// assert_eq!(fail, Err(String::from("Base URL must not end with a slash")));
+	/// ```
+	pub fn does_not_end_with<E: Into<String>>(
+		self,
+		suffix: &str,
+		error_message: E,
+	) -> Self {
+		self.check("does_not_end_with", |value| {
+			Guards::does_not_end_with(value, suffix, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` contains `needle`.
+	///
+	/// See [Guards::contains_str] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("uploads/avatar.png").contains_str("/", "?!").result();
+	/// assert_eq!(pass, Ok("uploads/avatar.png"));
+	///
+	/// let fail = Guard::new("avatar.png").contains_str("/", "Path must contain a directory").result();
+	/// assert_eq!(fail, Err(String::from("Path must contain a directory")));
+	/// ```
+	pub fn contains_str<E: Into<String>>(
+		self,
+		needle: &str,
+		error_message: E,
+	) -> Self {
+		self.check("contains_str", |value| {
+			Guards::contains_str(value, needle, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` does not contain `needle`.
+	///
+	/// See [Guards::does_not_contain_str] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("Adds retries")).does_not_contain_str("TODO", "?!").result();
+	/// assert_eq!(pass, Ok(String::from("Adds retries")));
+	///
+	/// let fail = Guard::new("Adds retries, TODO docs").does_not_contain_str("TODO", "Description must not contain TODO").result();
+	/// assert_eq!(fail, Err(String::from("Description must not contain TODO")));
+	/// ```
+	pub fn does_not_contain_str<E: Into<String>>(
+		self,
+		needle: &str,
+		error_message: E,
+	) -> Self {
+		self.check("does_not_contain_str", |value| {
+			Guards::does_not_contain_str(value, needle, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every character of `value` is ASCII.
+	///
+	/// See [Guards::is_ascii] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("report-2024.csv").is_ascii("?!").result();
+	/// assert_eq!(pass, Ok("report-2024.csv"));
+	///
+	/// let fail = Guard::new(String::from("résumé.pdf")).is_ascii("File name must be ASCII").result();
+	/// assert_eq!(fail, Err(String::from("File name must be ASCII")));
+	/// ```
+	pub fn is_ascii<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_ascii", |value| Guards::is_ascii(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that every character of `value` is alphanumeric.
+	///
+	/// See [Guards::chars_are_alphanumeric] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("Ferris42").chars_are_alphanumeric("?!").result();
+	/// assert_eq!(pass, Ok("Ferris42"));
+	///
+	/// let fail = Guard::new("ferris_42").chars_are_alphanumeric("Only letters and digits are allowed").result();
+	/// assert_eq!(fail, Err(String::from("Only letters and digits are allowed")));
+	/// ```
+	pub fn chars_are_alphanumeric<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("chars_are_alphanumeric", |value| {
+			Guards::chars_are_alphanumeric(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that every character of `value` is alphabetic.
+	///
+	/// See [Guards::chars_are_alphabetic] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("Zoë").chars_are_alphabetic("?!").result();
+	/// assert_eq!(pass, Ok("Zoë"));
+	///
+	/// let fail = Guard::new("Zoë2").chars_are_alphabetic("First name must only contain letters").result();
+	/// assert_eq!(fail, Err(String::from("First name must only contain letters")));
+	/// ```
+	pub fn chars_are_alphabetic<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("chars_are_alphabetic", |value| {
+			Guards::chars_are_alphabetic(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has no uppercase characters.
+	///
+	/// See [Guards::is_lowercase] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("max_connections").is_lowercase("?!").result();
+	/// assert_eq!(pass, Ok("max_connections"));
+	///
+	/// let fail = Guard::new("maxConnections").is_lowercase("Config keys must be lowercase").result();
+	/// assert_eq!(fail, Err(String::from("Config keys must be lowercase")));
+	/// ```
+	pub fn is_lowercase<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_lowercase", |value| {
+			Guards::is_lowercase(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has no lowercase characters.
+	///
+	/// See [Guards::is_uppercase] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("CH")).is_uppercase("?!").result();
+	/// assert_eq!(pass, Ok(String::from("CH")));
+	///
+	/// let fail = Guard::new("ch").is_uppercase("Country codes must be uppercase").result();
+	/// assert_eq!(fail, Err(String::from("Country codes must be uppercase")));
+	/// ```
+	pub fn is_uppercase<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_uppercase", |value| {
+			Guards::is_uppercase(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has no leading or trailing whitespace.
+	///
+	/// See [Guards::is_trimmed] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("ferris@example.com").is_trimmed("?!").result();
+	/// assert_eq!(pass, Ok("ferris@example.com"));
+	///
+	/// let fail = Guard::new(String::from("ferris@example.com ")).is_trimmed("Remove the trailing spaces").result();
+	/// assert_eq!(fail, Err(String::from("Remove the trailing spaces")));
+	/// ```
+	pub fn is_trimmed<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_trimmed", |value| {
+			Guards::is_trimmed(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has no whitespace between its first and last non-whitespace characters.
+	///
+	/// See [Guards::has_no_interior_whitespace] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("ferris").is_trimmed("?!").has_no_interior_whitespace("?!").result();
+	/// assert_eq!(pass, Ok("ferris"));
+	///
+	/// let fail = Guard::new("ferris crab").has_no_interior_whitespace("Usernames cannot contain spaces").result();
+	/// assert_eq!(fail, Err(String::from("Usernames cannot contain spaces")));
+	/// ```
+	pub fn has_no_interior_whitespace<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("has_no_interior_whitespace", |value| {
+			Guards::has_no_interior_whitespace(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has no control characters.
+	///
+	/// See [Guards::has_no_control_chars] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("login failed for ferris").has_no_control_chars("?!").result();
+	/// assert_eq!(pass, Ok("login failed for ferris"));
+	///
+	/// let fail = Guard::new("ferris\r\nlogin succeeded for admin").has_no_control_chars("Refusing to log control characters").result();
+	/// assert_eq!(fail, Err(String::from("Refusing to log control characters")));
+	/// ```
+	pub fn has_no_control_chars<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("has_no_control_chars", |value| {
+			Guards::has_no_control_chars(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has no whitespace at all.
+	///
+	/// See [Guards::has_no_whitespace] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("X-Request-Id")).has_no_whitespace("?!").result();
+	/// assert_eq!(pass, Ok(String::from("X-Request-Id")));
+	///
+	/// let fail = Guard::new("X Request Id").has_no_whitespace("Header names cannot contain whitespace").result();
+	/// assert_eq!(fail, Err(String::from("Header names cannot contain whitespace")));
+	/// ```
+	pub fn has_no_whitespace<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("has_no_whitespace", |value| {
+			Guards::has_no_whitespace(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` can be parsed into `U` and continues the chain with the parsed value.
+	///
+	/// See [Guards::parse_guarded] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::{Bound, Guard};
+	///
+	/// fn parse_port(input: &str) -> Result<u16, String> {
+	/// 	Guard::new(input)
+	/// 		.is_trimmed("Port must not contain spaces")
+	/// 		.parses_as::<u16>("Port must be a number between 0 and 65535")
+	/// 		.is_between(1024, 49151, Bound::Inclusive, "Port must be a registered port")
+	/// 		.result()
+	/// }
+	///
+	/// assert_eq!(parse_port("8080"), Ok(8080));
+	/// assert_eq!(parse_port("80"), Err(String::from("Port must be a registered port")));
+	/// assert_eq!(parse_port("http"), Err(String::from("Port must be a number between 0 and 65535")));
+	/// assert_eq!(parse_port("8080 "), Err(String::from("Port must not contain spaces")));
+	/// ```
+	pub fn parses_as<U: FromStr>(
+		self,
+		error_message: impl Into<String>,
+	) -> Guard<U> {
+		self.convert("parses_as", |value| Guards::parse_guarded(value, error_message))
+	}
+
+	/// Ensures that `value` is a hex encoded byte string.
+	///
+	/// See [Guards::is_hex] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("cafe01").is_hex("?!").result();
+	/// assert_eq!(pass, Ok("cafe01"));
+	///
+	/// let fail = Guard::new("cafe0").is_hex("Color must be hex encoded").result();
+	/// assert_eq!(fail, Err(String::from("Color must be hex encoded")));
+	/// ```
+	pub fn is_hex<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_hex", |value| Guards::is_hex(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is a hex encoded byte string of exactly `len` hex digits.
+	///
+	/// See [Guards::is_hex_of_len] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("d41d8cd98f00b204e9800998ecf8427e")).is_hex_of_len(32, "?!").result();
+	/// assert!(pass.is_ok());
+	///
+	/// let fail = Guard::new("d41d8cd98f00b204").is_hex_of_len(32, "Checksum must be an MD5 hash").result();
+	/// assert_eq!(fail, Err(String::from("Checksum must be an MD5 hash")));
+	/// ```
+	pub fn is_hex_of_len<E: Into<String>>(
+		self,
+		len: usize,
+		error_message: E,
+	) -> Self {
+		self.check("is_hex_of_len", |value| {
+			Guards::is_hex_of_len(value, len, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a UUID in the canonical hyphenated format.
+	///
+	/// See [Guards::is_uuid] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("67e55044-10b1-426f-9247-bb680e5fe0c8").is_uuid("?!").result();
+	/// assert_eq!(pass, Ok("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+	///
+	/// let fail = Guard::new("67e55044-10b1-426f-9247").is_uuid("Order id must be a UUID").result();
+	/// assert_eq!(fail, Err(String::from("Order id must be a UUID")));
+	/// ```
+	pub fn is_uuid<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_uuid", |value| Guards::is_uuid(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is a valid SemVer 2.0.0 version.
+	///
+	/// See [Guards::is_semver] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("2.1.0-beta.3").is_semver("?!").result();
+	/// assert_eq!(pass, Ok("2.1.0-beta.3"));
+	///
+	/// let fail = Guard::new(String::from("2.1")).is_semver("Plugin version must be a SemVer version").result();
+	/// assert_eq!(fail, Err(String::from("Plugin version must be a SemVer version")));
+	/// ```
+	pub fn is_semver<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_semver", |value| Guards::is_semver(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that every character of `value` is allowed by `allowed`.
+	///
+	/// See [Guards::contains_only] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("ABC-123").contains_only(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-', "?!").result();
+	/// assert_eq!(pass, Ok("ABC-123"));
+	///
+	/// let fail = Guard::new("ABC_123")
+	/// 	.contains_only(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-', "SKUs may only contain A-Z, 0-9 and -")
+	/// 	.result();
+	/// assert_eq!(fail, Err(String::from("SKUs may only contain A-Z, 0-9 and -")));
+	/// ```
+	pub fn contains_only<E: Into<String>>(
+		self,
+		allowed: impl Fn(char) -> bool,
+		error_message: E,
+	) -> Self {
+		self.check("contains_only", |value| {
+			Guards::contains_only(value, allowed, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` contains none of the characters in `forbidden`.
+	///
+	/// See [Guards::contains_none_of] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("Quarterly report")).contains_none_of("<>&", "?!").result();
+	/// assert_eq!(pass, Ok(String::from("Quarterly report")));
+	///
+	/// let fail = Guard::new("<script>").contains_none_of("<>&", "Title must not contain markup").result();
+	/// assert_eq!(fail, Err(String::from("Title must not contain markup")));
+	/// ```
+	pub fn contains_none_of<E: Into<String>>(
+		self,
+		forbidden: &str,
+		error_message: E,
+	) -> Self {
+		self.check("contains_none_of", |value| {
+			Guards::contains_none_of(value, forbidden, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is a single line.
+	///
+	/// See [Guards::is_single_line] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("Fix login redirect").is_single_line("?!").result();
+	/// assert_eq!(pass, Ok("Fix login redirect"));
+	///
+	/// let fail = Guard::new("Fix login\nredirect").is_single_line("Title must be a single line").result();
+	/// assert_eq!(fail, Err(String::from("Title must be a single line")));
+	/// ```
+	pub fn is_single_line<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_single_line", |value| {
+			Guards::is_single_line(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` has at most `max` lines.
+	///
+	/// See [Guards::has_max_lines] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("Line one\nLine two\n")).has_max_lines(10, "?!").result();
+	/// assert_eq!(pass, Ok(String::from("Line one\nLine two\n")));
+	///
+	/// let fail = Guard::new("line\n".repeat(11)).has_max_lines(10, "Description is limited to 10 lines").result();
+	/// assert_eq!(fail, Err(String::from("Description is limited to 10 lines")));
+	/// ```
+	pub fn has_max_lines<E: Into<String>>(
+		self,
+		max: usize,
+		error_message: E,
+	) -> Self {
+		self.check("has_max_lines", |value| {
+			Guards::has_max_lines(value, max, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` matches the wildcard `pattern`.
+	///
+	/// See [Guards::matches_glob] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("api.internal.example.com").matches_glob("*.internal.example.com", "?!").result();
+	/// assert_eq!(pass, Ok("api.internal.example.com"));
+	///
+	/// let fail = Guard::new("api.example.com").matches_glob("*.internal.example.com", "Host is not internal").result();
+	/// assert_eq!(fail, Err(String::from("Host is not internal")));
+	/// ```
+	pub fn matches_glob<E: Into<String>>(
+		self,
+		pattern: &str,
+		error_message: E,
+	) -> Self {
+		self.check("matches_glob", |value| {
+			Guards::matches_glob(value, pattern, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` matches the wildcard `pattern`, ignoring ASCII case.
+	///
+	/// See [Guards::matches_glob_ignore_case] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("API.Internal.Example.com").matches_glob_ignore_case("*.internal.example.com", "?!").result();
+	/// assert_eq!(pass, Ok("API.Internal.Example.com"));
+	///
+	/// let fail = Guard::new("API.Example.com").matches_glob_ignore_case("*.internal.example.com", "Host is not internal").result();
+	/// assert_eq!(fail, Err(String::from("Host is not internal")));
+	/// ```
+	pub fn matches_glob_ignore_case<E: Into<String>>(
+		self,
+		pattern: &str,
+		error_message: E,
+	) -> Self {
+		self.check("matches_glob_ignore_case", |value| {
+			Guards::matches_glob_ignore_case(value, pattern, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` matches at least one of the wildcard `patterns`.
+	///
+	/// See [Guards::matches_any_glob] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let allowlist = vec!["*.internal.example.com", "*.corp.example.com"];
+	///
+	/// let pass = Guard::new(String::from("wiki.corp.example.com")).matches_any_glob(&allowlist, "?!").result();
+	/// assert_eq!(pass, Ok(String::from("wiki.corp.example.com")));
+	///
+	/// let fail = Guard::new("wiki.example.com").matches_any_glob(&allowlist, "Host is not on the allowlist").result();
+	/// assert_eq!(fail, Err(String::from("Host is not on the allowlist")));
+	/// ```
+	pub fn matches_any_glob<E: Into<String>>(
+		self,
+		patterns: &[&str],
+		error_message: E,
+	) -> Self {
+		self.check("matches_any_glob", |value| {
+			Guards::matches_any_glob(value, patterns, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is an ASCII identifier.
+	///
+	/// See [Guards::is_valid_identifier] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("max_retries").is_valid_identifier("?!").result();
+	/// assert_eq!(pass, Ok("max_retries"));
+	///
+	/// let fail = Guard::new("max-retries").is_valid_identifier("Config keys must be identifiers").result();
+	/// assert_eq!(fail, Err(String::from("Config keys must be identifiers")));
+	/// ```
+	pub fn is_valid_identifier<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_identifier", |value| {
+			Guards::is_valid_identifier(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` equals `expected`, ignoring ASCII case.
+	///
+	/// See [Guards::equals_ignore_case] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("Bearer")).equals_ignore_case("bearer", "?!").result();
+	/// assert_eq!(pass, Ok(String::from("Bearer")));
+	///
+	/// let fail = Guard::new("Basic").equals_ignore_case("bearer", "Only bearer tokens are supported").result();
+	/// assert_eq!(fail, Err(String::from("Only bearer tokens are supported")));
+	/// ```
+	pub fn equals_ignore_case<E: Into<String>>(
+		self,
+		expected: &str,
+		error_message: E,
+	) -> Self {
+		self.check("equals_ignore_case", |value| {
+			Guards::equals_ignore_case(value, expected, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` equals one of `candidates`, ignoring ASCII case.
+	///
+	/// See [Guards::is_one_of_ignore_case] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("Json").is_one_of_ignore_case(&["json", "yaml"], "?!").result();
+	/// assert_eq!(pass, Ok("Json"));
+	///
+	/// let fail = Guard::new("xml").is_one_of_ignore_case(&["json", "yaml"], "Format must be json or yaml").result();
+	/// assert_eq!(fail, Err(String::from("Format must be json or yaml")));
+	/// ```
+	pub fn is_one_of_ignore_case<E: Into<String>>(
+		self,
+		candidates: &[&str],
+		error_message: E,
+	) -> Self {
+		self.check("is_one_of_ignore_case", |value| {
+			Guards::is_one_of_ignore_case(value, candidates, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+/// The outcome of a single check in a traced guard chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckOutcome {
+	/// The check ran and the value passed it.
+	Passed,
+	/// The check ran and the value failed it.
+	Failed,
+	/// The check did not run because an earlier check already failed.
+	Skipped,
+}
+
+impl fmt::Display for CheckOutcome {
+	fn fmt(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+	) -> fmt::Result {
+		match self {
+			CheckOutcome::Passed => f.write_str("pass"),
+			CheckOutcome::Failed => f.write_str("FAIL"),
+			CheckOutcome::Skipped => f.write_str("skipped"),
+		}
+	}
+}
+
+/// A record of a check in a traced guard chain.
+///
+/// See [`Guard::traced`](crate::Guard::traced).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CheckTrace {
+	/// Name of the check, for example `"is_between"`.
+	pub name: &'static str,
+	/// Whether the check passed, failed or was skipped.
+	pub outcome: CheckOutcome,
+}
+
+impl fmt::Display for CheckTrace {
+	fn fmt(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+	) -> fmt::Result {
+		write!(f, "{}: {}", self.name, self.outcome)
+	}
+}
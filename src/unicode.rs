@@ -0,0 +1,168 @@
+use unicode_ident::{
+	is_xid_continue,
+	is_xid_start,
+};
+use unicode_normalization::{
+	is_nfc,
+	is_nfkc,
+};
+
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is a Unicode identifier as defined by [UAX #31](https://www.unicode.org/reports/tr31/).
+	///
+	/// Returns [`Ok`] if the string starts with an `XID_Start` character or `_`, followed by any number of
+	/// `XID_Continue` characters, otherwise returns [`Err`] with the given `error_message`. This is the rule Rust
+	/// uses for identifiers. The string is not normalized first, and keywords are not rejected.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_valid_identifier_unicode("_foo", "?!"), Ok("_foo"));
+	/// assert_eq!(Guards::is_valid_identifier_unicode("café", "?!"), Ok("café"));
+	/// assert_eq!(Guards::is_valid_identifier_unicode("変数", "?!"), Ok("変数"));
+	/// assert_eq!(Guards::is_valid_identifier_unicode("Δx1", "?!"), Ok("Δx1"));
+	///
+	/// assert_eq!(Guards::is_valid_identifier_unicode("foo-bar", "Invalid identifier!"), Err(String::from("Invalid identifier!")));
+	/// assert!(Guards::is_valid_identifier_unicode("1abc", "Invalid identifier!").is_err());
+	/// assert!(Guards::is_valid_identifier_unicode("", "Invalid identifier!").is_err());
+	/// assert!(Guards::is_valid_identifier_unicode("🦀", "Invalid identifier!").is_err());
+	/// ```
+	pub fn is_valid_identifier_unicode<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		let mut chars = value.as_ref().chars();
+		let valid = chars.next().is_some_and(|first| first == '_' || is_xid_start(first)) && chars.all(is_xid_continue);
+
+		if valid {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is in Unicode Normalization Form C.
+	///
+	/// Returns [`Ok`] if normalizing the string to NFC would not change it, otherwise returns [`Err`] with the
+	/// given `error_message`. A quick check decides most strings without normalizing them, and strings that are
+	/// already normalized are never copied.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_nfc("caf\u{e9}", "?!"), Ok("caf\u{e9}"));
+	/// assert_eq!(Guards::is_nfc("plain ASCII", "?!"), Ok("plain ASCII"));
+	/// assert_eq!(Guards::is_nfc("ﬁ", "?!"), Ok("ﬁ"));
+	///
+	/// // `e` followed by a combining acute accent
+	/// assert_eq!(Guards::is_nfc("cafe\u{301}", "Not NFC!"), Err(String::from("Not NFC!")));
+	/// ```
+	pub fn is_nfc<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if is_nfc(value.as_ref()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is in Unicode Normalization Form KC.
+	///
+	/// Returns [`Ok`] if normalizing the string to NFKC would not change it, otherwise returns [`Err`] with the
+	/// given `error_message`. NFKC also replaces compatibility characters, like the `ﬁ` ligature or full width
+	/// digits, so it is stricter than [`Guards::is_nfc`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_nfkc("caf\u{e9}", "?!"), Ok("caf\u{e9}"));
+	///
+	/// assert_eq!(Guards::is_nfkc("cafe\u{301}", "Not NFKC!"), Err(String::from("Not NFKC!")));
+	/// assert!(Guards::is_nfkc("ﬁle", "Not NFKC!").is_err());
+	/// assert!(Guards::is_nfkc("１２３", "Not NFKC!").is_err());
+	/// ```
+	pub fn is_nfkc<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if is_nfkc(value.as_ref()) {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` is a Unicode identifier as defined by UAX #31.
+	///
+	/// See [Guards::is_valid_identifier_unicode] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("größe").is_valid_identifier_unicode("?!").result();
+	/// assert_eq!(pass, Ok("größe"));
+	///
+	/// let fail = Guard::new("größe-2").is_valid_identifier_unicode("Field names must be identifiers").result();
+	/// assert_eq!(fail, Err(String::from("Field names must be identifiers")));
+	/// ```
+	pub fn is_valid_identifier_unicode<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_identifier_unicode", |value| {
+			Guards::is_valid_identifier_unicode(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is in Unicode Normalization Form C.
+	///
+	/// See [Guards::is_nfc] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new(String::from("Jos\u{e9}")).is_nfc("?!").result();
+	/// assert_eq!(pass, Ok(String::from("José")));
+	///
+	/// let fail = Guard::new("Jose\u{301}").is_nfc("Names must be stored in NFC").result();
+	/// assert_eq!(fail, Err(String::from("Names must be stored in NFC")));
+	/// ```
+	pub fn is_nfc<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_nfc", |value| Guards::is_nfc(value, error_message).map(|_| ()))
+	}
+
+	/// Ensures that `value` is in Unicode Normalization Form KC.
+	///
+	/// See [Guards::is_nfkc] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("file").is_nfkc("?!").result();
+	/// assert_eq!(pass, Ok("file"));
+	///
+	/// let fail = Guard::new("ﬁle").is_nfkc("Search terms must be in NFKC").result();
+	/// assert_eq!(fail, Err(String::from("Search terms must be in NFKC")));
+	/// ```
+	pub fn is_nfkc<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_nfkc", |value| Guards::is_nfkc(value, error_message).map(|_| ()))
+	}
+}
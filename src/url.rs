@@ -0,0 +1,121 @@
+use ::url::Url;
+
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is an absolute URL.
+	///
+	/// Returns [`Ok`] if [`Url::parse`] accepts the string, otherwise returns [`Err`] with the given
+	/// `error_message`. Relative URLs like `/hooks/1` have no scheme and fail. Any scheme is accepted, see
+	/// [`Guards::is_valid_url_with_scheme`] to restrict it.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_valid_url("https://example.com/hooks/1", "?!"), Ok("https://example.com/hooks/1"));
+	/// assert_eq!(Guards::is_valid_url("mailto:ferris@example.com", "?!"), Ok("mailto:ferris@example.com"));
+	///
+	/// assert_eq!(Guards::is_valid_url("/hooks/1", "Invalid URL!"), Err(String::from("Invalid URL!")));
+	/// assert!(Guards::is_valid_url("example.com", "Invalid URL!").is_err());
+	/// assert!(Guards::is_valid_url("https://exa mple.com", "Invalid URL!").is_err());
+	/// assert!(Guards::is_valid_url("", "Invalid URL!").is_err());
+	/// ```
+	pub fn is_valid_url<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<T, String> {
+		if Url::parse(value.as_ref()).is_ok() {
+			Ok(value)
+		} else {
+			Err(error_message.into())
+		}
+	}
+
+	/// Ensures that `value` is an absolute URL with one of the `allowed` schemes.
+	///
+	/// Returns [`Ok`] if [`Url::parse`] accepts the string and its scheme is in `allowed`, otherwise returns
+	/// [`Err`] with the given `error_message`. Schemes are compared in lowercase, as [`Url`] normalizes them,
+	/// so `allowed` should be lowercase too.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	///
+	/// assert_eq!(Guards::is_valid_url_with_scheme("https://example.com", &["https"], "?!"), Ok("https://example.com"));
+	/// assert_eq!(Guards::is_valid_url_with_scheme("HTTPS://example.com", &["https"], "?!"), Ok("HTTPS://example.com"));
+	/// assert_eq!(Guards::is_valid_url_with_scheme("ws://example.com", &["ws", "wss"], "?!"), Ok("ws://example.com"));
+	///
+	/// assert_eq!(
+	/// 	Guards::is_valid_url_with_scheme("http://example.com", &["https"], "HTTPS only!"),
+	/// 	Err(String::from("HTTPS only!"))
+	/// );
+	/// assert!(Guards::is_valid_url_with_scheme("/hooks/1", &["https"], "HTTPS only!").is_err());
+	/// assert!(Guards::is_valid_url_with_scheme("https://example.com", &[], "HTTPS only!").is_err());
+	/// ```
+	pub fn is_valid_url_with_scheme<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		allowed: &[&str],
+		error_message: E,
+	) -> Result<T, String> {
+		match Url::parse(value.as_ref()) {
+			Ok(url) if allowed.contains(&url.scheme()) => Ok(value),
+			_ => Err(error_message.into()),
+		}
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` is an absolute URL.
+	///
+	/// See [Guards::is_valid_url] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let pass = Guard::new("https://example.com/hooks/1").is_valid_url("?!").result();
+	/// assert_eq!(pass, Ok("https://example.com/hooks/1"));
+	///
+	/// let fail = Guard::new("/hooks/1").is_valid_url("Webhook URL must be absolute").result();
+	/// assert_eq!(fail, Err(String::from("Webhook URL must be absolute")));
+	/// ```
+	pub fn is_valid_url<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_url", |value| {
+			Guards::is_valid_url(value, error_message).map(|_| ())
+		})
+	}
+
+	/// Ensures that `value` is an absolute URL with one of the `allowed` schemes.
+	///
+	/// See [Guards::is_valid_url_with_scheme] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	/// use url::Url;
+	///
+	/// fn webhook_url(input: &str) -> Result<Url, String> {
+	/// 	Guard::new(input)
+	/// 		.is_valid_url_with_scheme(&["https"], "Webhook URL must use https")
+	/// 		.result()
+	/// 		.and_then(|url| Url::parse(url).map_err(|error| error.to_string()))
+	/// }
+	///
+	/// assert_eq!(webhook_url("https://example.com/hooks/1").unwrap().path(), "/hooks/1");
+	/// assert_eq!(webhook_url("http://example.com/hooks/1"), Err(String::from("Webhook URL must use https")));
+	/// ```
+	pub fn is_valid_url_with_scheme<E: Into<String>>(
+		self,
+		allowed: &[&str],
+		error_message: E,
+	) -> Self {
+		self.check("is_valid_url_with_scheme", |value| {
+			Guards::is_valid_url_with_scheme(value, allowed, error_message).map(|_| ())
+		})
+	}
+}
@@ -0,0 +1,60 @@
+use ::uuid::Uuid;
+
+use crate::{
+	Guard,
+	Guards,
+};
+
+impl Guards {
+	/// Ensures that `value` is a UUID in the canonical hyphenated format and parses it.
+	///
+	/// Returns [`Ok`] with the parsed [`Uuid`], otherwise returns [`Err`] with the given `error_message`.
+	/// The accepted format is the same as for [`Guards::is_uuid`], which is stricter than [`Uuid::parse_str`].
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guards;
+	/// use uuid::Uuid;
+	///
+	/// let uuid = Guards::parses_as_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8", "?!").unwrap();
+	/// assert_eq!(uuid.get_version_num(), 4);
+	///
+	/// assert_eq!(Guards::parses_as_uuid("67e55044-10b1-426f", "Invalid UUID!"), Err(String::from("Invalid UUID!")));
+	/// assert!(Guards::parses_as_uuid("67e5504410b1426f9247bb680e5fe0c8", "Invalid UUID!").is_err());
+	/// ```
+	pub fn parses_as_uuid<T: AsRef<str>, E: Into<String>>(
+		value: T,
+		error_message: E,
+	) -> Result<Uuid, String> {
+		let value = Guards::is_uuid(value, "").ok().and_then(|value| Uuid::try_parse(value.as_ref()).ok());
+
+		value.ok_or_else(|| error_message.into())
+	}
+}
+
+impl<T: AsRef<str>> Guard<T> {
+	/// Ensures that `value` is a UUID in the canonical hyphenated format and continues the chain with the parsed
+	/// [`Uuid`].
+	///
+	/// See [Guards::parses_as_uuid] for more details.
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	/// use uuid::Uuid;
+	///
+	/// let pass = Guard::new("67e55044-10b1-426f-9247-bb680e5fe0c8")
+	/// 	.parses_as_uuid("Order id must be a UUID")
+	/// 	.is_not_equal_to(Uuid::nil(), "Order id must not be nil")
+	/// 	.result();
+	/// assert_eq!(pass, Ok(Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8)));
+	///
+	/// let fail = Guard::new("{67e55044-10b1-426f-9247-bb680e5fe0c8}").parses_as_uuid("Order id must be a UUID").result();
+	/// assert_eq!(fail, Err(String::from("Order id must be a UUID")));
+	/// ```
+	pub fn parses_as_uuid<E: Into<String>>(
+		self,
+		error_message: E,
+	) -> Guard<Uuid> {
+		self.convert("parses_as_uuid", |value| Guards::parses_as_uuid(value, error_message))
+	}
+}
@@ -0,0 +1,102 @@
+use std::{
+	fmt,
+	marker::PhantomData,
+	ops::Deref,
+};
+
+/// A value that has passed a guard chain.
+///
+/// A `Validated` can only be created by finishing a [`Guard`](crate::Guard) chain with
+/// [`Guard::validated`](crate::Guard::validated) or [`Guard::validated_as`](crate::Guard::validated_as),
+/// so functions can ask for a `Validated<T>` instead of a bare `T` to require that checks ran.
+///
+/// The optional `Tag` parameter separates values that were validated for different purposes.
+///
+/// ## Example
+/// ```
+/// use fluent_guards::{Bound, Guard, Validated};
+///
+/// struct Channel;
+/// struct Volume;
+///
+/// fn set_channel(channel: Validated<u32, Channel>) -> u32 {
+/// 	*channel
+/// }
+///
+/// let channel = Guard::new(5)
+/// 	.is_between(1, 15, Bound::Inclusive, "Invalid channel!")
+/// 	.validated_as::<Channel>()
+/// 	.unwrap();
+/// assert_eq!(set_channel(channel), 5);
+///
+/// let volume = Guard::new(5)
+/// 	.is_between(0, 10, Bound::Inclusive, "Invalid volume!")
+/// 	.validated_as::<Volume>()
+/// 	.unwrap();
+/// // set_channel(volume); // Does not compile, the value was validated as a volume
+/// assert_eq!(volume.into_inner(), 5);
+/// ```
+pub struct Validated<T, Tag = ()> {
+	value: T,
+	tag: PhantomData<fn() -> Tag>,
+}
+
+impl<T, Tag> Validated<T, Tag> {
+	pub(crate) fn new(value: T) -> Validated<T, Tag> {
+		Validated {
+			value,
+			tag: PhantomData,
+		}
+	}
+
+	/// Consumes the wrapper, returning the validated value.
+	///
+	/// ## Example
+	/// ```
+	/// use fluent_guards::Guard;
+	///
+	/// let validated = Guard::new(5).is_equal_to(5, "?!").validated().unwrap();
+	/// assert_eq!(validated.into_inner(), 5);
+	/// ```
+	pub fn into_inner(self) -> T {
+		self.value
+	}
+}
+
+impl<T, Tag> Deref for Validated<T, Tag> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T, Tag> AsRef<T> for Validated<T, Tag> {
+	fn as_ref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T: Clone, Tag> Clone for Validated<T, Tag> {
+	fn clone(&self) -> Self {
+		Validated::new(self.value.clone())
+	}
+}
+
+impl<T: fmt::Debug, Tag> fmt::Debug for Validated<T, Tag> {
+	fn fmt(
+		&self,
+		f: &mut fmt::Formatter<'_>,
+	) -> fmt::Result {
+		f.debug_tuple("Validated").field(&self.value).finish()
+	}
+}
+
+impl<T: PartialEq, Tag> PartialEq for Validated<T, Tag> {
+	fn eq(
+		&self,
+		other: &Self,
+	) -> bool {
+		self.value == other.value
+	}
+}